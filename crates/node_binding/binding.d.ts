@@ -28,6 +28,12 @@ export interface RawGlobOptions {
   dot?: boolean
   ignore?: Array<string>
 }
+export interface RawCircularDependenciesPluginConfig {
+  include?: string
+  exclude?: string
+  failOnError: boolean
+  allowAsyncCycles: boolean
+}
 export interface RawCopyConfig {
   patterns: Array<RawPattern>
 }
@@ -43,6 +49,9 @@ export interface RawDecoratorOptions {
   legacy: boolean
   emitMetadata: boolean
 }
+export interface RawDuplicatePackagesPluginConfig {
+  exclude?: string
+}
 export interface RawHtmlPluginConfig {
   /** emitted file name in output path */
   filename?: string
@@ -95,6 +104,8 @@ export interface RawPxToRemConfig {
 }
 export interface RawProgressPluginConfig {
   prefix?: string
+  /** Print the progress events to stderr as JSON lines instead of drawing a progress bar. */
+  json?: boolean
 }
 export interface RawReactOptions {
   runtime?: "automatic" | "classic"
@@ -138,6 +149,8 @@ export interface RawBuiltins {
   copy?: RawCopyConfig
   pluginImport?: Array<RawPluginImportConfig>
   relay?: RawRelayConfig
+  circularDependencies?: RawCircularDependenciesPluginConfig
+  duplicatePackages?: RawDuplicatePackagesPluginConfig
 }
 export interface RawCacheOptions {
   type: string
@@ -156,22 +169,43 @@ export interface RawDevServer {
 export interface RawEntryItem {
   import: Array<string>
   runtime?: string
+  layer?: string
+  dependOn?: Array<string>
+  filename?: string
+  library?: RawLibraryOptions
+  /** `"false"` disables chunk loading of the entry. */
+  chunkLoading?: string
+  asyncChunks?: boolean
+  publicPath?: string
 }
 export interface RawExperiments {
   lazyCompilation: boolean
   incrementalRebuild: boolean
   asyncWebAssembly: boolean
+  layers: boolean
 }
 export interface RawExternalItem {
-  type: "string" | "regexp" | "object"
+  type: "string" | "regexp" | "object" | "function"
   stringPayload?: string
   regexpPayload?: string
   objectPayload?: Record<string, RawExternalItemValue>
+  fnPayload?: (value: RawExternalItemFnCtx) => Promise<RawExternalItemValue | undefined>
+}
+export interface RawContextInfo {
+  issuer: string
+}
+export interface RawExternalItemFnCtx {
+  request: string
+  context: string
+  dependencyType: string
+  contextInfo: RawContextInfo
 }
 export interface RawExternalItemValue {
-  type: "string" | "bool"
+  type: "string" | "bool" | "array" | "object"
   stringPayload?: string
   boolPayload?: boolean
+  arrayPayload?: Array<string>
+  objectPayload?: Record<string, Array<string>>
 }
 export interface RawExternalsPresets {
   node: boolean
@@ -226,6 +260,8 @@ export interface RawModuleRule {
   generator?: RawModuleRuleGenerator
   resolve?: RawResolveOptions
   issuer?: RawRuleSetCondition
+  issuerLayer?: RawRuleSetCondition
+  layer?: string
   oneOf?: Array<RawModuleRule>
 }
 export interface RawModuleRuleGenerator {
@@ -276,11 +312,25 @@ export interface RawNodeOption {
   filename: string
   global: string
 }
+export interface RawHashedModuleIdsOptions {
+  context?: string
+  hashFunction?: string
+  hashDigest?: string
+  hashDigestLength?: number
+}
+export interface RawDeterministicChunkIdsOptions {
+  context?: string
+  maxLength?: number
+}
 export interface RawOptimizationOptions {
   splitChunks?: RawSplitChunksOptions
   moduleIds: string
+  chunkIds: string
+  hashedModuleIds?: RawHashedModuleIdsOptions
+  deterministicChunkIds?: RawDeterministicChunkIdsOptions
   removeAvailableModules: boolean
   sideEffects: string
+  providedExports: boolean
 }
 export interface RawLibraryName {
   amd?: string
@@ -310,9 +360,14 @@ export interface RawOutputOptions {
   cssFilename: string
   cssChunkFilename: string
   uniqueName: string
+  devtoolNamespace: string
+  devtoolModuleFilenameTemplate: string
+  devtoolFallbackModuleFilenameTemplate: string
   library?: RawLibraryOptions
   strictModuleErrorHandling: boolean
   enabledLibraryTypes?: Array<string>
+  chunkLoading: string
+  enabledChunkLoadingTypes?: Array<string>
   globalObject: string
   importFunctionName: string
   iife: boolean
@@ -336,17 +391,26 @@ export interface RawSnapshotStrategy {
   hash: boolean
   timestamp: boolean
 }
+export interface RawSnapshotPath {
+  type: "string" | "regexp"
+  stringPayload?: string
+  regexpPayload?: string
+}
 export interface RawSnapshotOptions {
   resolve: RawSnapshotStrategy
   module: RawSnapshotStrategy
+  immutablePaths: Array<RawSnapshotPath>
+  managedPaths: Array<RawSnapshotPath>
 }
 export interface RawSplitChunksOptions {
   cacheGroups?: Record<string, RawCacheGroupOptions>
   /** What kind of chunks should be selected. */
   chunks?: string
+  filename?: string
   maxAsyncRequests?: number
   maxInitialRequests?: number
   minChunks?: number
+  hidePathInfo?: boolean
   minSize?: number
   enforceSizeThreshold?: number
   minRemainingSize?: number
@@ -355,14 +419,36 @@ export interface RawCacheGroupOptions {
   priority?: number
   reuseExistingChunk?: boolean
   test?: string
+  filename?: string
   /** What kind of chunks should be selected. */
   chunks?: string
   minChunks?: number
+  layer?: string
   name?: string
 }
 export interface RawStatsOptions {
   colors: boolean
+  hash: boolean
+  timings: boolean
+  builtAt: boolean
+  pluginTimings: boolean
+  publicPath: boolean
+  outputPath: boolean
+  assets: boolean
+  chunks: boolean
+  chunkModules: boolean
+  chunkRelations: boolean
+  modules: boolean
   reasons: boolean
+  usedExports: boolean
+  providedExports: boolean
+  optimizationBailout: boolean
+  entrypoints: boolean
+  chunkGroups: boolean
+  errors: boolean
+  errorsCount: boolean
+  warnings: boolean
+  warningsCount: boolean
 }
 export interface RawOptions {
   entry: Record<string, RawEntryItem>
@@ -384,6 +470,9 @@ export interface RawOptions {
   cache: RawCacheOptions
   experiments: RawExperiments
   node: RawNodeOption
+  profile: boolean
+  recordsInputPath?: string
+  recordsOutputPath?: string
 }
 export interface JsAssetInfoRelated {
   sourceMap?: string
@@ -506,6 +595,21 @@ export interface JsStatsModuleIssuer {
   name: string
   id: string
 }
+export interface JsStatsModuleGraph {
+  entries: Array<string>
+  modules: Array<JsStatsModuleGraphModule>
+  connections: Array<JsStatsModuleGraphConnection>
+  cycles: Array<Array<string>>
+}
+export interface JsStatsModuleGraphModule {
+  identifier: string
+  name: string
+  id: string
+}
+export interface JsStatsModuleGraphConnection {
+  originIdentifier: string
+  moduleIdentifier: string
+}
 export interface JsStatsModuleReason {
   moduleIdentifier?: string
   moduleName?: string
@@ -534,6 +638,13 @@ export interface JsStatsChunkGroup {
   chunks: Array<string>
   assetsSize: number
 }
+export interface JsStatsPluginHookTiming {
+  plugin: string
+  hook: string
+  calls: number
+  /** Milliseconds. */
+  duration: number
+}
 export interface JsStatsAssetsByChunkName {
   name: string
   files: Array<string>
@@ -549,7 +660,7 @@ export interface JsStatsGetAssets {
  * Author Donny/강동윤
  * Copyright (c)
 */
-export function initCustomTraceSubscriber(): void
+export function initCustomTraceSubscriber(traceOutput?: string | undefined | null): void
 export class JsCompilation {
   updateAsset(filename: string, newSourceOrFunction: JsCompatSource | ((source: JsCompatSource) => JsCompatSource), assetInfoUpdateOrFunction?: JsAssetInfo | ((assetInfo: JsAssetInfo) => JsAssetInfo)): void
   getAssets(): Readonly<JsAsset>[]
@@ -590,6 +701,10 @@ export class JsStats {
   getErrors(): Array<JsStatsError>
   getWarnings(): Array<JsStatsWarning>
   getHash(): string
+  getPluginHookTimings(): Array<JsStatsPluginHookTiming>
+  getModuleGraph(): JsStatsModuleGraph
+  getModuleGraphDot(): string
+  getModuleImportPath(identifier: string): Array<JsStatsModuleIssuer> | null
 }
 export class Rspack {
   constructor(options: RawOptions, jsHooks: JsHooks | undefined | null, outputFilesystem: ThreadsafeNodeFS)
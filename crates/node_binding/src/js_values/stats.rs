@@ -128,6 +128,57 @@ impl From<rspack_core::StatsModuleIssuer> for JsStatsModuleIssuer {
   }
 }
 
+#[napi(object)]
+pub struct JsStatsModuleGraph {
+  pub entries: Vec<String>,
+  pub modules: Vec<JsStatsModuleGraphModule>,
+  pub connections: Vec<JsStatsModuleGraphConnection>,
+  pub cycles: Vec<Vec<String>>,
+}
+
+impl From<rspack_core::StatsModuleGraph> for JsStatsModuleGraph {
+  fn from(stats: rspack_core::StatsModuleGraph) -> Self {
+    Self {
+      entries: stats.entries,
+      modules: stats.modules.into_iter().map(Into::into).collect(),
+      connections: stats.connections.into_iter().map(Into::into).collect(),
+      cycles: stats.cycles,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsModuleGraphModule {
+  pub identifier: String,
+  pub name: String,
+  pub id: String,
+}
+
+impl From<rspack_core::StatsModuleGraphModule> for JsStatsModuleGraphModule {
+  fn from(stats: rspack_core::StatsModuleGraphModule) -> Self {
+    Self {
+      identifier: stats.identifier,
+      name: stats.name,
+      id: stats.id,
+    }
+  }
+}
+
+#[napi(object)]
+pub struct JsStatsModuleGraphConnection {
+  pub origin_identifier: String,
+  pub module_identifier: String,
+}
+
+impl From<rspack_core::StatsModuleGraphConnection> for JsStatsModuleGraphConnection {
+  fn from(stats: rspack_core::StatsModuleGraphConnection) -> Self {
+    Self {
+      origin_identifier: stats.origin_identifier,
+      module_identifier: stats.module_identifier,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsModuleReason {
   pub module_identifier: Option<String>,
@@ -214,6 +265,26 @@ impl From<rspack_core::StatsChunkGroup> for JsStatsChunkGroup {
   }
 }
 
+#[napi(object)]
+pub struct JsStatsPluginHookTiming {
+  pub plugin: String,
+  pub hook: String,
+  pub calls: u32,
+  /// Milliseconds.
+  pub duration: f64,
+}
+
+impl From<rspack_core::StatsPluginHookTiming> for JsStatsPluginHookTiming {
+  fn from(stats: rspack_core::StatsPluginHookTiming) -> Self {
+    Self {
+      plugin: stats.plugin,
+      hook: stats.hook,
+      calls: stats.calls,
+      duration: stats.duration,
+    }
+  }
+}
+
 #[napi(object)]
 pub struct JsStatsAssetsByChunkName {
   pub name: String,
@@ -325,4 +396,32 @@ impl JsStats {
   pub fn get_hash(&self) -> String {
     self.inner.get_hash()
   }
+
+  #[napi]
+  pub fn get_plugin_hook_timings(&self) -> Vec<JsStatsPluginHookTiming> {
+    self
+      .inner
+      .get_plugin_hook_timings()
+      .into_iter()
+      .map(Into::into)
+      .collect()
+  }
+
+  #[napi]
+  pub fn get_module_graph(&self) -> JsStatsModuleGraph {
+    self.inner.get_module_graph().into()
+  }
+
+  #[napi]
+  pub fn get_module_graph_dot(&self) -> String {
+    self.inner.get_module_graph_dot()
+  }
+
+  #[napi]
+  pub fn get_module_import_path(&self, identifier: String) -> Option<Vec<JsStatsModuleIssuer>> {
+    self
+      .inner
+      .get_module_import_path(&identifier)
+      .map(|path| path.into_iter().map(Into::into).collect())
+  }
 }
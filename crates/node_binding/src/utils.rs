@@ -83,28 +83,42 @@ pub(crate) fn get_named_property_value_string<T: NapiRaw>(
 #[napi]
 pub fn init_custom_trace_subscriber(
   mut env: Env,
-  // trace_out_file_path: Option<String>,
+  trace_output: Option<String>,
 ) -> Result<()> {
-  CUSTOM_TRACE_SUBSCRIBER.get_or_init(|| {
+  CUSTOM_TRACE_SUBSCRIBER.get_or_try_init(|| {
     let layer = std::env::var("layer").unwrap_or("logger".to_string());
-    let guard = match layer.as_str() {
-      "chrome" => rspack_tracing::enable_tracing_by_env_with_chrome_layer(),
-      "logger" => {
-        rspack_tracing::enable_tracing_by_env();
-        None
+    match layer.as_str() {
+      "chrome" => {
+        if let Some(guard) = rspack_tracing::enable_tracing_by_env_with_chrome_layer() {
+          env
+            .add_env_cleanup_hook(guard, |flush_guard| {
+              flush_guard.flush();
+              drop(flush_guard);
+            })
+            .expect("Should able to initialize cleanup for custom trace subscriber");
+        }
       }
+      // Write a chrome trace and a summary of the time taken by plugins to `trace_output`,
+      // `TRACE_OUTPUT` is used if it's not passed.
+      "profile" => {
+        let output = trace_output
+          .or_else(|| std::env::var("TRACE_OUTPUT").ok())
+          .unwrap_or(".rspack-profile".to_string());
+        let guard = rspack_tracing::enable_tracing_profile(&output).map_err(|e| {
+          Error::from_reason(format!("Failed to create the profile output {output}: {e}"))
+        })?;
+        if let Some(guard) = guard {
+          env
+            // The chrome trace and the summary are written when the guard is dropped.
+            .add_env_cleanup_hook(guard, drop)
+            .expect("Should able to initialize cleanup for custom trace subscriber");
+        }
+      }
+      "logger" => rspack_tracing::enable_tracing_by_env(),
       _ => panic!("not supported layer type:{layer}"),
-    };
-    if let Some(guard) = guard {
-      env
-        .add_env_cleanup_hook(guard, |flush_guard| {
-          flush_guard.flush();
-          drop(flush_guard);
-        })
-        .expect("Should able to initialize cleanup for custom trace subscriber");
     }
-    true
-  });
+    Ok::<_, Error>(true)
+  })?;
 
   Ok(())
 }
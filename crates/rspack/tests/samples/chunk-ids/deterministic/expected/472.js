@@ -0,0 +1,16 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["472"], {
+"./b.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "b", {
+    enumerable: true,
+    get: function() {
+        return b;
+    }
+});
+const b = "b";
+},
+
+}]);
\ No newline at end of file
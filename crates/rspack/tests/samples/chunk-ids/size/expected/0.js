@@ -0,0 +1,16 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["0"], {
+"./a.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "a", {
+    enumerable: true,
+    get: function() {
+        return a;
+    }
+});
+const a = "a";
+},
+
+}]);
\ No newline at end of file
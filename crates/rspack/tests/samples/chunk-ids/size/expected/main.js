@@ -0,0 +1,11 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["2"], {
+"./index.js": function (module, exports, __webpack_require__) {
+__webpack_require__.el("./a.js").then(__webpack_require__.bind(__webpack_require__, "./a.js")).then(__webpack_require__.ir).then(({ a  })=>console.log(a));
+__webpack_require__.el("./b.js").then(__webpack_require__.bind(__webpack_require__, "./b.js")).then(__webpack_require__.ir).then(({ b  })=>console.log(b));
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
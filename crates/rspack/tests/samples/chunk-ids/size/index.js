@@ -0,0 +1,2 @@
+import("./a").then(({ a }) => console.log(a));
+import("./b").then(({ b }) => console.log(b));
@@ -0,0 +1,25 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"./index.js": function (module, exports, __webpack_require__) {
+__webpack_require__.el("./lazy.js").then(__webpack_require__.bind(__webpack_require__, "./lazy.js")).then(__webpack_require__.ir).then(({ value  })=>{
+    console.log(value);
+});
+},
+"./lazy.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "value", {
+    enumerable: true,
+    get: function() {
+        return value;
+    }
+});
+const value = 42;
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./index.js');
+
+}
+]);
\ No newline at end of file
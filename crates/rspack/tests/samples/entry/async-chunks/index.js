@@ -0,0 +1,3 @@
+import("./lazy").then(({ value }) => {
+	console.log(value);
+});
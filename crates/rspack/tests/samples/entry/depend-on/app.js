@@ -0,0 +1,4 @@
+import { value } from "./shared";
+import { local } from "./local";
+
+console.log(value, local);
@@ -0,0 +1,29 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["app"], {
+"./app.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _sharedJs = __webpack_require__("./shared.js");
+var _localJs = __webpack_require__("./local.js");
+console.log(_sharedJs.value, _localJs.local);
+},
+"./local.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "local", {
+    enumerable: true,
+    get: function() {
+        return local;
+    }
+});
+const local = "app";
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./app.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,20 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["shared"], {
+"./shared.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "value", {
+    enumerable: true,
+    get: function() {
+        return value;
+    }
+});
+const value = 42;
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./shared.js');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,28 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["client"], {
+"./client.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _sharedJs = __webpack_require__("./shared.js");
+console.log("client", _sharedJs.value);
+},
+"./shared.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "value", {
+    enumerable: true,
+    get: function() {
+        return value;
+    }
+});
+const value = 42;
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('./client.js');
+
+}
+]);
\ No newline at end of file
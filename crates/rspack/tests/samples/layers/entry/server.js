@@ -0,0 +1,3 @@
+import { value } from "./shared";
+
+console.log("server", value);
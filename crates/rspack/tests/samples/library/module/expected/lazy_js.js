@@ -0,0 +1,17 @@
+export const ids = ['lazy_js'];
+export const modules = {
+"./lazy.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "greeting", {
+    enumerable: true,
+    get: function() {
+        return greeting;
+    }
+});
+const greeting = "hello";
+},
+
+};
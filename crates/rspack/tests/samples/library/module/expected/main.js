@@ -0,0 +1,54 @@
+export const ids = ['main'];
+export const modules = {
+"./answer.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "answer", {
+    enumerable: true,
+    get: function() {
+        return answer;
+    }
+});
+const answer = 42;
+},
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    answer: function() {
+        return _answerJs.answer;
+    },
+    name: function() {
+        return name;
+    },
+    default: function() {
+        return greet;
+    }
+});
+var _answerJs = __webpack_require__("./answer.js");
+const name = "library";
+function greet() {
+    return __webpack_require__.el("./lazy.js").then(__webpack_require__.bind(__webpack_require__, "./lazy.js")).then(__webpack_require__.ir).then(({ greeting  })=>greeting);
+}
+},
+
+};
+
+import __webpack_require__ from './runtime.js';
+import * as __webpack_self_exports__ from './main.js';
+__webpack_require__.C(__webpack_self_exports__);
+var __webpack_exports__ = __webpack_require__('./index.js');
+var __webpack_exports__answer = __webpack_exports__["answer"];
+var __webpack_exports__default = __webpack_exports__["default"];
+var __webpack_exports__name = __webpack_exports__["name"];
+export { __webpack_exports__answer as answer, __webpack_exports__default as default, __webpack_exports__name as name };
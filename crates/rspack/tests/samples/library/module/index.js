@@ -0,0 +1,5 @@
+export { answer } from "./answer";
+export const name = "library";
+export default function greet() {
+	return import("./lazy").then(({ greeting }) => greeting);
+}
@@ -0,0 +1,49 @@
+System.register([], function(__WEBPACK_DYNAMIC_EXPORT__, __system_context__) {
+  return {
+    execute: function() {
+      __WEBPACK_DYNAMIC_EXPORT__(function() {
+        return (function() {
+var __webpack_modules__ = {
+"./index.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "answer", {
+    enumerable: true,
+    get: function() {
+        return answer;
+    }
+});
+const answer = 42;
+},
+
+}
+// The module cache
+ var __webpack_module_cache__ = {};
+function __webpack_require__(moduleId) {
+// Check if module is in cache
+        var cachedModule = __webpack_module_cache__[moduleId];
+        if (cachedModule !== undefined) {
+      return cachedModule.exports;
+      }
+      // Create a new module (and put it into the cache)
+      var module = (__webpack_module_cache__[moduleId] = {
+      // no module.loaded needed
+          exports: {}
+        });
+        // Execute the module function
+      __webpack_modules__[moduleId](module, module.exports, __webpack_require__);
+// Return the exports of the module
+ return module.exports;
+
+}
+var __webpack_exports__ = __webpack_require__('./index.js');
+return __webpack_exports__;
+
+})();
+
+      }());
+    }
+  };
+});
\ No newline at end of file
@@ -0,0 +1,43 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"1Q20": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "a", {
+    enumerable: true,
+    get: function() {
+        return a;
+    }
+});
+const a = "a";
+},
+"vnYo": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "b", {
+    enumerable: true,
+    get: function() {
+        return b;
+    }
+});
+var _1Q20 = __webpack_require__("1Q20");
+const b = _1Q20.a + "b";
+},
+"Yqpk": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _1Q20 = __webpack_require__("1Q20");
+var _vnYo = __webpack_require__("vnYo");
+console.log(_1Q20.a, _vnYo.b);
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('Yqpk');
+
+}
+]);
\ No newline at end of file
@@ -0,0 +1,43 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["main"], {
+"1": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "a", {
+    enumerable: true,
+    get: function() {
+        return a;
+    }
+});
+const a = "a";
+},
+"2": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "b", {
+    enumerable: true,
+    get: function() {
+        return b;
+    }
+});
+var _1 = __webpack_require__("1");
+const b = _1.a + "b";
+},
+"0": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+var _1 = __webpack_require__("1");
+var _2 = __webpack_require__("2");
+console.log(_1.a, _2.b);
+},
+
+},function(__webpack_require__) {
+var __webpack_exports__ = __webpack_require__('0');
+
+}
+]);
\ No newline at end of file
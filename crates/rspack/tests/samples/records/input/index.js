@@ -0,0 +1,2 @@
+import { a } from "./a";
+import("./b").then(({ b }) => console.log(a, b));
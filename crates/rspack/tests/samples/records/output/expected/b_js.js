@@ -0,0 +1,16 @@
+(self['webpackChunkwebpack'] = self['webpackChunkwebpack'] || []).push([["b_js"], {
+"./c.js": function (module, exports, __webpack_require__) {
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "c", {
+    enumerable: true,
+    get: function() {
+        return c;
+    }
+});
+const c = "c";
+},
+
+}]);
\ No newline at end of file
@@ -0,0 +1,2 @@
+import("./b").then(({ b }) => console.log(b));
+import("./c").then(({ c }) => console.log(c));
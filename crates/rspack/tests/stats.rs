@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use rspack_core::{Compiler, StatsOptions, StatsPreset};
+use rspack_fs::AsyncNativeFileSystem;
+use rspack_testing::apply_from_fixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn stats_to_json() {
+  let fixture_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/static-import");
+  let (mut options, plugins) = apply_from_fixture(&fixture_path);
+  options.output.path = std::env::temp_dir().join("rspack_stats_to_json");
+  let mut compiler = Compiler::new(options, plugins, AsyncNativeFileSystem);
+  compiler.build().await.expect("should build");
+  let stats = compiler.compilation.get_stats();
+
+  let json = stats
+    .to_json(&StatsOptions::default())
+    .expect("should get stats");
+  assert!(json.hash.is_none() && json.assets.is_none());
+  assert!(json.modules.is_none() && json.errors.is_none());
+
+  let json = stats
+    .to_json(&StatsOptions::from_preset(StatsPreset::Normal))
+    .expect("should get stats");
+  assert!(json.hash.is_some());
+  let assets_by_chunk_name = json
+    .assets_by_chunk_name
+    .expect("should have assets by chunk name");
+  assert_eq!(assets_by_chunk_name["main"], vec!["main.js".to_string()]);
+  let modules = json.modules.expect("should have modules");
+  assert_eq!(modules.len(), 3);
+  assert!(modules
+    .iter()
+    .any(|module| module.reasons.as_ref().map_or(false, |r| !r.is_empty())));
+  let chunks = json.chunks.expect("should have chunks");
+  let main = chunks
+    .iter()
+    .find(|chunk| chunk.names == vec!["main".to_string()])
+    .expect("should have the main chunk");
+  assert_eq!(main.modules.as_ref().map(Vec::len), Some(3));
+  let entrypoints = json.entrypoints.expect("should have entrypoints");
+  assert!(entrypoints["main"].chunks.contains(&main.id));
+  assert_eq!(json.errors_count, Some(0));
+  assert!(json.plugin_hook_timings.is_none());
+
+  let json = stats
+    .to_json(&StatsOptions::from_preset(StatsPreset::Verbose))
+    .expect("should get stats");
+  let timings = json
+    .plugin_hook_timings
+    .expect("should have plugin timings");
+  assert!(timings
+    .iter()
+    .any(|timing| timing.hook == "render_manifest"));
+}
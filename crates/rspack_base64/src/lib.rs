@@ -11,6 +11,10 @@ pub mod base64 {
     pub fn encode_to_string<D: AsRef<[u8]>>(&self, data: D) -> String {
       self.0.encode_to_string(data)
     }
+
+    pub fn decode_to_vec<D: AsRef<[u8]>>(&self, data: D) -> Option<Vec<u8>> {
+      self.0.decode_to_vec(data).ok()
+    }
   }
 
   impl Default for Base64 {
@@ -23,6 +27,11 @@ pub mod base64 {
     static BASE64: Base64 = Base64::new();
     BASE64.0.encode_to_string(data)
   }
+
+  pub fn decode_to_vec<D: AsRef<[u8]>>(data: D) -> Option<Vec<u8>> {
+    static BASE64: Base64 = Base64::new();
+    BASE64.decode_to_vec(data)
+  }
 }
 
-pub use base64::encode_to_string;
+pub use base64::{decode_to_vec, encode_to_string};
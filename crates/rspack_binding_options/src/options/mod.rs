@@ -1,10 +1,11 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{collections::HashMap, fmt::Debug, path::PathBuf};
 
 use napi_derive::napi;
 use rspack_core::{
   BoxPlugin, CompilerOptions, DevServerOptions, Devtool, EntryItem, Experiments, ModuleOptions,
   OutputOptions, PluginExt, TargetPlatform,
 };
+use rspack_error::internal_error;
 use serde::Deserialize;
 
 mod raw_builtins;
@@ -77,6 +78,9 @@ pub struct RawOptions {
   pub cache: RawCacheOptions,
   pub experiments: RawExperiments,
   pub node: RawNodeOption,
+  pub profile: bool,
+  pub records_input_path: Option<String>,
+  pub records_output_path: Option<String>,
 }
 
 impl RawOptionsApply for RawOptions {
@@ -103,6 +107,20 @@ impl RawOptionsApply for RawOptions {
     let node = self.node.into();
     let dev_server: DevServerOptions = self.dev_server.into();
     let builtins = self.builtins.apply(plugins)?;
+    // The module library exports what the entry module provides, which is only known when the
+    // provided exports are computed.
+    if output
+      .enabled_library_types
+      .iter()
+      .flatten()
+      .any(|library| library == "module")
+      && !optimization.provided_exports
+      && !builtins.tree_shaking
+    {
+      return Err(internal_error!(
+        "Library type \"module\" is only supported with \"optimization.providedExports: true\""
+      ));
+    }
 
     plugins.push(
       rspack_plugin_asset::AssetPlugin::new(rspack_plugin_asset::AssetConfig {
@@ -111,22 +129,40 @@ impl RawOptionsApply for RawOptions {
       .boxed(),
     );
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
-    match &target.platform {
-      TargetPlatform::Web => {
-        plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    if output.module {
+      plugins.push(rspack_plugin_runtime::ModuleChunkFormatPlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+      if matches!(target.platform, TargetPlatform::Web) {
         plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
-      }
-      TargetPlatform::Node(_) => {
-        plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
       }
-      _ => {
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+    } else {
+      match &target.platform {
+        TargetPlatform::Web => {
+          plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
+        }
+        TargetPlatform::Node(_) => {
+          plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+        }
+        _ => {
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+        }
+      };
+    }
+    for chunk_loading in output.enabled_chunk_loading_types.iter().flatten() {
+      match chunk_loading.as_str() {
+        "jsonp" => plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed()),
+        "require" => plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed()),
+        "import" => plugins.push(rspack_plugin_runtime::ModuleChunkLoadingPlugin {}.boxed()),
+        _ => {
+          return Err(internal_error!(
+            "Unsupported chunk loading type {chunk_loading}, expected one of \"jsonp\", \"require\" and \"import\""
+          ))
+        }
       }
-    };
+    }
     if dev_server.hot {
       plugins.push(rspack_plugin_runtime::HotModuleReplacementPlugin {}.boxed());
     }
@@ -138,7 +174,10 @@ impl RawOptionsApply for RawOptions {
       plugins.push(
         rspack_plugin_externals::ExternalPlugin::new(
           self.externals_type,
-          externals.into_iter().map(Into::into).collect(),
+          externals
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<rspack_error::Result<_>>()?,
         )
         .boxed(),
       );
@@ -156,7 +195,9 @@ impl RawOptionsApply for RawOptions {
       rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
         inline: devtool.inline(),
         append: !devtool.hidden(),
-        namespace: output.unique_name.clone(),
+        namespace: output.devtool_namespace.clone(),
+        module_filename_template: output.devtool_module_filename_template.clone(),
+        fallback_module_filename_template: output.devtool_fallback_module_filename_template.clone(),
         columns: !devtool.cheap(),
         no_sources: devtool.no_sources(),
         public_path: None,
@@ -164,7 +205,7 @@ impl RawOptionsApply for RawOptions {
       .boxed(),
     );
 
-    plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed());
+    plugins.push(rspack_ids::RecordIdsPlugin::default().boxed());
 
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
@@ -186,6 +227,9 @@ impl RawOptionsApply for RawOptions {
       node,
       dev_server,
       builtins,
+      profile: self.profile,
+      records_input_path: self.records_input_path.map(PathBuf::from),
+      records_output_path: self.records_output_path.map(PathBuf::from),
     })
   }
 }
@@ -1,16 +1,20 @@
 use napi_derive::napi;
 use rspack_core::{Builtins, Define, Minification, PluginExt, PresetEnv};
 use rspack_error::internal_error;
+use rspack_plugin_circular_dependencies::CircularDependenciesPlugin;
 use rspack_plugin_copy::CopyPlugin;
 use rspack_plugin_css::{plugin::CssConfig, CssPlugin};
 use rspack_plugin_dev_friendly_split_chunks::DevFriendlySplitChunksPlugin;
+use rspack_plugin_duplicate_packages::DuplicatePackagesPlugin;
 use rspack_plugin_html::HtmlPlugin;
 use rspack_plugin_progress::ProgressPlugin;
 use serde::Deserialize;
 
+mod raw_circular_dependencies;
 mod raw_copy;
 mod raw_css;
 mod raw_decorator;
+mod raw_duplicate_packages;
 mod raw_html;
 mod raw_plugin_import;
 mod raw_postcss;
@@ -18,8 +22,10 @@ mod raw_progress;
 mod raw_react;
 mod raw_relay;
 
+pub use raw_circular_dependencies::*;
 pub use raw_css::*;
 pub use raw_decorator::*;
+pub use raw_duplicate_packages::*;
 pub use raw_html::*;
 pub use raw_postcss::*;
 pub use raw_progress::*;
@@ -94,6 +100,8 @@ pub struct RawBuiltins {
   pub copy: Option<RawCopyConfig>,
   pub plugin_import: Option<Vec<RawPluginImportConfig>>,
   pub relay: Option<RawRelayConfig>,
+  pub circular_dependencies: Option<RawCircularDependenciesPluginConfig>,
+  pub duplicate_packages: Option<RawDuplicatePackagesPluginConfig>,
 }
 
 impl RawOptionsApply for RawBuiltins {
@@ -129,6 +137,12 @@ impl RawOptionsApply for RawBuiltins {
     if let Some(copy) = self.copy {
       plugins.push(CopyPlugin::new(copy.patterns.into_iter().map(Into::into).collect()).boxed());
     }
+    if let Some(circular_dependencies) = self.circular_dependencies {
+      plugins.push(CircularDependenciesPlugin::new(circular_dependencies.try_into()?).boxed());
+    }
+    if let Some(duplicate_packages) = self.duplicate_packages {
+      plugins.push(DuplicatePackagesPlugin::new(duplicate_packages.try_into()?).boxed());
+    }
 
     Ok(Builtins {
       minify_options: self.minify_options.map(Into::into),
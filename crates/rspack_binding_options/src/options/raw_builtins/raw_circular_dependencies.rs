@@ -0,0 +1,33 @@
+use napi_derive::napi;
+use rspack_plugin_circular_dependencies::CircularDependenciesPluginOptions;
+use rspack_regex::RspackRegex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawCircularDependenciesPluginConfig {
+  pub include: Option<String>,
+  pub exclude: Option<String>,
+  pub fail_on_error: bool,
+  pub allow_async_cycles: bool,
+}
+
+impl TryFrom<RawCircularDependenciesPluginConfig> for CircularDependenciesPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCircularDependenciesPluginConfig) -> rspack_error::Result<Self> {
+    Ok(Self {
+      include: value
+        .include
+        .map(|source| RspackRegex::new(&source))
+        .transpose()?,
+      exclude: value
+        .exclude
+        .map(|source| RspackRegex::new(&source))
+        .transpose()?,
+      fail_on_error: value.fail_on_error,
+      allow_async_cycles: value.allow_async_cycles,
+    })
+  }
+}
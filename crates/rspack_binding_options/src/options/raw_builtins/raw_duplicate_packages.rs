@@ -0,0 +1,24 @@
+use napi_derive::napi;
+use rspack_plugin_duplicate_packages::DuplicatePackagesPluginOptions;
+use rspack_regex::RspackRegex;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawDuplicatePackagesPluginConfig {
+  pub exclude: Option<String>,
+}
+
+impl TryFrom<RawDuplicatePackagesPluginConfig> for DuplicatePackagesPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawDuplicatePackagesPluginConfig) -> rspack_error::Result<Self> {
+    Ok(Self {
+      exclude: value
+        .exclude
+        .map(|source| RspackRegex::new(&source))
+        .transpose()?,
+    })
+  }
+}
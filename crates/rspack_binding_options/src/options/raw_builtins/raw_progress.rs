@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 use napi_derive::napi;
-use rspack_plugin_progress::ProgressPluginConfig;
+use rspack_plugin_progress::{JsonLinesReporter, ProgressPluginConfig};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Debug, Serialize, Clone)]
@@ -7,12 +9,18 @@ use serde::{Deserialize, Serialize};
 #[napi(object)]
 pub struct RawProgressPluginConfig {
   pub prefix: Option<String>,
+  /// Print the progress events to stderr as JSON lines instead of drawing a progress bar.
+  pub json: Option<bool>,
 }
 
 impl From<RawProgressPluginConfig> for ProgressPluginConfig {
   fn from(value: RawProgressPluginConfig) -> Self {
     Self {
       prefix: value.prefix,
+      reporter: value
+        .json
+        .unwrap_or_default()
+        .then(|| Arc::new(JsonLinesReporter::stderr()) as _),
     }
   }
 }
@@ -1,13 +1,23 @@
 use napi_derive::napi;
-use rspack_core::EntryItem;
-use serde::{Deserialize, Serialize};
+use rspack_core::{EntryItem, Filename, PublicPath};
+use serde::Deserialize;
 
-#[derive(Deserialize, Debug, Serialize)]
+use super::RawLibraryOptions;
+
+#[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawEntryItem {
   pub import: Vec<String>,
   pub runtime: Option<String>,
+  pub layer: Option<String>,
+  pub depend_on: Option<Vec<String>>,
+  pub filename: Option<String>,
+  pub library: Option<RawLibraryOptions>,
+  /// `"false"` disables chunk loading of the entry.
+  pub chunk_loading: Option<String>,
+  pub async_chunks: Option<bool>,
+  pub public_path: Option<String>,
 }
 
 impl From<RawEntryItem> for EntryItem {
@@ -15,6 +25,13 @@ impl From<RawEntryItem> for EntryItem {
     Self {
       import: value.import,
       runtime: value.runtime,
+      layer: value.layer,
+      depend_on: value.depend_on,
+      filename: value.filename.map(Filename::from),
+      library: value.library.map(Into::into),
+      chunk_loading: value.chunk_loading.map(Into::into),
+      async_chunks: value.async_chunks,
+      public_path: value.public_path.map(PublicPath::from),
     }
   }
 }
@@ -1,75 +1,182 @@
 use std::collections::HashMap;
+use std::fmt::Debug;
 
+use napi::bindgen_prelude::*;
 use napi_derive::napi;
-use rspack_core::{ExternalItem, ExternalItemObject, ExternalItemValue};
+use rspack_core::{ExternalItem, ExternalItemFnCtx, ExternalItemObject, ExternalItemValue};
+use rspack_error::internal_error;
 use rspack_regex::RspackRegex;
 use serde::Deserialize;
+#[cfg(feature = "node-api")]
+use {
+  rspack_napi_shared::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode},
+  rspack_napi_shared::{NapiResultExt, NAPI_ENV},
+};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawExternalItem {
-  #[napi(ts_type = r#""string" | "regexp" | "object""#)]
+  #[napi(ts_type = r#""string" | "regexp" | "object" | "function""#)]
   pub r#type: String,
   pub string_payload: Option<String>,
   pub regexp_payload: Option<String>,
   pub object_payload: Option<HashMap<String, RawExternalItemValue>>,
+  #[serde(skip_deserializing)]
+  #[napi(
+    ts_type = r#"(value: RawExternalItemFnCtx) => Promise<RawExternalItemValue | undefined>"#
+  )]
+  pub fn_payload: Option<JsFunction>,
+}
+
+impl Debug for RawExternalItem {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("RawExternalItem")
+      .field("r#type", &self.r#type)
+      .field("string_payload", &self.string_payload)
+      .field("regexp_payload", &self.regexp_payload)
+      .field("object_payload", &self.object_payload)
+      .field("fn_payload", &"...")
+      .finish()
+  }
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct RawContextInfo {
+  pub issuer: String,
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct RawExternalItemFnCtx {
+  pub request: String,
+  pub context: String,
+  pub dependency_type: String,
+  pub context_info: RawContextInfo,
+}
+
+impl From<ExternalItemFnCtx> for RawExternalItemFnCtx {
+  fn from(value: ExternalItemFnCtx) -> Self {
+    Self {
+      request: value.request,
+      context: value.context,
+      dependency_type: value.dependency_type,
+      context_info: RawContextInfo {
+        issuer: value.context_info.issuer,
+      },
+    }
+  }
 }
 
 #[derive(Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawExternalItemValue {
-  #[napi(ts_type = r#""string" | "bool""#)]
+  #[napi(ts_type = r#""string" | "bool" | "array" | "object""#)]
   pub r#type: String,
   pub string_payload: Option<String>,
   pub bool_payload: Option<bool>,
+  pub array_payload: Option<Vec<String>>,
+  pub object_payload: Option<HashMap<String, Vec<String>>>,
 }
 
-impl From<RawExternalItemValue> for ExternalItemValue {
-  fn from(value: RawExternalItemValue) -> Self {
+impl TryFrom<RawExternalItemValue> for ExternalItemValue {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExternalItemValue) -> rspack_error::Result<Self> {
     match value.r#type.as_str() {
-      "string" => Self::String(
-        value
-          .string_payload
-          .expect("should have a string_payload when RawExternalItemValue.type is \"string\""),
-      ),
-      "bool" => Self::Bool(
-        value
-          .bool_payload
-          .expect("should have a bool_payload when RawExternalItemValue.type is \"bool\""),
-      ),
-      _ => unreachable!(),
+      "string" => Ok(Self::String(value.string_payload.ok_or_else(|| {
+        internal_error!("should have a string_payload when RawExternalItemValue.type is \"string\"")
+      })?)),
+      "bool" => Ok(Self::Bool(value.bool_payload.ok_or_else(|| {
+        internal_error!("should have a bool_payload when RawExternalItemValue.type is \"bool\"")
+      })?)),
+      "array" => {
+        let payload = value.array_payload.ok_or_else(|| {
+          internal_error!("should have a array_payload when RawExternalItemValue.type is \"array\"")
+        })?;
+        if payload.is_empty() {
+          return Err(internal_error!(
+            "The array value of an external should not be empty"
+          ));
+        }
+        Ok(Self::Array(payload))
+      }
+      "object" => {
+        let payload = value.object_payload.ok_or_else(|| {
+          internal_error!(
+            "should have a object_payload when RawExternalItemValue.type is \"object\""
+          )
+        })?;
+        if let Some(external_type) = payload.iter().find_map(|(k, v)| v.is_empty().then_some(k)) {
+          return Err(internal_error!(
+            "The value of the external type \"{external_type}\" should not be empty"
+          ));
+        }
+        Ok(Self::Object(payload.into_iter().collect()))
+      }
+      r#type => Err(internal_error!(
+        "Unsupported RawExternalItemValue.type \"{type}\""
+      )),
     }
   }
 }
 
-impl From<RawExternalItem> for ExternalItem {
-  fn from(value: RawExternalItem) -> Self {
+impl TryFrom<RawExternalItem> for ExternalItem {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawExternalItem) -> rspack_error::Result<Self> {
     match value.r#type.as_str() {
-      "string" => Self::from(
-        value
-          .string_payload
-          .expect("should have a string_payload when RawExternalItem.type is \"string\""),
-      ),
+      "string" => Ok(Self::from(value.string_payload.ok_or_else(|| {
+        internal_error!("should have a string_payload when RawExternalItem.type is \"string\"")
+      })?)),
       "regexp" => {
-        let payload = value
-          .regexp_payload
-          .expect("should have a regexp_payload when RawExternalItem.type is \"regexp\"");
-        let reg =
-          RspackRegex::new(&payload).expect("regex_payload is not a legal regex in rust side");
-        Self::from(reg)
+        let payload = value.regexp_payload.ok_or_else(|| {
+          internal_error!("should have a regexp_payload when RawExternalItem.type is \"regexp\"")
+        })?;
+        Ok(Self::from(RspackRegex::new(&payload)?))
       }
       "object" => {
         let payload: ExternalItemObject = value
           .object_payload
-          .expect("should have a object_payload when RawExternalItem.type is \"object\"")
+          .ok_or_else(|| {
+            internal_error!("should have a object_payload when RawExternalItem.type is \"object\"")
+          })?
           .into_iter()
-          .map(|(k, v)| (k, v.into()))
-          .collect();
-        payload.into()
+          .map(|(k, v)| Ok((k, v.try_into()?)))
+          .collect::<rspack_error::Result<_>>()?;
+        Ok(payload.into())
+      }
+      #[cfg(feature = "node-api")]
+      "function" => {
+        let fn_payload = value.fn_payload.ok_or_else(|| {
+          internal_error!("should have a fn_payload when RawExternalItem.type is \"function\"")
+        })?;
+        let fn_payload: ThreadsafeFunction<RawExternalItemFnCtx, Option<RawExternalItemValue>> =
+          NAPI_ENV.with(|env| -> anyhow::Result<_> {
+            let env = env
+              .borrow()
+              .expect("Failed to get env, did you forget to call it from node?");
+            let fn_payload =
+              rspack_binding_macros::js_fn_into_theadsafe_fn!(fn_payload, &Env::from(env));
+            Ok(fn_payload)
+          })?;
+
+        Ok(Self::Fn(Box::new(move |ctx| {
+          let result = fn_payload.call(ctx.into(), ThreadsafeFunctionCallMode::NonBlocking);
+          Box::pin(async move {
+            let value = result
+              .into_rspack_result()?
+              .await
+              .map_err(|err| internal_error!("Failed to call external function: {err}"))??;
+            value.map(TryInto::try_into).transpose()
+          })
+        })))
       }
-      _ => unreachable!(),
+      r#type => Err(internal_error!(
+        "Unsupported RawExternalItem.type \"{type}\""
+      )),
     }
   }
 }
@@ -230,6 +230,8 @@ pub struct RawModuleRule {
   pub generator: Option<RawModuleRuleGenerator>,
   pub resolve: Option<RawResolveOptions>,
   pub issuer: Option<RawRuleSetCondition>,
+  pub issuer_layer: Option<RawRuleSetCondition>,
+  pub layer: Option<String>,
   pub one_of: Option<Vec<RawModuleRule>>,
 }
 
@@ -245,6 +247,8 @@ impl Debug for RawModuleRule {
       .field("side_effects", &self.side_effects)
       .field("use", &self.r#use)
       .field("issuer", &self.issuer)
+      .field("issuer_layer", &self.issuer_layer)
+      .field("layer", &self.layer)
       .field("one_of", &self.one_of)
       .finish()
   }
@@ -589,6 +593,8 @@ impl TryFrom<RawModuleRule> for ModuleRule {
       resolve: value.resolve.map(|raw| raw.try_into()).transpose()?,
       side_effects: value.side_effects,
       issuer: value.issuer.map(|raw| raw.try_into()).transpose()?,
+      issuer_layer: value.issuer_layer.map(|raw| raw.try_into()).transpose()?,
+      layer: value.layer,
       one_of,
     })
   }
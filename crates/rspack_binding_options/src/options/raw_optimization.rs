@@ -1,20 +1,78 @@
 use napi_derive::napi;
 use rspack_core::{Optimization, PluginExt, SideEffectOption};
 use rspack_error::internal_error;
-use rspack_ids::{DeterministicModuleIdsPlugin, NamedModuleIdsPlugin};
+use rspack_ids::{
+  DeterministicChunkIdsPlugin, DeterministicModuleIdsPlugin, HashedModuleIdsPlugin,
+  HashedModuleIdsPluginOptions, NamedModuleIdsPlugin, NaturalChunkIdsPlugin,
+  NaturalModuleIdsPlugin, OccurrenceChunkIdsPlugin, OccurrenceModuleIdsPlugin,
+  StableNamedChunkIdsPlugin,
+};
 use rspack_plugin_split_chunks::SplitChunksPlugin;
 use serde::Deserialize;
 
 use crate::{RawOptionsApply, RawSplitChunksOptions};
 
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawHashedModuleIdsOptions {
+  pub context: Option<String>,
+  pub hash_function: Option<String>,
+  pub hash_digest: Option<String>,
+  pub hash_digest_length: Option<u32>,
+}
+
+impl TryFrom<RawHashedModuleIdsOptions> for HashedModuleIdsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawHashedModuleIdsOptions) -> Result<Self, Self::Error> {
+    let default = HashedModuleIdsPluginOptions::default();
+    Ok(Self {
+      context: value.context,
+      hash_function: match value.hash_function {
+        Some(hash_function) => hash_function.parse()?,
+        None => default.hash_function,
+      },
+      hash_digest: match value.hash_digest {
+        Some(hash_digest) => hash_digest.parse()?,
+        None => default.hash_digest,
+      },
+      hash_digest_length: value
+        .hash_digest_length
+        .map_or(default.hash_digest_length, |length| length as usize),
+    })
+  }
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawDeterministicChunkIdsOptions {
+  pub context: Option<String>,
+  pub max_length: Option<u32>,
+}
+
+impl From<RawDeterministicChunkIdsOptions> for DeterministicChunkIdsPlugin {
+  fn from(value: RawDeterministicChunkIdsOptions) -> Self {
+    Self::new(
+      value.max_length.map(|length| length as usize),
+      value.context,
+    )
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawOptimizationOptions {
   pub split_chunks: Option<RawSplitChunksOptions>,
   pub module_ids: String,
+  pub chunk_ids: String,
+  pub hashed_module_ids: Option<RawHashedModuleIdsOptions>,
+  pub deterministic_chunk_ids: Option<RawDeterministicChunkIdsOptions>,
   pub remove_available_modules: bool,
   pub side_effects: String,
+  pub provided_exports: bool,
 }
 
 impl RawOptionsApply for RawOptimizationOptions {
@@ -29,18 +87,46 @@ impl RawOptionsApply for RawOptimizationOptions {
       plugins.push(split_chunks_plugin);
     }
     let module_ids_plugin = match self.module_ids.as_ref() {
+      "natural" => NaturalModuleIdsPlugin::default().boxed(),
       "named" => NamedModuleIdsPlugin::default().boxed(),
+      "hashed" => HashedModuleIdsPlugin::new(
+        self
+          .hashed_module_ids
+          .map(TryInto::try_into)
+          .transpose()?
+          .unwrap_or_default(),
+      )
+      .boxed(),
       "deterministic" => DeterministicModuleIdsPlugin::default().boxed(),
+      "size" => OccurrenceModuleIdsPlugin::new(true).boxed(),
       _ => {
         return Err(internal_error!(
-          "'module_ids' should be 'named' or 'deterministic'."
+          "'module_ids' should be 'natural', 'named', 'hashed', 'deterministic' or 'size'."
         ))
       }
     };
     plugins.push(module_ids_plugin);
+    let chunk_ids_plugin = match self.chunk_ids.as_ref() {
+      "natural" => NaturalChunkIdsPlugin::default().boxed(),
+      "named" => StableNamedChunkIdsPlugin::new(None, None).boxed(),
+      "deterministic" => self
+        .deterministic_chunk_ids
+        .map(DeterministicChunkIdsPlugin::from)
+        .unwrap_or_default()
+        .boxed(),
+      "size" => OccurrenceChunkIdsPlugin::new(true).boxed(),
+      "total-size" => OccurrenceChunkIdsPlugin::new(false).boxed(),
+      _ => {
+        return Err(internal_error!(
+          "'chunk_ids' should be 'natural', 'named', 'deterministic', 'size' or 'total-size'."
+        ))
+      }
+    };
+    plugins.push(chunk_ids_plugin);
     Ok(Optimization {
       remove_available_modules: self.remove_available_modules,
       side_effects: SideEffectOption::from(self.side_effects.as_str()),
+      provided_exports: self.provided_exports,
     })
   }
 }
@@ -2,6 +2,7 @@ use napi_derive::napi;
 use rspack_core::{
   BoxPlugin, LibraryAuxiliaryComment, LibraryName, LibraryOptions, OutputOptions, PluginExt,
 };
+use rspack_error::internal_error;
 use serde::Deserialize;
 
 use crate::RawOptionsApply;
@@ -83,9 +84,14 @@ pub struct RawOutputOptions {
   pub css_filename: String,
   pub css_chunk_filename: String,
   pub unique_name: String,
+  pub devtool_namespace: String,
+  pub devtool_module_filename_template: String,
+  pub devtool_fallback_module_filename_template: String,
   pub library: Option<RawLibraryOptions>,
   pub strict_module_error_handling: bool,
   pub enabled_library_types: Option<Vec<String>>,
+  pub chunk_loading: String,
+  pub enabled_chunk_loading_types: Option<Vec<String>>,
   pub global_object: String,
   pub import_function_name: String,
   pub iife: bool,
@@ -97,13 +103,16 @@ pub struct RawOutputOptions {
 impl RawOptionsApply for RawOutputOptions {
   type Options = OutputOptions;
   fn apply(self, plugins: &mut Vec<BoxPlugin>) -> Result<OutputOptions, rspack_error::Error> {
-    self.apply_library_plugin(plugins);
+    self.apply_library_plugin(plugins)?;
     Ok(OutputOptions {
       path: self.path.into(),
       public_path: self.public_path.into(),
       asset_module_filename: self.asset_module_filename.into(),
       webassembly_module_filename: self.webassembly_module_filename.into(),
       unique_name: self.unique_name,
+      devtool_namespace: self.devtool_namespace,
+      devtool_module_filename_template: self.devtool_module_filename_template,
+      devtool_fallback_module_filename_template: self.devtool_fallback_module_filename_template,
       filename: self.filename.into(),
       chunk_filename: self.chunk_filename.into(),
       css_filename: self.css_filename.into(),
@@ -111,6 +120,8 @@ impl RawOptionsApply for RawOutputOptions {
       library: self.library.map(Into::into),
       strict_module_error_handling: self.strict_module_error_handling,
       enabled_library_types: self.enabled_library_types,
+      chunk_loading: self.chunk_loading.into(),
+      enabled_chunk_loading_types: self.enabled_chunk_loading_types,
       global_object: self.global_object,
       import_function_name: self.import_function_name,
       iife: self.iife,
@@ -120,7 +131,7 @@ impl RawOptionsApply for RawOutputOptions {
 }
 
 impl RawOutputOptions {
-  fn apply_library_plugin(&self, plugins: &mut Vec<BoxPlugin>) {
+  fn apply_library_plugin(&self, plugins: &mut Vec<BoxPlugin>) -> rspack_error::Result<()> {
     if let Some(enabled_library_types) = &self.enabled_library_types {
       for library in enabled_library_types {
         match library.as_str() {
@@ -237,7 +248,21 @@ impl RawOutputOptions {
             );
           }
           "umd" | "umd2" => {
-            plugins.push(rspack_plugin_library::UmdLibraryPlugin::new("umd2".eq(library)).boxed());
+            plugins.push(
+              rspack_plugin_library::UmdLibraryPlugin::new("umd2".eq(library), library.clone())
+                .boxed(),
+            );
+          }
+          "module" => {
+            if !self.module {
+              return Err(internal_error!(
+                "Library type \"module\" is only supported with \"output.module: true\""
+              ));
+            }
+            plugins.push(rspack_plugin_library::ModuleLibraryPlugin::default().boxed());
+          }
+          "system" => {
+            plugins.push(rspack_plugin_library::SystemLibraryPlugin::default().boxed());
           }
           "amd" | "amd-require" => {
             plugins.push(
@@ -248,5 +273,6 @@ impl RawOutputOptions {
         }
       }
     }
+    Ok(())
   }
 }
@@ -1,5 +1,6 @@
 use napi_derive::napi;
-use rspack_core::{SnapshotOptions, SnapshotStrategy};
+use rspack_core::{SnapshotOptions, SnapshotPath, SnapshotStrategy};
+use rspack_regex::RspackRegex;
 use serde::Deserialize;
 
 #[derive(Deserialize, Debug, Default)]
@@ -19,21 +20,61 @@ impl From<RawSnapshotStrategy> for SnapshotStrategy {
   }
 }
 
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+#[napi(object)]
+pub struct RawSnapshotPath {
+  #[napi(ts_type = r#""string" | "regexp""#)]
+  pub r#type: String,
+  pub string_payload: Option<String>,
+  pub regexp_payload: Option<String>,
+}
+
+impl From<RawSnapshotPath> for SnapshotPath {
+  fn from(value: RawSnapshotPath) -> Self {
+    match value.r#type.as_str() {
+      "string" => Self::String(
+        value
+          .string_payload
+          .expect("should have a string_payload when RawSnapshotPath.type is \"string\""),
+      ),
+      "regexp" => {
+        let payload = value
+          .regexp_payload
+          .expect("should have a regexp_payload when RawSnapshotPath.type is \"regexp\"");
+        Self::RegExp(
+          RspackRegex::new(&payload).expect("regexp_payload is not a legal regex in rust side"),
+        )
+      }
+      _ => unreachable!(),
+    }
+  }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 #[napi(object)]
 pub struct RawSnapshotOptions {
   pub resolve: RawSnapshotStrategy,
   pub module: RawSnapshotStrategy,
+  pub immutable_paths: Vec<RawSnapshotPath>,
+  pub managed_paths: Vec<RawSnapshotPath>,
 }
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
   fn from(value: RawSnapshotOptions) -> Self {
-    let RawSnapshotOptions { resolve, module } = value;
+    let RawSnapshotOptions {
+      resolve,
+      module,
+      immutable_paths,
+      managed_paths,
+    } = value;
 
     SnapshotOptions {
       resolve: resolve.into(),
       module: module.into(),
+      immutable_paths: immutable_paths.into_iter().map(Into::into).collect(),
+      managed_paths: managed_paths.into_iter().map(Into::into).collect(),
     }
   }
 }
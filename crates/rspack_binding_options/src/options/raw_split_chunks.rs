@@ -1,7 +1,9 @@
 use std::{collections::HashMap, sync::Arc};
 
 use napi_derive::napi;
-use rspack_plugin_split_chunks::{CacheGroupOptions, ChunkType, SplitChunksOptions, TestFn};
+use rspack_plugin_split_chunks::{
+  CacheGroupOptions, ChunkType, LayerFn, SplitChunksOptions, TestFn,
+};
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
@@ -12,11 +14,12 @@ pub struct RawSplitChunksOptions {
   /// What kind of chunks should be selected.
   pub chunks: Option<String>,
   //   pub automatic_name_delimiter: String,
+  pub filename: Option<String>,
   pub max_async_requests: Option<u32>,
   pub max_initial_requests: Option<u32>,
   //   pub default_size_types: Option<Vec<SizeType>>,
   pub min_chunks: Option<u32>,
-  // hide_path_info: bool,
+  pub hide_path_info: Option<bool>,
   pub min_size: Option<f64>,
   //   pub min_size_reduction: usize,
   pub enforce_size_threshold: Option<f64>,
@@ -30,6 +33,8 @@ pub struct RawSplitChunksOptions {
 impl From<RawSplitChunksOptions> for SplitChunksOptions {
   fn from(value: RawSplitChunksOptions) -> Self {
     let mut defaults = SplitChunksOptions {
+      filename: value.filename,
+      hide_path_info: value.hide_path_info,
       max_async_requests: value.max_async_requests,
       max_initial_requests: value.max_initial_requests,
       min_chunks: value.min_chunks,
@@ -76,6 +81,18 @@ impl From<RawSplitChunksOptions> for SplitChunksOptions {
                   _ => panic!("Invalid chunk type: {chunks}"),
                 }),
                 min_chunks: v.min_chunks,
+                filename: v.filename,
+                layer: v.layer.map(|layer| {
+                  // Align with `checkModuleLayer` of webpack, an empty string selects modules without a layer.
+                  let f: LayerFn = Arc::new(move |module_layer| {
+                    if layer.is_empty() {
+                      module_layer.is_none()
+                    } else {
+                      module_layer.map_or(false, |module_layer| module_layer.starts_with(&layer))
+                    }
+                  });
+                  f
+                }),
                 ..Default::default()
               },
             )
@@ -93,7 +110,7 @@ pub struct RawCacheGroupOptions {
   pub reuse_existing_chunk: Option<bool>,
   //   pub r#type: SizeType,
   pub test: Option<String>,
-  //   pub filename: String,
+  pub filename: Option<String>,
   //   pub enforce: bool,
   //   pub id_hint: String,
   /// What kind of chunks should be selected.
@@ -102,12 +119,11 @@ pub struct RawCacheGroupOptions {
   //   pub max_async_requests: usize,
   //   pub max_initial_requests: usize,
   pub min_chunks: Option<u32>,
-  // hide_path_info: bool,
   //   pub min_size: usize,
   //   pub min_size_reduction: usize,
   //   pub enforce_size_threshold: usize,
   //   pub min_remaining_size: usize,
-  // layer: String,
+  pub layer: Option<String>,
   //   pub max_size: usize,
   //   pub max_async_size: usize,
   //   pub max_initial_size: usize,
@@ -7,14 +7,54 @@ use serde::Deserialize;
 #[napi(object)]
 pub struct RawStatsOptions {
   pub colors: bool,
+  pub hash: bool,
+  pub timings: bool,
+  pub built_at: bool,
+  pub plugin_timings: bool,
+  pub public_path: bool,
+  pub output_path: bool,
+  pub assets: bool,
+  pub chunks: bool,
+  pub chunk_modules: bool,
+  pub chunk_relations: bool,
+  pub modules: bool,
   pub reasons: bool,
+  pub used_exports: bool,
+  pub provided_exports: bool,
+  pub optimization_bailout: bool,
+  pub entrypoints: bool,
+  pub chunk_groups: bool,
+  pub errors: bool,
+  pub errors_count: bool,
+  pub warnings: bool,
+  pub warnings_count: bool,
 }
 
 impl From<RawStatsOptions> for StatsOptions {
   fn from(value: RawStatsOptions) -> Self {
     Self {
       colors: value.colors,
+      hash: value.hash,
+      timings: value.timings,
+      built_at: value.built_at,
+      plugin_timings: value.plugin_timings,
+      public_path: value.public_path,
+      output_path: value.output_path,
+      assets: value.assets,
+      chunks: value.chunks,
+      chunk_modules: value.chunk_modules,
+      chunk_relations: value.chunk_relations,
+      modules: value.modules,
       reasons: value.reasons,
+      used_exports: value.used_exports,
+      provided_exports: value.provided_exports,
+      optimization_bailout: value.optimization_bailout,
+      entrypoints: value.entrypoints,
+      chunk_groups: value.chunk_groups,
+      errors: value.errors,
+      errors_count: value.errors_count,
+      warnings: value.warnings,
+      warnings_count: value.warnings_count,
     }
   }
 }
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use rspack_core::Compiler;
+use rspack_core::{Compiler, Stats};
 use rspack_fs::AsyncNativeFileSystem;
 use rspack_testing::apply_from_fixture;
 
@@ -32,4 +32,8 @@ async fn run(config_path: PathBuf) {
     .build()
     .await
     .unwrap_or_else(|err| panic!("{err:?}, failed to compile {abs_path:?}"));
+  let stats = Stats::new(&compiler.compilation)
+    .to_string(&compiler.options.stats)
+    .unwrap_or_else(|err| panic!("{err:?}, failed to print stats of {abs_path:?}"));
+  println!("{stats}");
 }
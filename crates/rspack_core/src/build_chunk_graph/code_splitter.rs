@@ -4,7 +4,10 @@ use rspack_identifier::IdentifierSet;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use super::remove_parent_modules::RemoveParentModulesContext;
-use crate::{ChunkGroup, ChunkGroupKind, ChunkGroupUkey, ChunkUkey, Compilation, ModuleIdentifier};
+use crate::{
+  ChunkGroup, ChunkGroupKind, ChunkGroupUkey, ChunkUkey, Compilation, EntryData, ModuleIdentifier,
+  RuntimeSpec,
+};
 
 pub(super) struct CodeSplitter<'me> {
   pub(super) compilation: &'me mut Compilation,
@@ -43,6 +46,27 @@ impl<'me> CodeSplitter<'me> {
     for (name, entry_data) in entries.iter() {
       let options = &entry_data.options;
       let dependencies = &entry_data.dependencies;
+      if options.depend_on.is_some() && options.runtime.is_some() {
+        return Err(
+          anyhow!(
+            "Entrypoint '{name}' has 'dependOn' and 'runtime' specified. This is not valid.
+Entrypoints that depend on other entrypoints do not have their own runtime.
+They will use the runtime(s) from referenced entrypoints instead.
+Remove the 'runtime' option from the entrypoint."
+          )
+          .into(),
+        );
+      }
+      if options
+        .depend_on
+        .as_ref()
+        .map_or(false, |depend_on| depend_on.is_empty())
+      {
+        return Err(
+          anyhow!("Entrypoint '{name}' has an empty 'dependOn', it should depend on at least one entrypoint.")
+            .into(),
+        );
+      }
       let module_identifiers = dependencies
         .iter()
         .filter_map(|dep| module_graph.module_identifier_by_dependency_id(dep))
@@ -54,6 +78,9 @@ impl<'me> CodeSplitter<'me> {
         &mut compilation.named_chunks,
       );
       chunk.chunk_reasons.push(format!("Entrypoint({name})",));
+      if let Some(filename) = &options.filename {
+        chunk.filename_template = Some(filename.clone());
+      }
       self
         .remove_parent_modules_context
         .add_root_chunk(chunk.ukey);
@@ -71,12 +98,13 @@ impl<'me> CodeSplitter<'me> {
 
       let mut entrypoint = ChunkGroup::new(
         ChunkGroupKind::Entrypoint,
-        HashSet::from_iter([name.to_string()]),
+        get_entry_runtime(name, &entries),
         Some(name.to_string()),
       );
-      if options.runtime.is_none() {
+      if options.runtime.is_none() && options.depend_on.is_none() {
         entrypoint.set_runtime_chunk(chunk.ukey);
       }
+      entrypoint.set_entry_options(options.clone());
       entrypoint.set_entry_point_chunk(chunk.ukey);
       entrypoint.connect_chunk(chunk);
       // compilation.chunk_graph.con
@@ -150,9 +178,108 @@ impl<'me> CodeSplitter<'me> {
         entry_point.set_runtime_chunk(chunk.ukey);
       }
     }
+
+    for (name, entry_data) in entries.iter() {
+      let Some(depend_on) = &entry_data.options.depend_on else {
+        continue;
+      };
+      let ukey = *compilation
+        .entrypoints
+        .get(name)
+        .ok_or_else(|| anyhow!("no entrypoints found"))?;
+
+      for dependency in depend_on {
+        let dependency_ukey = *compilation.entrypoints.get(dependency).ok_or_else(|| {
+          anyhow!("Entry {name} depends on {dependency}, but this entry was not found")
+        })?;
+        compilation
+          .chunk_group_by_ukey
+          .get_mut(&dependency_ukey)
+          .ok_or_else(|| anyhow!("no chunk group found"))?
+          .children
+          .insert(ukey);
+        compilation
+          .chunk_group_by_ukey
+          .get_mut(&ukey)
+          .ok_or_else(|| anyhow!("no chunk group found"))?
+          .parents
+          .insert(dependency_ukey);
+      }
+
+      // Entries with `dependOn` use the runtime chunk of the first entry they depend on.
+      let mut visited = HashSet::from_iter([name.as_str()]);
+      let mut current = depend_on
+        .first()
+        .ok_or_else(|| anyhow!("no dependOn found for entrypoint {name}"))?;
+      while let Some(next) = entries
+        .get(current)
+        .and_then(|entry_data| entry_data.options.depend_on.as_ref()?.first())
+      {
+        if !visited.insert(current) {
+          return Err(
+            anyhow!("Entrypoints '{name}' and '{current}' use 'dependOn' to depend on each other in a circular way.")
+              .into(),
+          );
+        }
+        current = next;
+      }
+      let runtime_chunk = compilation
+        .entrypoints
+        .get(current)
+        .and_then(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+        .and_then(|entrypoint| entrypoint.runtime_chunk)
+        .ok_or_else(|| anyhow!("no runtime chunk found for entrypoint {current}"))?;
+      compilation
+        .chunk_group_by_ukey
+        .get_mut(&ukey)
+        .ok_or_else(|| anyhow!("no chunk group found"))?
+        .set_runtime_chunk(runtime_chunk);
+    }
     Ok(input_entrypoints_and_modules)
   }
 
+  /// Modules of the entries depended on by `dependOn` are loaded before the entry chunk,
+  /// so there is no need to duplicate them in the entry chunk.
+  fn remove_modules_of_depended_entries(&mut self) {
+    let compilation = &mut self.compilation;
+    for entrypoint_ukey in compilation.entrypoints.values() {
+      let entrypoint = compilation
+        .chunk_group_by_ukey
+        .get(entrypoint_ukey)
+        .expect("chunk group not found");
+      if entrypoint
+        .get_entry_options()
+        .and_then(|options| options.depend_on.as_ref())
+        .is_none()
+      {
+        continue;
+      }
+
+      let available_modules = entrypoint
+        .ancestors(&compilation.chunk_group_by_ukey)
+        .iter()
+        .filter_map(|ukey| compilation.chunk_group_by_ukey.get(ukey))
+        .flat_map(|chunk_group| chunk_group.chunks.iter())
+        .flat_map(|chunk| compilation.chunk_graph.get_chunk_module_identifiers(chunk))
+        .copied()
+        .collect::<IdentifierSet>();
+      let chunk = entrypoint.get_entry_point_chunk();
+      let entry_modules = compilation.chunk_graph.get_chunk_entry_modules(&chunk);
+      let removed_modules = compilation
+        .chunk_graph
+        .get_chunk_module_identifiers(&chunk)
+        .iter()
+        .filter(|module| available_modules.contains(*module) && !entry_modules.contains(*module))
+        .copied()
+        .collect::<Vec<_>>();
+      for module in removed_modules {
+        compilation
+          .chunk_graph
+          .disconnect_chunk_and_module(&chunk, module);
+      }
+    }
+  }
+
   #[tracing::instrument(skip_all)]
   pub fn split(mut self) -> Result<()> {
     let input_entrypoints_and_modules = self.prepare_input_entrypoints_and_modules()?;
@@ -192,6 +319,8 @@ impl<'me> CodeSplitter<'me> {
     }
     tracing::trace!("--- process_queue end ---");
 
+    self.remove_modules_of_depended_entries();
+
     for chunk_group in self.compilation.chunk_group_by_ukey.values() {
       for chunk_ukey in chunk_group.chunks.iter() {
         self
@@ -346,6 +475,36 @@ impl<'me> CodeSplitter<'me> {
       .into_iter()
       .rev()
     {
+      let async_chunks = self
+        .compilation
+        .chunk_group_by_ukey
+        .get(&item.chunk_group)
+        .and_then(|chunk_group| chunk_group.get_entry_options())
+        .and_then(|options| options.async_chunks)
+        .unwrap_or(true);
+      if !async_chunks {
+        // Dynamically imported modules are placed in the chunk of the importer, the import
+        // resolves with the chunks of the current chunk group which are already loaded.
+        if !self
+          .compilation
+          .chunk_graph
+          .block_to_chunk_group_ukey
+          .contains_key(module_identifier)
+        {
+          self
+            .compilation
+            .chunk_graph
+            .connect_block_and_chunk_group(*module_identifier, item.chunk_group);
+        }
+        self.queue.push(QueueItem {
+          action: QueueAction::AddAndEnter,
+          chunk: item.chunk,
+          chunk_group: item.chunk_group,
+          module_identifier: *module_identifier,
+        });
+        continue;
+      }
+
       let is_already_split_module = self.split_point_modules.contains(module_identifier);
 
       if is_already_split_module {
@@ -436,4 +595,37 @@ enum QueueAction {
   Leave,
 }
 
+/// Same as `getEntryRuntime` of webpack, entries with `dependOn` share the runtime of the
+/// entries they depend on.
+fn get_entry_runtime(name: &str, entries: &HashMap<String, EntryData>) -> RuntimeSpec {
+  let Some(depend_on) = entries
+    .get(name)
+    .and_then(|entry_data| entry_data.options.depend_on.as_ref())
+  else {
+    return RuntimeSpec::from_iter([name.to_string()]);
+  };
+
+  let mut runtime = RuntimeSpec::default();
+  let mut visited = HashSet::default();
+  let mut queue = depend_on.iter().collect::<Vec<_>>();
+  while let Some(dependency) = queue.pop() {
+    if !visited.insert(dependency) {
+      continue;
+    }
+    let Some(entry_data) = entries.get(dependency) else {
+      continue;
+    };
+    match &entry_data.options.depend_on {
+      Some(depend_on) => queue.extend(depend_on),
+      None => {
+        runtime.insert(dependency.to_string());
+      }
+    }
+  }
+  if runtime.is_empty() {
+    runtime.insert(name.to_string());
+  }
+  runtime
+}
+
 // struct chunkGroupInfoMap {}
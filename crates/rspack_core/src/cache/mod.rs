@@ -6,7 +6,7 @@ use std::{
   },
 };
 
-use crate::CompilerOptions;
+use crate::{calc_hash, Builtins, CacheOptions, CompilerOptions};
 
 mod local;
 mod occasion;
@@ -17,7 +17,7 @@ use occasion::{
   BuildModuleOccasion, CodeGenerateOccasion, CreateChunkAssetsOccasion, ResolveModuleOccasion,
 };
 use snapshot::SnapshotManager;
-use storage::new_storage;
+use storage::{new_storage, FileSystemStorageOptions};
 
 #[derive(Debug)]
 pub struct Cache {
@@ -31,20 +31,48 @@ pub struct Cache {
 
 impl Cache {
   pub fn new(options: Arc<CompilerOptions>) -> Self {
-    let snapshot_manager = Arc::new(SnapshotManager::new(options.snapshot.clone()));
+    let mut snapshot_options = options.snapshot.clone();
+    let fs_options = match &options.cache {
+      CacheOptions::FileSystem(cache_options) => {
+        // Entries restored in a new process can't rely on the watcher to know modified files,
+        // so make sure that every snapshot records something to check.
+        for strategy in [&mut snapshot_options.resolve, &mut snapshot_options.module] {
+          if !strategy.hash && !strategy.timestamp {
+            strategy.timestamp = true;
+          }
+        }
+        Some(Arc::new(FileSystemStorageOptions::new(
+          cache_options,
+          options.context.as_ref(),
+          options_hash(&options),
+        )))
+      }
+      _ => None,
+    };
+    let fs_options = fs_options.as_ref();
+
+    let snapshot_manager = Arc::new(SnapshotManager::new(snapshot_options));
     Self {
       is_idle: true.into(),
       snapshot_manager: snapshot_manager.clone(),
       resolve_module_occasion: ResolveModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&options.cache, fs_options, "resolve"),
         snapshot_manager.clone(),
       ),
       build_module_occasion: BuildModuleOccasion::new(
-        new_storage(&options.cache),
+        new_storage(&options.cache, fs_options, "build"),
         snapshot_manager,
       ),
-      code_generate_occasion: CodeGenerateOccasion::new(new_storage(&options.cache)),
-      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(&options.cache)),
+      code_generate_occasion: CodeGenerateOccasion::new(new_storage(
+        &options.cache,
+        fs_options,
+        "code-generate",
+      )),
+      create_chunk_assets_occasion: CreateChunkAssetsOccasion::new(new_storage(
+        &options.cache,
+        fs_options,
+        "chunk-assets",
+      )),
     }
   }
 
@@ -59,6 +87,10 @@ impl Cache {
       .is_ok()
     {
       self.snapshot_manager.clear();
+      self.resolve_module_occasion.begin_idle();
+      self.build_module_occasion.begin_idle();
+      self.code_generate_occasion.begin_idle();
+      self.create_chunk_assets_occasion.begin_idle();
     }
   }
 
@@ -66,3 +98,33 @@ impl Cache {
     self.is_idle.store(false, Ordering::Release);
   }
 }
+
+/// Hashes the options which affect the stored results, e.g. `devtool` and `output.filename`,
+/// so entries stored with other options are not restored.
+///
+/// Maps are hashed in the order of their keys, the iteration order of std maps differs between processes.
+fn options_hash(options: &CompilerOptions) -> u64 {
+  let mut entry = options.entry.iter().collect::<Vec<_>>();
+  entry.sort_unstable_by_key(|(name, _)| *name);
+  let mut define = options.builtins.define.iter().collect::<Vec<_>>();
+  define.sort_unstable();
+  let builtins = Builtins {
+    define: Default::default(),
+    ..options.builtins.clone()
+  };
+  calc_hash(&format!(
+    "{:?}",
+    (
+      entry,
+      &options.output,
+      &options.target,
+      &options.mode,
+      &options.devtool,
+      &options.experiments,
+      &options.node,
+      &options.optimization,
+      builtins,
+      define,
+    )
+  ))
+}
@@ -1,12 +1,20 @@
-use std::{path::Path, sync::Arc};
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 
 use futures::Future;
 use rspack_error::{Result, TWithDiagnosticArray};
+use rustc_hash::FxHashSet as HashSet;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
-  BoxModule, BuildResult,
+  cache::storage::{self, Serializable},
+  BoxModule, BuildInfo, BuildMeta, BuildResult, CodeGeneratable, CodeGeneratableContext,
+  CodeGeneratableResult, Dependency, DependencyCategory, DependencyId, DependencyType, ErrorSpan,
+  ModuleDependency, ModuleIdentifier,
 };
 
 type Storage = dyn storage::Storage<(Snapshot, TWithDiagnosticArray<BuildResult>)>;
@@ -25,14 +33,24 @@ impl BuildModuleOccasion {
     }
   }
 
-  pub async fn use_cache<'a, G, F>(
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  /// `is_restorable` tells whether the other occasions could restore the module without
+  /// building it, e.g. whether its code generation result is cached.
+  pub async fn use_cache<'a, G, F, R>(
     &self,
     module: &'a mut BoxModule,
+    is_restorable: R,
     generator: G,
   ) -> Result<TWithDiagnosticArray<BuildResult>>
   where
     G: Fn(&'a mut BoxModule) -> F,
     F: Future<Output = Result<TWithDiagnosticArray<BuildResult>>>,
+    R: Fn(&ModuleIdentifier) -> bool,
   {
     let storage = match &self.storage {
       Some(s) => s,
@@ -45,16 +63,9 @@ impl BuildModuleOccasion {
     if module.as_normal_module().is_some() {
       // normal module
       // TODO cache all module type
-      if let Some((snapshot, data)) = storage.get(&id) {
-        let valid = self
-          .snapshot_manager
-          .check_snapshot_valid(&snapshot)
-          .await
-          .unwrap_or(false);
-        if valid {
-          return Ok(data);
-        }
-      };
+      if let Some(data) = self.restore(storage.as_ref(), &id, is_restorable).await {
+        return Ok(data);
+      }
       need_cache = true;
     }
 
@@ -103,4 +114,226 @@ impl BuildModuleOccasion {
     }
     Ok(data)
   }
+
+  async fn restore<R>(
+    &self,
+    storage: &Storage,
+    id: &ModuleIdentifier,
+    is_restorable: R,
+  ) -> Option<TWithDiagnosticArray<BuildResult>>
+  where
+    R: Fn(&ModuleIdentifier) -> bool,
+  {
+    // Cached entries of the occasions expire and fail to persist independently,
+    // build the module again if the others can't restore it.
+    if !is_restorable(id) {
+      return None;
+    }
+    let (snapshot, data) = storage.get(id)?;
+    let valid = self
+      .snapshot_manager
+      .check_snapshot_valid(&snapshot)
+      .await
+      .unwrap_or(false);
+    valid.then_some(data)
+  }
+}
+
+/// A dependency restored from a persistent cache.
+///
+/// Modules restored from cache are not built again, their code generation results are
+/// restored as well, so only the information needed to build the module graph is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct CachedDependency {
+  #[serde(skip)]
+  id: Option<DependencyId>,
+  #[serde(skip)]
+  parent_module_identifier: Option<ModuleIdentifier>,
+  category: DependencyCategory,
+  dependency_type: DependencyType,
+  request: String,
+  user_request: String,
+  span: Option<ErrorSpan>,
+}
+
+impl Dependency for CachedDependency {
+  fn id(&self) -> Option<DependencyId> {
+    self.id
+  }
+
+  fn set_id(&mut self, id: Option<DependencyId>) {
+    self.id = id;
+  }
+
+  fn parent_module_identifier(&self) -> Option<&ModuleIdentifier> {
+    self.parent_module_identifier.as_ref()
+  }
+
+  fn set_parent_module_identifier(&mut self, module_identifier: Option<ModuleIdentifier>) {
+    self.parent_module_identifier = module_identifier;
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &self.category
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &self.dependency_type
+  }
+}
+
+impl ModuleDependency for CachedDependency {
+  fn request(&self) -> &str {
+    &self.request
+  }
+
+  fn user_request(&self) -> &str {
+    &self.user_request
+  }
+
+  fn span(&self) -> Option<&ErrorSpan> {
+    self.span.as_ref()
+  }
+}
+
+impl CodeGeneratable for CachedDependency {
+  fn generate(
+    &self,
+    _code_generatable_context: &mut CodeGeneratableContext,
+  ) -> Result<CodeGeneratableResult> {
+    Ok(CodeGeneratableResult::default())
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedBuildResult {
+  strict_harmony_module: bool,
+  is_async: bool,
+  esm: bool,
+  hash: u64,
+  strict: bool,
+  file_dependencies: HashSet<PathBuf>,
+  context_dependencies: HashSet<PathBuf>,
+  missing_dependencies: HashSet<PathBuf>,
+  build_dependencies: HashSet<PathBuf>,
+  dependencies: Vec<CachedDependency>,
+}
+
+impl Serializable for TWithDiagnosticArray<BuildResult> {
+  fn serialize(&self) -> Option<Value> {
+    // Keep results with diagnostics in memory, so they are reported again on the next start.
+    if !self.diagnostic.is_empty() {
+      return None;
+    }
+    let BuildResult {
+      build_meta,
+      build_info,
+      dependencies,
+    } = &self.inner;
+    let dependencies = dependencies
+      .iter()
+      .map(|dependency| {
+        // Context dependencies carry a regexp which is not serializable yet.
+        if dependency.options().is_some() {
+          return None;
+        }
+        Some(CachedDependency {
+          id: None,
+          parent_module_identifier: None,
+          category: *dependency.category(),
+          dependency_type: *dependency.dependency_type(),
+          request: dependency.request().to_string(),
+          user_request: dependency.user_request().to_string(),
+          span: dependency.span().copied(),
+        })
+      })
+      .collect::<Option<Vec<_>>>()?;
+
+    serde_json::to_value(SerializedBuildResult {
+      strict_harmony_module: build_meta.strict_harmony_module,
+      is_async: build_meta.is_async,
+      esm: build_meta.esm,
+      hash: build_info.hash,
+      strict: build_info.strict,
+      file_dependencies: build_info.file_dependencies.clone(),
+      context_dependencies: build_info.context_dependencies.clone(),
+      missing_dependencies: build_info.missing_dependencies.clone(),
+      build_dependencies: build_info.build_dependencies.clone(),
+      dependencies,
+    })
+    .ok()
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let result = serde_json::from_value::<SerializedBuildResult>(value).ok()?;
+    Some(TWithDiagnosticArray::new(
+      BuildResult {
+        build_meta: BuildMeta {
+          strict_harmony_module: result.strict_harmony_module,
+          is_async: result.is_async,
+          esm: result.esm,
+        },
+        build_info: BuildInfo {
+          cacheable: true,
+          hash: result.hash,
+          strict: result.strict,
+          file_dependencies: result.file_dependencies,
+          context_dependencies: result.context_dependencies,
+          missing_dependencies: result.missing_dependencies,
+          build_dependencies: result.build_dependencies,
+        },
+        dependencies: result
+          .dependencies
+          .into_iter()
+          .map(|dependency| Box::new(dependency) as Box<dyn ModuleDependency>)
+          .collect(),
+      },
+      vec![],
+    ))
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::{
+    cache::{occasion::CodeGenerateOccasion, storage::new_storage},
+    CacheOptions, MemoryCacheOptions, SnapshotOptions,
+  };
+
+  #[tokio::test]
+  async fn skip_modules_without_cached_code_generation_result() {
+    let options = CacheOptions::Memory(MemoryCacheOptions::default());
+    let snapshot_manager = Arc::new(SnapshotManager::new(SnapshotOptions::default()));
+    let occasion = BuildModuleOccasion::new(
+      new_storage(&options, None, "build"),
+      snapshot_manager.clone(),
+    );
+    let code_generate_occasion =
+      CodeGenerateOccasion::new(new_storage(&options, None, "code-generate"));
+
+    let id = ModuleIdentifier::from("javascript/auto|/a.js");
+    let snapshot = snapshot_manager
+      .create_snapshot(&[], |option| &option.module)
+      .await
+      .expect("should create snapshot");
+    let storage = occasion.storage.as_ref().expect("should have storage");
+    storage.set(
+      id,
+      (
+        snapshot,
+        TWithDiagnosticArray::new(BuildResult::default(), vec![]),
+      ),
+    );
+
+    let is_restorable = |id: &ModuleIdentifier| code_generate_occasion.has_cache(id);
+    assert!(occasion
+      .restore(storage.as_ref(), &id, is_restorable)
+      .await
+      .is_none());
+    assert!(occasion
+      .restore(storage.as_ref(), &id, |_| true)
+      .await
+      .is_some());
+  }
 }
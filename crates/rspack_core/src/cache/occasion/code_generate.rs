@@ -1,6 +1,14 @@
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
+use rspack_sources::BoxSource;
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::{cache::storage, BoxModule, CodeGenerationResult, NormalModuleAstOrSource};
+use crate::{
+  cache::storage::{self, Serializable},
+  AstOrSource, BoxModule, CodeGenerationResult, ModuleIdentifier, NormalModuleAstOrSource,
+  RuntimeGlobals, SourceType,
+};
 
 type Storage = dyn storage::Storage<CodeGenerationResult>;
 
@@ -14,7 +22,23 @@ impl CodeGenerateOccasion {
     Self { storage }
   }
 
-  #[allow(clippy::unwrap_in_result)]
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
+  /// Whether the code generation result of the module is cached.
+  ///
+  /// Modules restored by the build occasion are not built, so they can only be restored
+  /// when their code generation result is cached as well.
+  pub fn has_cache(&self, id: &ModuleIdentifier) -> bool {
+    self
+      .storage
+      .as_ref()
+      .map_or(false, |storage| storage.contains(id))
+  }
+
   pub fn use_cache<'a, G>(
     &self,
     module: &'a BoxModule,
@@ -38,8 +62,10 @@ impl CodeGenerateOccasion {
         if let Some(data) = storage.get(&id) {
           return Ok(data);
         }
-        // unbuild and no cache is unexpected
-        panic!("unexpected unbuild module");
+        // the build occasion only restores modules with cached code generation results
+        return Err(internal_error!(
+          "code generation result of unbuilt module {id} is not cached"
+        ));
       }
       need_cache = true;
     }
@@ -52,3 +78,45 @@ impl CodeGenerateOccasion {
     Ok(data)
   }
 }
+
+#[derive(Serialize, Deserialize)]
+struct SerializedCodeGenerationResult {
+  sources: Vec<(SourceType, Value)>,
+  data: HashMap<String, String>,
+  runtime_requirements: u64,
+}
+
+impl Serializable for CodeGenerationResult {
+  fn serialize(&self) -> Option<Value> {
+    let sources = self
+      .inner()
+      .iter()
+      .map(|(source_type, result)| {
+        // Ast results can't be restored.
+        let source = result.ast_or_source.as_source()?;
+        Some((*source_type, source.serialize()?))
+      })
+      .collect::<Option<Vec<_>>>()?;
+    serde_json::to_value(SerializedCodeGenerationResult {
+      sources,
+      data: self.data.clone(),
+      runtime_requirements: self.runtime_requirements.bits(),
+    })
+    .ok()
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let result = serde_json::from_value::<SerializedCodeGenerationResult>(value).ok()?;
+    let mut code_generation_result = CodeGenerationResult::default();
+    code_generation_result.data = result.data;
+    code_generation_result.runtime_requirements =
+      RuntimeGlobals::from_bits(result.runtime_requirements)?;
+    for (source_type, source) in result.sources {
+      code_generation_result.add(
+        source_type,
+        AstOrSource::Source(BoxSource::deserialize(source)?),
+      );
+    }
+    Some(code_generation_result)
+  }
+}
@@ -1,10 +1,16 @@
 use futures::Future;
 use rspack_error::Result;
 use rspack_identifier::Identifier;
+use rspack_sources::BoxSource;
 
-use crate::{cache::storage, Chunk, Compilation, NormalModuleAstOrSource, RenderManifestEntry};
+use crate::{
+  cache::storage, Chunk, Compilation, NormalModuleAstOrSource, PathData, RenderManifestEntry,
+};
 
-type Storage = dyn storage::Storage<Vec<RenderManifestEntry>>;
+/// The rendered assets of a chunk, stored as `(filename, source)`.
+///
+/// Chunk ukeys are not stable between compilations, so they are attached when the assets are restored.
+type Storage = dyn storage::Storage<Vec<(String, BoxSource)>>;
 
 #[derive(Debug)]
 pub struct CreateChunkAssetsOccasion {
@@ -16,6 +22,12 @@ impl CreateChunkAssetsOccasion {
     Self { storage }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     compilation: &Compilation,
@@ -51,13 +63,32 @@ impl CreateChunkAssetsOccasion {
     if is_cache_valid {
       // read
       if let Some(data) = storage.get(&chunk_id) {
-        return Ok(data);
+        return Ok(
+          data
+            .into_iter()
+            .map(|(filename, source)| {
+              RenderManifestEntry::new(
+                source,
+                filename,
+                PathData {
+                  chunk_ukey: chunk.ukey,
+                },
+              )
+            })
+            .collect(),
+        );
       }
     }
     // run generator and save to cache
     let data = generator().await?;
     // TODO sometime may not run save
-    storage.set(chunk_id, data.clone());
+    storage.set(
+      chunk_id,
+      data
+        .iter()
+        .map(|entry| (entry.filename().to_string(), entry.source().clone()))
+        .collect(),
+    );
     Ok(data)
   }
 }
@@ -1,10 +1,12 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
 use futures::Future;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
   cache::snapshot::{Snapshot, SnapshotManager},
-  cache::storage,
+  cache::storage::{self, Serializable},
   ModuleIdentifier, ResolveArgs, ResolveError, ResolveResult,
 };
 
@@ -24,6 +26,12 @@ impl ResolveModuleOccasion {
     }
   }
 
+  pub fn begin_idle(&self) {
+    if let Some(storage) = &self.storage {
+      storage.begin_idle();
+    }
+  }
+
   pub async fn use_cache<'a, G, F>(
     &self,
     args: ResolveArgs<'a>,
@@ -80,3 +88,51 @@ impl ResolveModuleOccasion {
     Ok(data)
   }
 }
+
+#[derive(Serialize, Deserialize)]
+enum SerializedResolveResult {
+  Resource {
+    path: PathBuf,
+    query: Option<String>,
+    fragment: Option<String>,
+  },
+  Ignored,
+}
+
+impl Serializable for ResolveResult {
+  fn serialize(&self) -> Option<Value> {
+    let result = match self {
+      ResolveResult::Resource(resource) => {
+        // The description file data can't be restored without the resolver,
+        // so these results are only cached in memory.
+        if resource.description.is_some() {
+          return None;
+        }
+        SerializedResolveResult::Resource {
+          path: resource.path.clone(),
+          query: resource.query.clone(),
+          fragment: resource.fragment.clone(),
+        }
+      }
+      ResolveResult::Ignored => SerializedResolveResult::Ignored,
+    };
+    serde_json::to_value(result).ok()
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let result = match serde_json::from_value::<SerializedResolveResult>(value).ok()? {
+      SerializedResolveResult::Resource {
+        path,
+        query,
+        fragment,
+      } => ResolveResult::Resource(nodejs_resolver::Resource {
+        path,
+        query,
+        fragment,
+        description: None,
+      }),
+      SerializedResolveResult::Ignored => ResolveResult::Ignored,
+    };
+    Some(result)
+  }
+}
@@ -6,7 +6,7 @@ use std::{
 
 use dashmap::{DashMap, DashSet};
 use rspack_error::Result;
-use rustc_hash::{FxHashMap as HashMap, FxHasher};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
 use super::Snapshot;
 use crate::{calc_hash, SnapshotOptions, SnapshotStrategy};
@@ -23,16 +23,29 @@ pub struct SnapshotManager {
   update_time_cache: DashMap<PathBuf, SystemTime, BuildHasherDefault<FxHasher>>,
   /// cache file hash
   hash_cache: DashMap<PathBuf, u64, BuildHasherDefault<FxHasher>>,
+  /// cache `name@version` of managed package directories
+  managed_info_cache: DashMap<PathBuf, Option<String>, BuildHasherDefault<FxHasher>>,
   /// has modified file
   modified_files: DashSet<PathBuf>,
 }
 
+/// How a file is tracked by a snapshot.
+enum SnapshotKind {
+  /// The file is never checked again.
+  Immutable,
+  /// The file is checked by the package it belongs to.
+  Managed(PathBuf),
+  /// The file is checked by the snapshot strategy.
+  Unmanaged,
+}
+
 impl SnapshotManager {
   pub fn new(options: SnapshotOptions) -> Self {
     Self {
       options,
       update_time_cache: Default::default(),
       hash_cache: Default::default(),
+      managed_info_cache: Default::default(),
       modified_files: Default::default(),
     }
   }
@@ -41,20 +54,44 @@ impl SnapshotManager {
   where
     F: FnOnce(&SnapshotOptions) -> &SnapshotStrategy,
   {
-    // TODO file_paths deduplication && calc immutable path
     let strategy = f(&self.options);
+    let mut unmanaged_paths = Vec::with_capacity(paths.len());
+    let mut managed_items = HashMap::default();
+    let mut visited = HashSet::default();
+    for &path in paths {
+      if !visited.insert(path) {
+        continue;
+      }
+      match self.snapshot_kind(path) {
+        SnapshotKind::Immutable => {}
+        SnapshotKind::Managed(package_path) => {
+          if managed_items.contains_key(&package_path) {
+            continue;
+          }
+          match self.managed_info(&package_path).await {
+            Some(info) => {
+              managed_items.insert(package_path, info);
+            }
+            // Packages without a valid package.json are tracked like other files.
+            None => unmanaged_paths.push(path),
+          }
+        }
+        SnapshotKind::Unmanaged => unmanaged_paths.push(path),
+      }
+    }
+
     let mut file_update_times = HashMap::default();
-    file_update_times.reserve(paths.len());
+    file_update_times.reserve(unmanaged_paths.len());
     let mut file_hashes = HashMap::default();
-    file_hashes.reserve(paths.len());
+    file_hashes.reserve(unmanaged_paths.len());
     if strategy.timestamp {
-      for &path in paths {
+      for &path in &unmanaged_paths {
         file_update_times.insert(path.to_owned(), SystemTime::now());
       }
     }
     if strategy.hash {
       let hash_cache = &self.hash_cache;
-      for &path in paths {
+      for &path in &unmanaged_paths {
         let hash = match hash_cache.get(path) {
           Some(hash) => *hash,
           None => {
@@ -70,6 +107,7 @@ impl SnapshotManager {
     Ok(Snapshot {
       file_update_times,
       file_hashes,
+      managed_items,
     })
   }
 
@@ -77,7 +115,7 @@ impl SnapshotManager {
     let Snapshot {
       file_update_times,
       file_hashes,
-      ..
+      managed_items,
     } = snapshot;
     if !file_update_times.is_empty() {
       // check update time
@@ -124,12 +162,20 @@ impl SnapshotManager {
       }
     }
 
+    // check managed package version
+    for (package_path, snapshot_info) in managed_items {
+      if self.managed_info(package_path).await.as_ref() != Some(snapshot_info) {
+        return Ok(false);
+      }
+    }
+
     Ok(true)
   }
 
   pub fn clear(&self) {
     self.update_time_cache.clear();
     self.hash_cache.clear();
+    self.managed_info_cache.clear();
     self.modified_files.clear();
   }
 
@@ -138,4 +184,131 @@ impl SnapshotManager {
       self.modified_files.insert(item);
     }
   }
+
+  fn snapshot_kind(&self, path: &Path) -> SnapshotKind {
+    let Some(path_str) = path.to_str() else {
+      return SnapshotKind::Unmanaged;
+    };
+    if self
+      .options
+      .immutable_paths
+      .iter()
+      .any(|item| item.match_prefix(path_str).is_some())
+    {
+      return SnapshotKind::Immutable;
+    }
+    self
+      .options
+      .managed_paths
+      .iter()
+      .find_map(|item| {
+        item
+          .match_prefix(path_str)
+          .and_then(|managed_path| get_managed_item(managed_path, path_str))
+      })
+      .map(|package_path| SnapshotKind::Managed(PathBuf::from(package_path)))
+      .unwrap_or(SnapshotKind::Unmanaged)
+  }
+
+  /// Get `name@version` from the package.json in `package_path`.
+  async fn managed_info(&self, package_path: &Path) -> Option<String> {
+    if let Some(info) = self.managed_info_cache.get(package_path) {
+      return info.clone();
+    }
+    let info = tokio::fs::read(package_path.join("package.json"))
+      .await
+      .ok()
+      .and_then(|content| serde_json::from_slice::<serde_json::Value>(&content).ok())
+      .and_then(|json| {
+        let name = json.get("name")?.as_str()?;
+        let version = json.get("version")?.as_str()?;
+        Some(format!("{name}@{version}"))
+      });
+    self
+      .managed_info_cache
+      .insert(package_path.to_path_buf(), info.clone());
+    info
+  }
+}
+
+/// Get the package directory of `path`, which is inside `managed_path`.
+///
+/// e.g. `/project/node_modules/@scope/pkg/lib/index.js` with managed path `/project/node_modules/`
+/// gives `/project/node_modules/@scope/pkg`.
+/// Nested `node_modules` directories are handled as well.
+fn get_managed_item<'a>(managed_path: &str, path: &'a str) -> Option<&'a str> {
+  let bytes = path.as_bytes();
+  let mut i = managed_path.len();
+  let mut slashes = 1;
+  let mut starting_position = true;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' | b'\\' => {
+        slashes -= 1;
+        if slashes == 0 {
+          break;
+        }
+        starting_position = true;
+      }
+      // hidden directories like `.pnpm` or `.cache` are not packages
+      b'.' if starting_position => return None,
+      b'@' => {
+        if !starting_position {
+          return None;
+        }
+        slashes += 1;
+      }
+      _ => starting_position = false,
+    }
+    i += 1;
+  }
+  if i == bytes.len() {
+    slashes -= 1;
+  }
+  if slashes != 0 {
+    return None;
+  }
+  // the package has its own node_modules, the file may belong to a nested package
+  let rest = &path[i..];
+  if rest.len() > "/node_modules/".len()
+    && rest[1..].starts_with("node_modules")
+    && matches!(rest.as_bytes()["/node_modules".len()], b'/' | b'\\')
+  {
+    if let Some(nested) = get_managed_item(&path[..i + "/node_modules/".len()], path) {
+      return Some(nested);
+    }
+  }
+  Some(&path[..i])
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn managed_item() {
+    let managed_path = "/project/node_modules/";
+    assert_eq!(
+      get_managed_item(managed_path, "/project/node_modules/react/index.js"),
+      Some("/project/node_modules/react")
+    );
+    assert_eq!(
+      get_managed_item(
+        managed_path,
+        "/project/node_modules/@scope/pkg/lib/index.js"
+      ),
+      Some("/project/node_modules/@scope/pkg")
+    );
+    assert_eq!(
+      get_managed_item(
+        managed_path,
+        "/project/node_modules/a/node_modules/b/index.js"
+      ),
+      Some("/project/node_modules/a/node_modules/b")
+    );
+    assert_eq!(
+      get_managed_item(managed_path, "/project/node_modules/.cache/a.js"),
+      None
+    );
+  }
 }
@@ -1,6 +1,13 @@
-use std::{path::PathBuf, time::SystemTime};
+use std::{
+  path::PathBuf,
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::storage::Serializable;
 
 mod manager;
 pub use manager::SnapshotManager;
@@ -10,4 +17,56 @@ pub use manager::SnapshotManager;
 pub struct Snapshot {
   pub file_update_times: HashMap<PathBuf, SystemTime>,
   pub file_hashes: HashMap<PathBuf, u64>,
+  /// `name@version` of the packages in managed paths
+  pub managed_items: HashMap<PathBuf, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedSnapshot {
+  /// Update times as `(seconds, nanoseconds)` since the unix epoch.
+  file_update_times: Vec<(PathBuf, (u64, u32))>,
+  file_hashes: Vec<(PathBuf, u64)>,
+  managed_items: Vec<(PathBuf, String)>,
+}
+
+impl Serializable for Snapshot {
+  fn serialize(&self) -> Option<Value> {
+    let file_update_times = self
+      .file_update_times
+      .iter()
+      .map(|(path, time)| {
+        let duration = time.duration_since(UNIX_EPOCH).ok()?;
+        Some((path.clone(), (duration.as_secs(), duration.subsec_nanos())))
+      })
+      .collect::<Option<Vec<_>>>()?;
+    let file_hashes = self
+      .file_hashes
+      .iter()
+      .map(|(path, hash)| (path.clone(), *hash))
+      .collect();
+    let managed_items = self
+      .managed_items
+      .iter()
+      .map(|(path, info)| (path.clone(), info.clone()))
+      .collect();
+    serde_json::to_value(SerializedSnapshot {
+      file_update_times,
+      file_hashes,
+      managed_items,
+    })
+    .ok()
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let snapshot = serde_json::from_value::<SerializedSnapshot>(value).ok()?;
+    Some(Self {
+      file_update_times: snapshot
+        .file_update_times
+        .into_iter()
+        .map(|(path, (secs, nanos))| (path, UNIX_EPOCH + Duration::new(secs, nanos)))
+        .collect(),
+      file_hashes: snapshot.file_hashes.into_iter().collect(),
+      managed_items: snapshot.managed_items.into_iter().collect(),
+    })
+  }
 }
@@ -0,0 +1,261 @@
+use std::{
+  hash::BuildHasherDefault,
+  path::{Path, PathBuf},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use dashmap::DashMap;
+use rspack_identifier::{Identifier, IdentifierHasher};
+use rustc_hash::FxHashMap as HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Serializable, Storage};
+use crate::{calc_hash, FileSystemCacheOptions};
+
+/// Unused entries are dropped after one month if `max_age` is not configured.
+const DEFAULT_MAX_AGE: Duration = Duration::from_millis(2_592_000_000);
+
+/// Options shared by all filesystem storages of a compiler.
+#[derive(Debug)]
+pub struct FileSystemStorageOptions {
+  /// Directory where the pack files are stored.
+  location: PathBuf,
+  /// Packs written with a different version are ignored.
+  ///
+  /// It's derived from the rspack version, `cache.version`, the content of `cache.buildDependencies`
+  /// and the hash of the compiler options.
+  version: String,
+  max_age: Duration,
+}
+
+impl FileSystemStorageOptions {
+  pub fn new(options: &FileSystemCacheOptions, context: &Path, options_hash: u64) -> Self {
+    let location = if !options.cache_location.is_empty() {
+      context.join(&options.cache_location)
+    } else {
+      let directory = if options.cache_directory.is_empty() {
+        context.join("node_modules/.cache/rspack")
+      } else {
+        context.join(&options.cache_directory)
+      };
+      let name = if options.name.is_empty() {
+        "default"
+      } else {
+        options.name.as_str()
+      };
+      directory.join(name)
+    };
+
+    let mut build_dependencies = options
+      .build_dependencies
+      .iter()
+      .map(|dependency| context.join(dependency))
+      .collect::<Vec<_>>();
+    build_dependencies.sort();
+    let build_dependencies_hash = calc_hash(
+      &build_dependencies
+        .iter()
+        // A missing build dependency is hashed by its path, so creating it later invalidates the cache.
+        .map(|path| (path, std::fs::read(path).ok()))
+        .collect::<Vec<_>>(),
+    );
+
+    Self {
+      location,
+      version: format!(
+        "{}|{}|{:016x}|{:016x}",
+        env!("CARGO_PKG_VERSION"),
+        options.version,
+        build_dependencies_hash,
+        options_hash
+      ),
+      max_age: if options.max_age == 0 {
+        DEFAULT_MAX_AGE
+      } else {
+        Duration::from_millis(options.max_age as u64)
+      },
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Pack {
+  version: String,
+  entries: HashMap<String, PackEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PackEntry {
+  /// Last time the entry was written or read, in milliseconds since the unix epoch.
+  time: u64,
+  data: Value,
+}
+
+fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+/// A storage which keeps its items in memory and writes them to a pack file
+/// in the cache location when the compiler becomes idle.
+///
+/// The pack is loaded on creation, so items stored by a previous process can be
+/// reused after they are validated by the occasion.
+#[derive(Debug)]
+pub struct FileSystemStorage<Item> {
+  options: Arc<FileSystemStorageOptions>,
+  path: PathBuf,
+  data: DashMap<Identifier, (Item, u64), BuildHasherDefault<IdentifierHasher>>,
+  changed: AtomicBool,
+}
+
+impl<Item> FileSystemStorage<Item>
+where
+  Item: Serializable,
+{
+  pub fn new(options: Arc<FileSystemStorageOptions>, scope: &str) -> Self {
+    let path = options.location.join(format!("{scope}.pack"));
+    let data = DashMap::default();
+    let mut changed = false;
+
+    if let Some(pack) = std::fs::read(&path)
+      .ok()
+      .and_then(|content| serde_json::from_slice::<Pack>(&content).ok())
+      .filter(|pack| pack.version == options.version)
+    {
+      let expired_before = now().saturating_sub(options.max_age.as_millis() as u64);
+      for (id, entry) in pack.entries {
+        if entry.time < expired_before {
+          changed = true;
+          continue;
+        }
+        if let Some(item) = Item::deserialize(entry.data) {
+          data.insert(Identifier::from(id), (item, entry.time));
+        }
+      }
+    }
+
+    Self {
+      options,
+      path,
+      data,
+      changed: AtomicBool::new(changed),
+    }
+  }
+
+  fn write_pack(&self) -> std::io::Result<()> {
+    let entries = self
+      .data
+      .iter()
+      .filter_map(|entry| {
+        let (item, time) = entry.value();
+        item
+          .serialize()
+          .map(|data| (entry.key().to_string(), PackEntry { time: *time, data }))
+      })
+      .collect();
+    let pack = Pack {
+      version: self.options.version.clone(),
+      entries,
+    };
+
+    std::fs::create_dir_all(&self.options.location)?;
+    // Write to a temporary file first, so an interrupted write never leaves a broken pack.
+    let temp_path = self.path.with_extension("pack_");
+    std::fs::write(&temp_path, serde_json::to_vec(&pack)?)?;
+    std::fs::rename(temp_path, &self.path)
+  }
+}
+
+impl<Item> Storage<Item> for FileSystemStorage<Item>
+where
+  Item: Serializable + Clone + std::fmt::Debug + Send + Sync,
+{
+  fn get(&self, id: &Identifier) -> Option<Item> {
+    self.data.get_mut(id).map(|mut entry| {
+      entry.1 = now();
+      entry.0.clone()
+    })
+  }
+
+  fn contains(&self, id: &Identifier) -> bool {
+    self.data.contains_key(id)
+  }
+
+  fn set(&self, id: Identifier, data: Item) {
+    self.data.insert(id, (data, now()));
+    self.changed.store(true, Ordering::Release);
+  }
+
+  fn begin_idle(&self) {
+    if self
+      .changed
+      .compare_exchange(true, false, Ordering::AcqRel, Ordering::Relaxed)
+      .is_ok()
+    {
+      if let Err(err) = self.write_pack() {
+        tracing::warn!("Failed to write cache pack {}: {err}", self.path.display());
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn restore_items_from_pack() {
+    let context = std::env::temp_dir().join(format!("rspack_fs_storage_{}", now()));
+    let options = Arc::new(FileSystemStorageOptions::new(
+      &FileSystemCacheOptions {
+        cache_location: ".cache".to_string(),
+        ..Default::default()
+      },
+      &context,
+      0,
+    ));
+
+    let storage = FileSystemStorage::<String>::new(options.clone(), "test");
+    storage.set(Identifier::from("a"), "content".to_string());
+    storage.begin_idle();
+
+    let storage = FileSystemStorage::<String>::new(options, "test");
+    assert_eq!(
+      storage.get(&Identifier::from("a")),
+      Some("content".to_string())
+    );
+
+    let options = Arc::new(FileSystemStorageOptions::new(
+      &FileSystemCacheOptions {
+        cache_location: ".cache".to_string(),
+        version: "next".to_string(),
+        ..Default::default()
+      },
+      &context,
+      0,
+    ));
+    let storage = FileSystemStorage::<String>::new(options, "test");
+    assert_eq!(storage.get(&Identifier::from("a")), None);
+
+    let options = Arc::new(FileSystemStorageOptions::new(
+      &FileSystemCacheOptions {
+        cache_location: ".cache".to_string(),
+        ..Default::default()
+      },
+      &context,
+      1,
+    ));
+    let storage = FileSystemStorage::<String>::new(options, "test");
+    assert_eq!(storage.get(&Identifier::from("a")), None);
+
+    std::fs::remove_dir_all(context).expect("should remove temp dir");
+  }
+}
@@ -1,26 +1,144 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, sync::Arc};
 
 use rspack_identifier::Identifier;
+use rspack_sources::{
+  BoxSource, MapOptions, RawSource, Source, SourceExt, SourceMap, SourceMapSource,
+  SourceMapSourceOptions,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::CacheOptions;
 
+mod filesystem;
 mod memory;
+use filesystem::FileSystemStorage;
+pub use filesystem::FileSystemStorageOptions;
 use memory::MemoryStorage;
 
 pub trait Storage<Item>: Debug + Send + Sync {
   fn get(&self, id: &Identifier) -> Option<Item>;
+  fn contains(&self, id: &Identifier) -> bool;
   fn set(&self, id: Identifier, data: Item);
-  // fn begin_idle(&self);
+  /// Called when the compiler becomes idle, persistent storages flush their data here.
+  fn begin_idle(&self) {}
   // fn end_idle(&self);
   // fn clear(&self);
 }
 
-pub fn new_storage<Item>(options: &CacheOptions) -> Option<Box<dyn Storage<Item>>>
+/// Items which could be written to a persistent storage.
+///
+/// `serialize` returns `None` for items that can't be restored in another process,
+/// these items are only kept in memory.
+pub trait Serializable: Sized {
+  fn serialize(&self) -> Option<Value>;
+  fn deserialize(value: Value) -> Option<Self>;
+}
+
+impl Serializable for String {
+  fn serialize(&self) -> Option<Value> {
+    Some(Value::String(self.clone()))
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    match value {
+      Value::String(s) => Some(s),
+      _ => None,
+    }
+  }
+}
+
+impl<A: Serializable, B: Serializable> Serializable for (A, B) {
+  fn serialize(&self) -> Option<Value> {
+    Some(Value::Array(vec![self.0.serialize()?, self.1.serialize()?]))
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let Value::Array(items) = value else {
+      return None;
+    };
+    let [a, b]: [Value; 2] = items.try_into().ok()?;
+    Some((A::deserialize(a)?, B::deserialize(b)?))
+  }
+}
+
+impl<T: Serializable> Serializable for Vec<T> {
+  fn serialize(&self) -> Option<Value> {
+    self
+      .iter()
+      .map(|item| item.serialize())
+      .collect::<Option<Vec<_>>>()
+      .map(Value::Array)
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let Value::Array(items) = value else {
+      return None;
+    };
+    items.into_iter().map(T::deserialize).collect()
+  }
+}
+
+#[derive(Serialize, Deserialize)]
+enum SerializedSource {
+  Source { source: String, map: Option<String> },
+  Buffer(String),
+}
+
+/// Sources are restored as [SourceMapSource] or [RawSource], the structure of the original source is not kept.
+impl Serializable for BoxSource {
+  fn serialize(&self) -> Option<Value> {
+    let source = match self.as_ref().as_any().downcast_ref::<RawSource>() {
+      Some(raw) if raw.is_buffer() => {
+        SerializedSource::Buffer(rspack_base64::encode_to_string(raw.buffer()))
+      }
+      _ => SerializedSource::Source {
+        source: self.source().into_owned(),
+        map: match self.map(&MapOptions::default()) {
+          Some(map) => Some(map.to_json().ok()?),
+          None => None,
+        },
+      },
+    };
+    serde_json::to_value(source).ok()
+  }
+
+  fn deserialize(value: Value) -> Option<Self> {
+    let source = match serde_json::from_value::<SerializedSource>(value).ok()? {
+      SerializedSource::Source { source, map: None } => RawSource::from(source).boxed(),
+      SerializedSource::Source {
+        source,
+        map: Some(map),
+      } => SourceMapSource::new(SourceMapSourceOptions {
+        value: source,
+        name: String::new(),
+        source_map: SourceMap::from_json(&map).ok()?,
+        original_source: None,
+        inner_source_map: None,
+        remove_original_source: false,
+      })
+      .boxed(),
+      SerializedSource::Buffer(buffer) => {
+        RawSource::from(rspack_base64::decode_to_vec(buffer)?).boxed()
+      }
+    };
+    Some(source)
+  }
+}
+
+pub fn new_storage<Item>(
+  options: &CacheOptions,
+  fs_options: Option<&Arc<FileSystemStorageOptions>>,
+  scope: &str,
+) -> Option<Box<dyn Storage<Item>>>
 where
-  Item: Debug + Clone + Send + Sync + 'static,
+  Item: Serializable + Debug + Clone + Send + Sync + 'static,
 {
-  match options {
-    CacheOptions::Disabled => None,
+  match (options, fs_options) {
+    (CacheOptions::Disabled, _) => None,
+    (CacheOptions::FileSystem(_), Some(fs_options)) => {
+      Some(Box::new(FileSystemStorage::new(fs_options.clone(), scope)))
+    }
     _ => Some(Box::new(MemoryStorage::new())),
   }
 }
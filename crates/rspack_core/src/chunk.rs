@@ -8,8 +8,8 @@ use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupKind, ChunkGroupUkey, ChunkUkey, ModuleGraph,
-  RuntimeSpec, SourceType,
+  ChunkGraph, ChunkGroupByUkey, ChunkGroupKind, ChunkGroupUkey, ChunkUkey, EntryOptions, Filename,
+  ModuleGraph, RuntimeSpec, SourceType,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,6 +36,8 @@ pub struct Chunk {
   pub hash: Xxh3,
   pub content_hash: HashMap<SourceType, String>,
   pub chunk_reasons: Vec<String>,
+  /// Overrides the filename template of the chunk, e.g. the `filename` of an entry.
+  pub filename_template: Option<Filename>,
 }
 
 impl DatabaseItem for Chunk {
@@ -73,6 +75,7 @@ impl Chunk {
       hash: Default::default(),
       content_hash: HashMap::default(),
       chunk_reasons: Default::default(),
+      filename_template: None,
     }
   }
 
@@ -176,6 +179,18 @@ impl Chunk {
     chunks
   }
 
+  /// Options of the entry if the chunk belongs to an entrypoint.
+  pub fn get_entry_options<'a>(
+    &self,
+    chunk_group_by_ukey: &'a ChunkGroupByUkey,
+  ) -> Option<&'a EntryOptions> {
+    self
+      .groups
+      .iter()
+      .filter_map(|ukey| chunk_group_by_ukey.get(ukey))
+      .find_map(|group| group.entry_options.as_ref())
+  }
+
   pub fn has_runtime(&self, chunk_group_by_ukey: &ChunkGroupByUkey) -> bool {
     self
       .groups
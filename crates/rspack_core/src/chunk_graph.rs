@@ -248,6 +248,17 @@ impl ChunkGraph {
     cgc.modules.len()
   }
 
+  pub fn is_entry_module_in_chunk(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    chunk_ukey: ChunkUkey,
+  ) -> bool {
+    let chunk_graph_chunk = self.get_chunk_graph_chunk(&chunk_ukey);
+    chunk_graph_chunk
+      .entry_modules
+      .contains_key(module_identifier)
+  }
+
   pub fn get_number_of_entry_modules(&self, chunk: &ChunkUkey) -> usize {
     let cgc = self.get_chunk_graph_chunk(chunk);
     cgc.entry_modules.len()
@@ -3,7 +3,8 @@ use rspack_identifier::IdentifierMap;
 use rustc_hash::FxHashSet as HashSet;
 
 use crate::{
-  Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, ModuleIdentifier, RuntimeSpec,
+  Chunk, ChunkByUkey, ChunkGroupByUkey, ChunkGroupUkey, ChunkUkey, EntryOptions, ModuleIdentifier,
+  RuntimeSpec,
 };
 
 impl DatabaseItem for ChunkGroup {
@@ -29,6 +30,7 @@ pub struct ChunkGroup {
   // pub(crate) name: Option<String>,
   pub(crate) runtime_chunk: Option<ChunkUkey>,
   pub(crate) entry_point_chunk: Option<ChunkUkey>,
+  pub(crate) entry_options: Option<EntryOptions>,
 }
 
 impl ChunkGroup {
@@ -47,6 +49,7 @@ impl ChunkGroup {
       // name,
       runtime_chunk: None,
       entry_point_chunk: None,
+      entry_options: None,
     }
   }
 
@@ -83,10 +86,14 @@ impl ChunkGroup {
     chunk.add_group(self.ukey);
   }
 
-  pub(crate) fn is_initial(&self) -> bool {
+  pub fn is_initial(&self) -> bool {
     matches!(self.kind, ChunkGroupKind::Entrypoint)
   }
 
+  pub fn parents_iterable(&self) -> impl Iterator<Item = &ChunkGroupUkey> {
+    self.parents.iter()
+  }
+
   pub fn set_runtime_chunk(&mut self, chunk_ukey: ChunkUkey) {
     self.runtime_chunk = Some(chunk_ukey);
   }
@@ -100,6 +107,14 @@ impl ChunkGroup {
     }
   }
 
+  pub fn set_entry_options(&mut self, options: EntryOptions) {
+    self.entry_options = Some(options);
+  }
+
+  pub fn get_entry_options(&self) -> Option<&EntryOptions> {
+    self.entry_options.as_ref()
+  }
+
   pub fn set_entry_point_chunk(&mut self, chunk_ukey: ChunkUkey) {
     self.entry_point_chunk = Some(chunk_ukey);
   }
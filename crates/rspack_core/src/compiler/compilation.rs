@@ -7,6 +7,7 @@ use std::{
     // time::Instant,
     Arc,
   },
+  time::{SystemTime, UNIX_EPOCH},
 };
 
 use dashmap::DashSet;
@@ -33,7 +34,9 @@ use crate::{
   build_chunk_graph::build_chunk_graph,
   cache::{use_code_splitting_cache, Cache, CodeSplittingCache},
   is_source_equal,
-  tree_shaking::{optimizer, visitor::SymbolRef, BailoutFlag, OptimizeDependencyResult},
+  tree_shaking::{
+    optimizer, visitor::SymbolRef, BailoutFlag, ExportsInfo, OptimizeDependencyResult,
+  },
   utils::fast_drop,
   AddQueue, AddTask, AddTaskResult, AdditionalChunkRuntimeRequirementsArgs, BoxModuleDependency,
   BuildQueue, BuildTask, BuildTaskResult, BundleEntries, Chunk, ChunkByUkey, ChunkGraph,
@@ -41,9 +44,9 @@ use crate::{
   CodeGenerationResult, CodeGenerationResults, CompilerOptions, ContentHashArgs, DependencyId,
   EntryDependency, EntryItem, EntryOptions, Entrypoint, FactorizeQueue, FactorizeTask,
   FactorizeTaskResult, LoaderRunnerRunner, Module, ModuleGraph, ModuleIdentifier, ModuleType,
-  NormalModuleAstOrSource, ProcessAssetsArgs, ProcessDependenciesQueue, ProcessDependenciesResult,
-  ProcessDependenciesTask, RenderManifestArgs, Resolve, RuntimeGlobals, RuntimeModule,
-  SharedPluginDriver, Stats, TaskResult, WorkerTask,
+  NormalModuleAstOrSource, PluginHookTiming, ProcessAssetsArgs, ProcessDependenciesQueue,
+  ProcessDependenciesResult, ProcessDependenciesTask, Records, RenderManifestArgs, Resolve,
+  RuntimeGlobals, RuntimeModule, SharedPluginDriver, Stats, TaskResult, WorkerTask,
 };
 
 #[derive(Debug)]
@@ -85,6 +88,8 @@ pub struct Compilation {
   pub used_symbol_ref: HashSet<SymbolRef>,
   /// Collecting all module that need to skip in tree-shaking ast modification phase
   pub bailout_module_identifiers: IdentifierMap<BailoutFlag>,
+  /// Exports of modules, only collected when tree shaking is enabled
+  pub exports_info: IdentifierMap<ExportsInfo>,
   #[cfg(debug_assertions)]
   pub tree_shaking_result: IdentifierMap<TreeShakingResult>,
 
@@ -96,6 +101,9 @@ pub struct Compilation {
   // lazy compilation visit module
   pub lazy_visit_modules: std::collections::HashSet<String>,
   pub used_chunk_ids: HashSet<String>,
+  pub used_module_ids: HashSet<String>,
+  /// Records of the previous build, only present when records are enabled.
+  pub records: Option<Records>,
 
   pub file_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub context_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
@@ -103,6 +111,12 @@ pub struct Compilation {
   pub build_dependencies: IndexSet<PathBuf, BuildHasherDefault<FxHasher>>,
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
+  /// Time taken by each plugin in each hook, collected before the `done` hook.
+  pub plugin_hook_timings: Vec<PluginHookTiming>,
+  /// Milliseconds since the Unix epoch.
+  pub start_time: u64,
+  /// Milliseconds since the Unix epoch, set before the `done` hook.
+  pub end_time: Option<u64>,
 }
 
 impl Compilation {
@@ -140,6 +154,7 @@ impl Compilation {
       #[cfg(debug_assertions)]
       tree_shaking_result: IdentifierMap::default(),
       bailout_module_identifiers: IdentifierMap::default(),
+      exports_info: IdentifierMap::default(),
 
       code_generation_results: Default::default(),
       code_generated_modules: Default::default(),
@@ -149,6 +164,8 @@ impl Compilation {
       hash: Default::default(),
       lazy_visit_modules: Default::default(),
       used_chunk_ids: Default::default(),
+      used_module_ids: Default::default(),
+      records: None,
 
       file_dependencies: Default::default(),
       context_dependencies: Default::default(),
@@ -156,6 +173,9 @@ impl Compilation {
       build_dependencies: Default::default(),
       side_effects_free_modules: IdentifierSet::default(),
       module_item_map: IdentifierMap::default(),
+      plugin_hook_timings: Default::default(),
+      start_time: now_millis(),
+      end_time: None,
     }
   }
 
@@ -302,6 +322,13 @@ impl Compilation {
             name: name.clone(),
             options: EntryOptions {
               runtime: item.runtime.clone(),
+              layer: item.layer.clone(),
+              depend_on: item.depend_on.clone(),
+              filename: item.filename.clone(),
+              library: item.library.clone(),
+              chunk_loading: item.chunk_loading.clone(),
+              async_chunks: item.async_chunks,
+              public_path: item.public_path.clone(),
             },
           },
         )
@@ -326,6 +353,19 @@ impl Compilation {
     })
   }
 
+  fn entry_layer(&self, dependency_id: &DependencyId) -> Option<String> {
+    self
+      .entries
+      .iter()
+      .find(|(name, _)| {
+        self
+          .entry_dependencies
+          .get(*name)
+          .map_or(false, |dependencies| dependencies.contains(dependency_id))
+      })
+      .and_then(|(_, item)| item.layer.clone())
+  }
+
   #[instrument(name = "compilation:make", skip_all)]
   pub async fn make(&mut self, params: SetupMakeParam) -> Result<()> {
     if let Some(e) = self
@@ -446,6 +486,11 @@ impl Compilation {
       if parent_module_identifier.is_some() && parent_module.is_none() {
         return;
       }
+      // Modules inherit the layer of their issuer, entry modules take the layer of the entry.
+      let issuer_layer = match parent_module {
+        Some(module) => module.get_layer().map(ToOwned::to_owned),
+        None => self.entry_layer(id),
+      };
 
       self.handle_module_creation(
         &mut factorize_queue,
@@ -465,6 +510,7 @@ impl Compilation {
           .and_then(|m| m.as_normal_module())
           .and_then(|module| module.name_for_condition())
           .map(|issuer| issuer.to_string()),
+        issuer_layer,
       );
     });
 
@@ -565,6 +611,7 @@ impl Compilation {
               .as_normal_module()
               .and_then(|module| module.name_for_condition())
               .map(|issuer| issuer.to_string()),
+            module.get_layer().map(ToOwned::to_owned),
           );
         });
 
@@ -823,10 +870,12 @@ impl Compilation {
     resolve_options: Option<Resolve>,
     lazy_visit_modules: std::collections::HashSet<String>,
     issuer: Option<String>,
+    issuer_layer: Option<String>,
   ) {
     queue.add_task(FactorizeTask {
       original_module_identifier,
       issuer,
+      issuer_layer,
       original_resource_path,
       dependencies,
       is_entry,
@@ -963,6 +1012,8 @@ impl Compilation {
   }
 
   pub async fn done(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
+    self.plugin_hook_timings = plugin_driver.read().await.hook_timings.to_vec();
+    self.end_time = Some(now_millis());
     let stats = &mut Stats::new(self);
     plugin_driver.write().await.done(stats).await?;
     Ok(())
@@ -988,6 +1039,8 @@ impl Compilation {
 
   #[instrument(name = "compilation:seal", skip_all)]
   pub async fn seal(&mut self, plugin_driver: SharedPluginDriver) -> Result<()> {
+    plugin_driver.write().await.seal(self)?;
+
     use_code_splitting_cache(self, |compilation| async {
       build_chunk_graph(compilation)?;
       plugin_driver.write().await.optimize_chunks(compilation)?;
@@ -1000,9 +1053,16 @@ impl Compilation {
       .optimize_chunk_modules(self)
       .await?;
 
+    plugin_driver.write().await.revive_modules(self)?;
     plugin_driver.write().await.module_ids(self)?;
+    plugin_driver.write().await.revive_chunks(self)?;
     plugin_driver.write().await.chunk_ids(self)?;
+    if self.records.is_some() {
+      plugin_driver.write().await.record_modules(self)?;
+      plugin_driver.write().await.record_chunks(self)?;
+    }
 
+    plugin_driver.write().await.before_code_generation(self)?;
     self.code_generation().await?;
 
     self
@@ -1400,3 +1460,10 @@ impl AssetInfo {
 pub struct AssetInfoRelated {
   pub source_map: Option<String>,
 }
+
+fn now_millis() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
@@ -143,6 +143,7 @@ where
         .await
         .resolver_factory
         .clear_entries();
+      self.plugin_driver.read().await.hook_timings.clear();
 
       let mut new_compilation = Compilation::new(
         // TODO: use Arc<T> instead
@@ -154,6 +155,8 @@ where
         self.cache.clone(),
       );
 
+      new_compilation.records = self.compilation.records.take();
+
       let is_incremental_rebuild = self.options.is_incremental_rebuild();
       if is_incremental_rebuild {
         // copy field from old compilation
@@ -8,7 +8,7 @@ use std::{path::Path, sync::Arc};
 pub use compilation::*;
 pub use queue::*;
 pub use resolver::*;
-use rspack_error::Result;
+use rspack_error::{internal_error, Result};
 use rspack_fs::AsyncWritableFileSystem;
 use rspack_futures::FuturesResults;
 use rustc_hash::FxHashSet as HashSet;
@@ -16,7 +16,7 @@ use tokio::sync::RwLock;
 use tracing::instrument;
 
 use crate::{
-  cache::Cache, fast_set, CompilerOptions, LoaderRunnerRunner, Plugin, PluginDriver,
+  cache::Cache, fast_set, CompilerOptions, LoaderRunnerRunner, Plugin, PluginDriver, Records,
   SharedPluginDriver,
 };
 
@@ -91,6 +91,7 @@ where
       .await
       .resolver_factory
       .clear_entries();
+    self.plugin_driver.read().await.hook_timings.clear();
 
     fast_set(
       &mut self.compilation,
@@ -104,6 +105,7 @@ where
         self.cache.clone(),
       ),
     );
+    self.compilation.records = self.read_records()?;
 
     // Fake this compilation as *currently* rebuilding does not create a new compilation
     self
@@ -139,7 +141,7 @@ where
     let option = self.options.clone();
     self.compilation.make(params).await?;
     self.compilation.finish(self.plugin_driver.clone()).await?;
-    if option.builtins.tree_shaking {
+    if option.builtins.tree_shaking || option.optimization.provided_exports {
       let (analyze_result, diagnostics) = self
         .compilation
         .optimize_dependency()
@@ -148,15 +150,18 @@ where
       if !diagnostics.is_empty() {
         self.compilation.push_batch_diagnostic(diagnostics);
       }
-      self.compilation.used_symbol_ref = analyze_result.used_symbol_ref;
-      self.compilation.bailout_module_identifiers = analyze_result.bail_out_module_identifiers;
-      self.compilation.side_effects_free_modules = analyze_result.side_effects_free_modules;
-      self.compilation.module_item_map = analyze_result.module_item_map;
-
-      // This is only used when testing
-      #[cfg(debug_assertions)]
-      {
-        self.compilation.tree_shaking_result = analyze_result.analyze_results;
+      self.compilation.exports_info = analyze_result.exports_info();
+      if option.builtins.tree_shaking {
+        self.compilation.used_symbol_ref = analyze_result.used_symbol_ref;
+        self.compilation.bailout_module_identifiers = analyze_result.bail_out_module_identifiers;
+        self.compilation.side_effects_free_modules = analyze_result.side_effects_free_modules;
+        self.compilation.module_item_map = analyze_result.module_item_map;
+
+        // This is only used when testing
+        #[cfg(debug_assertions)]
+        {
+          self.compilation.tree_shaking_result = analyze_result.analyze_results;
+        }
       }
     }
     self.compilation.seal(self.plugin_driver.clone()).await?;
@@ -175,6 +180,7 @@ where
     if !self.compilation.options.builtins.no_emit_assets {
       self.emit_assets().await?;
     }
+    self.emit_records().await?;
 
     self.compilation.done(self.plugin_driver.clone()).await?;
     Ok(())
@@ -206,6 +212,37 @@ where
       .await
   }
 
+  fn read_records(&self) -> Result<Option<Records>> {
+    if self.options.records_input_path.is_none() && self.options.records_output_path.is_none() {
+      return Ok(None);
+    }
+    let records = match &self.options.records_input_path {
+      Some(path) if path.exists() => {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content)
+          .map_err(|e| internal_error!("Failed to parse records from {}: {e}", path.display()))?
+      }
+      _ => Records::default(),
+    };
+    Ok(Some(records))
+  }
+
+  async fn emit_records(&self) -> Result<()> {
+    let (Some(path), Some(records)) = (&self.options.records_output_path, &self.compilation.records) else {
+      return Ok(());
+    };
+    if let Some(parent) = path.parent() {
+      self.output_filesystem.create_dir_all(parent).await?;
+    }
+    let content = serde_json::to_string_pretty(records)
+      .map_err(|e| internal_error!("Failed to serialize records: {e}"))?;
+    self
+      .output_filesystem
+      .write(path, content.as_bytes())
+      .await?;
+    Ok(())
+  }
+
   async fn emit_asset(
     &self,
     output_path: &Path,
@@ -26,6 +26,7 @@ pub trait WorkerTask {
 pub struct FactorizeTask {
   pub original_module_identifier: Option<ModuleIdentifier>,
   pub issuer: Option<String>,
+  pub issuer_layer: Option<String>,
   pub original_resource_path: Option<PathBuf>,
   pub dependencies: Vec<BoxModuleDependency>,
   pub is_entry: bool,
@@ -95,6 +96,7 @@ impl WorkerTask for FactorizeTask {
             options: self.options.clone(),
             lazy_visit_modules: self.lazy_visit_modules,
             issuer: self.issuer,
+            issuer_layer: self.issuer_layer,
           },
           self.plugin_driver,
           self.cache,
@@ -247,24 +249,28 @@ impl WorkerTask for BuildTask {
 
     let build_result = cache
       .build_module_occasion
-      .use_cache(&mut module, |module| async {
-        plugin_driver
-          .read()
-          .await
-          .build_module(module.as_mut())
-          .await?;
-
-        let result = module
-          .build(BuildContext {
-            loader_runner_runner: &loader_runner_runner,
-            compiler_options: &compiler_options,
-          })
-          .await;
-
-        plugin_driver.read().await.succeed_module(module).await?;
-
-        result
-      })
+      .use_cache(
+        &mut module,
+        |id| cache.code_generate_occasion.has_cache(id),
+        |module| async {
+          plugin_driver
+            .read()
+            .await
+            .build_module(module.as_mut())
+            .await?;
+
+          let result = module
+            .build(BuildContext {
+              loader_runner_runner: &loader_runner_runner,
+              compiler_options: &compiler_options,
+            })
+            .await;
+
+          plugin_driver.read().await.succeed_module(module).await?;
+
+          result
+        },
+      )
       .await;
 
     build_result.map(|build_result| {
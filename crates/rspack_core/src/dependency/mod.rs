@@ -15,6 +15,7 @@ use std::{any::Any, fmt::Debug, hash::Hash};
 
 use dyn_clone::{clone_trait_object, DynClone};
 pub use require_context_dependency::RequireContextDependency;
+use serde::{Deserialize, Serialize};
 mod static_exports_dependency;
 pub use static_exports_dependency::*;
 
@@ -24,7 +25,7 @@ use crate::{
 
 // Used to describe dependencies' types, see webpack's `type` getter in `Dependency`
 // Note: This is almost the same with the old `ResolveKind`
-#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum DependencyType {
   #[default]
   Unknown,
@@ -69,7 +70,7 @@ pub enum DependencyType {
   StaticExports,
 }
 
-#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[derive(Default, Copy, Clone, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
 pub enum DependencyCategory {
   #[default]
   Unknown,
@@ -95,6 +96,20 @@ impl From<&str> for DependencyCategory {
   }
 }
 
+impl DependencyCategory {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Unknown => "unknown",
+      Self::Esm => "esm",
+      Self::CommonJS => "commonjs",
+      Self::Url => "url",
+      Self::Wasm => "wasm",
+      Self::CssImport => "css-import",
+      Self::CssCompose => "css-compose",
+    }
+  }
+}
+
 pub trait Dependency:
   CodeGeneratable + AsAny + DynHash + DynClone + DynEq + Send + Sync + Debug
 {
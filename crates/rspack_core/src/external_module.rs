@@ -1,28 +1,122 @@
 use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::Display;
 use std::hash::Hash;
 
-use rspack_error::{IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
+use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::{Identifiable, Identifier};
 
 use crate::{
   rspack_sources::{BoxSource, RawSource, Source, SourceExt},
   to_identifier, AstOrSource, BuildContext, BuildResult, CodeGenerationResult, Compilation,
-  Context, ExternalType, GenerationResult, LibIdentOptions, Module, ModuleType, SourceType,
+  Context, ExternalType, GenerationResult, LibIdentOptions, Module, ModuleType, RuntimeGlobals,
+  SourceType,
 };
 
 static EXTERNAL_MODULE_SOURCE_TYPES: &[SourceType] = &[SourceType::JavaScript];
 
+/// A module name followed by the property path into it, e.g. `['lodash', 'chunk']`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalRequestValue(Vec<String>);
+
+impl ExternalRequestValue {
+  pub fn new(request: Vec<String>) -> Result<Self> {
+    if request.is_empty() {
+      return Err(internal_error!(
+        "The request of an external should not be empty"
+      ));
+    }
+    Ok(Self(request))
+  }
+
+  pub fn primary(&self) -> &str {
+    &self.0[0]
+  }
+
+  pub fn rest(&self) -> &[String] {
+    &self.0[1..]
+  }
+
+  pub fn as_slice(&self) -> &[String] {
+    &self.0
+  }
+}
+
+impl From<String> for ExternalRequestValue {
+  fn from(value: String) -> Self {
+    Self(vec![value])
+  }
+}
+
+/// The request of an external, either used by all external types or specified for each of them,
+/// e.g. `{ root: '_', commonjs: 'lodash' }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalRequest {
+  Single(ExternalRequestValue),
+  Map(BTreeMap<String, ExternalRequestValue>),
+}
+
+impl ExternalRequest {
+  /// The request used by `external_type`.
+  pub fn get(&self, external_type: &str) -> Result<&ExternalRequestValue> {
+    match self {
+      Self::Single(request) => Ok(request),
+      Self::Map(map) => map
+        .get(external_type)
+        .ok_or_else(|| internal_error!("Missing external configuration for type: {external_type}")),
+    }
+  }
+}
+
+impl Display for ExternalRequest {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let to_json = |request: &ExternalRequestValue| {
+      if request.0.len() == 1 {
+        format!("\"{}\"", request.primary())
+      } else {
+        format!(
+          "[{}]",
+          request
+            .as_slice()
+            .iter()
+            .map(|s| format!("\"{s}\""))
+            .collect::<Vec<_>>()
+            .join(",")
+        )
+      }
+    };
+    match self {
+      Self::Single(request) if request.0.len() == 1 => write!(f, "{}", request.primary()),
+      Self::Single(request) => write!(f, "{}", to_json(request)),
+      Self::Map(map) => write!(
+        f,
+        "{{{}}}",
+        map
+          .iter()
+          .map(|(k, v)| format!("\"{k}\":{}", to_json(v)))
+          .collect::<Vec<_>>()
+          .join(",")
+      ),
+    }
+  }
+}
+
+/// `['a']['b']`
+fn property_access(properties: &[String]) -> String {
+  properties.iter().map(|p| format!("['{p}']")).collect()
+}
+
 #[derive(Debug)]
 pub struct ExternalModule {
   id: Identifier,
-  pub request: String,
+  pub request: ExternalRequest,
   external_type: ExternalType,
   /// Request intended by user (without loaders from config)
   user_request: String,
 }
 
 impl ExternalModule {
-  pub fn new(request: String, external_type: ExternalType, user_request: String) -> Self {
+  pub fn new(request: ExternalRequest, external_type: ExternalType, user_request: String) -> Self {
     Self {
       id: Identifier::from(format!("external {external_type} {request}")),
       request,
@@ -31,22 +125,44 @@ impl ExternalModule {
     }
   }
 
-  pub fn get_source(&self, compilation: &Compilation) -> BoxSource {
+  pub fn external_type(&self) -> &ExternalType {
+    &self.external_type
+  }
+
+  /// The variable a `module` external is imported into when the output is ESM,
+  /// i.e. `import * as __WEBPACK_EXTERNAL_MODULE_<request>__ from "<request>"`.
+  pub fn module_namespace_name(&self) -> Result<String> {
+    Ok(format!(
+      "__WEBPACK_EXTERNAL_MODULE_{}__",
+      to_identifier(self.request.get(&self.external_type)?.primary())
+    ))
+  }
+
+  pub fn get_source(&self, compilation: &Compilation) -> Result<BoxSource> {
+    // Externals of the library types (`amd`, `umd`...) don't need the request in the module.
+    let request = || self.request.get(&self.external_type);
     let source = match self.external_type.as_str() {
       "this" => format!(
-        "module.exports = (function() {{ return this['{}']; }}())",
-        self.request
+        "module.exports = (function() {{ return this{}; }}())",
+        property_access(request()?.as_slice())
       ),
       "window" | "self" => format!(
-        "module.exports = {}['{}']",
-        self.external_type, self.request
+        "module.exports = {}{}",
+        self.external_type,
+        property_access(request()?.as_slice())
       ),
       "global" => format!(
-        "module.exports = {}['{}']",
-        compilation.options.output.global_object, self.request
+        "module.exports = {}{}",
+        compilation.options.output.global_object,
+        property_access(request()?.as_slice())
       ),
       "commonjs" | "commonjs2" | "commonjs-module" | "commonjs-static" => {
-        format!("module.exports = require('{}')", self.request)
+        let request = request()?;
+        format!(
+          "module.exports = require('{}'){}",
+          request.primary(),
+          property_access(request.rest())
+        )
       }
       "amd" | "amd-require" | "umd" | "umd2" | "system" | "jsonp" => {
         let id = compilation
@@ -59,19 +175,98 @@ impl ExternalModule {
           to_identifier(id)
         )
       }
-      "import" => {
+      "import" => self.dynamic_import_source(request()?, compilation),
+      "var" | "promise" | "const" | "let" | "assign" => {
+        let request = request()?;
         format!(
-          "module.exports = {}('{}')",
-          compilation.options.output.import_function_name, self.request
+          "module.exports = {}{}",
+          request.primary(),
+          property_access(request.rest())
         )
       }
-      "var" | "promise" | "const" | "let" | "assign" => {
-        format!("module.exports = {}", self.request)
+      "script" => {
+        // The request is `global@url` or `[url, global, ...properties]`, the global is assigned by
+        // the script once it is loaded.
+        let (global, url, properties) = match request()?.as_slice() {
+          [request] => {
+            let (global, url) = request
+              .split_once('@')
+              .filter(|(global, url)| !global.is_empty() && !url.is_empty())
+              .ok_or_else(|| {
+                internal_error!(
+                  "Invalid request \"{}\" of script external, expected \"global@url\"",
+                  request
+                )
+              })?;
+            (global, url, &[][..])
+          }
+          [url, global, properties @ ..] => (global.as_str(), url.as_str(), properties),
+          [] => unreachable!(),
+        };
+        format!(
+          r#"var __webpack_error__ = new Error();
+module.exports = new Promise(function(resolve, reject) {{
+  if(typeof {global} !== "undefined") return resolve();
+  {load_script}('{url}', function(event) {{
+    if(typeof {global} !== "undefined") return resolve();
+    var errorType = event && (event.type === 'load' ? 'missing' : event.type);
+    var realSrc = event && event.target && event.target.src;
+    __webpack_error__.message = 'Loading script failed.\n(' + errorType + ': ' + realSrc + ')';
+    __webpack_error__.name = 'ScriptExternalLoadError';
+    __webpack_error__.type = errorType;
+    __webpack_error__.request = realSrc;
+    reject(__webpack_error__);
+  }}, '{global}');
+}}).then(function() {{ return {global}{properties}; }})"#,
+          load_script = RuntimeGlobals::LOAD_SCRIPT,
+          properties = property_access(properties),
+        )
+      }
+      "module" => {
+        let request = request()?;
+        if compilation.options.output.module {
+          // The namespace is imported by the chunk, see `ModuleChunkFormatPlugin`.
+          let namespace = self.module_namespace_name()?;
+          if request.rest().is_empty() {
+            format!(
+              r#"Object.defineProperty(exports, "__esModule", {{ value: true }});
+Object.keys({namespace}).forEach(function(key) {{
+  Object.defineProperty(exports, key, {{ enumerable: true, get: function() {{ return {namespace}[key]; }} }});
+}})"#
+            )
+          } else {
+            format!(
+              "module.exports = {namespace}{}",
+              property_access(request.rest())
+            )
+          }
+        } else {
+          self.dynamic_import_source(request, compilation)
+        }
       }
-      // TODO "script" "module"
       _ => "".to_string(),
     };
-    RawSource::from(source).boxed()
+    Ok(RawSource::from(source).boxed())
+  }
+
+  fn dynamic_import_source(
+    &self,
+    request: &ExternalRequestValue,
+    compilation: &Compilation,
+  ) -> String {
+    let import = format!(
+      "{}('{}')",
+      compilation.options.output.import_function_name,
+      request.primary()
+    );
+    if request.rest().is_empty() {
+      format!("module.exports = {import}")
+    } else {
+      format!(
+        "module.exports = {import}.then(function(module) {{ return module{}; }})",
+        property_access(request.rest())
+      )
+    }
   }
 }
 
@@ -107,9 +302,16 @@ impl Module for ExternalModule {
 
   async fn build(
     &mut self,
-    _build_context: BuildContext<'_>,
+    build_context: BuildContext<'_>,
   ) -> Result<TWithDiagnosticArray<BuildResult>> {
-    Ok(BuildResult::default().with_empty_diagnostic())
+    let mut build_result = BuildResult::default();
+    // Externals resolved by a promise make their importers async modules.
+    build_result.build_meta.is_async = match self.external_type.as_str() {
+      "script" => true,
+      "module" => !build_context.compiler_options.output.module,
+      _ => false,
+    };
+    Ok(build_result.with_empty_diagnostic())
   }
 
   fn code_generation(&self, compilation: &Compilation) -> Result<CodeGenerationResult> {
@@ -117,8 +319,11 @@ impl Module for ExternalModule {
 
     cgr.add(
       SourceType::JavaScript,
-      GenerationResult::from(AstOrSource::from(self.get_source(compilation))),
+      GenerationResult::from(AstOrSource::from(self.get_source(compilation)?)),
     );
+    if self.external_type == "script" {
+      cgr.runtime_requirements.insert(RuntimeGlobals::LOAD_SCRIPT);
+    }
 
     Ok(cgr)
   }
@@ -7,6 +7,7 @@
 use std::{fmt, sync::Arc};
 
 use rspack_database::Database;
+use serde::{Deserialize, Serialize};
 pub mod external_module;
 pub use external_module::*;
 pub mod ast;
@@ -68,12 +69,14 @@ mod chunk_group;
 pub use chunk_group::*;
 mod ukey;
 pub use ukey::*;
+mod records;
+pub use records::*;
 
 pub mod tree_shaking;
 
 pub use rspack_sources;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SourceType {
   JavaScript,
   Css,
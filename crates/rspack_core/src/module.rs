@@ -118,6 +118,12 @@ pub trait Module: Debug + Send + Sync + AsAny + DynHash + DynEq + Identifiable {
   fn get_resolve_options(&self) -> Option<&Resolve> {
     None
   }
+
+  /// The layer of the module, modules in different layers are different modules
+  /// even if they come from the same resource.
+  fn get_layer(&self) -> Option<&str> {
+    None
+  }
 }
 
 pub trait ModuleExt {
@@ -184,6 +190,10 @@ impl Module for Box<dyn Module> {
   fn get_resolve_options(&self) -> Option<&Resolve> {
     (**self).get_resolve_options()
   }
+
+  fn get_layer(&self) -> Option<&str> {
+    (**self).get_layer()
+  }
 }
 
 impl PartialEq for dyn Module + '_ {
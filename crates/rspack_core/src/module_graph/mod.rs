@@ -1,9 +1,10 @@
 use std::borrow::Cow;
-use std::collections::hash_map::Entry;
+use std::collections::{hash_map::Entry, VecDeque};
 use std::path::PathBuf;
 
+use petgraph::{algo::tarjan_scc, graphmap::DiGraphMap};
 use rspack_error::{internal_error, Result};
-use rspack_identifier::IdentifierMap;
+use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 mod connection;
@@ -340,6 +341,109 @@ impl ModuleGraph {
       .unwrap_or_default()
   }
 
+  pub fn get_incoming_connections(&self, module: &BoxModule) -> HashSet<&ModuleGraphConnection> {
+    self
+      .module_graph_module_by_identifier(&module.identifier())
+      .map(|mgm| {
+        mgm
+          .incoming_connections
+          .iter()
+          .filter_map(|id| self.connection_by_connection_id(id))
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  /// The modules referenced by the connections of the module accepted by `filter`, sorted to keep
+  /// traversals stable.
+  pub fn get_referenced_module_identifiers(
+    &self,
+    module_identifier: &ModuleIdentifier,
+    filter: impl Fn(&ModuleGraphConnection) -> bool,
+  ) -> Vec<ModuleIdentifier> {
+    let mut modules = self
+      .module_graph_module_by_identifier(module_identifier)
+      .map(|mgm| {
+        mgm
+          .outgoing_connections
+          .iter()
+          .filter_map(|id| self.connection_by_connection_id(id))
+          .filter(|connection| filter(connection))
+          .map(|connection| connection.module_identifier)
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+    modules.sort_unstable();
+    modules.dedup();
+    modules
+  }
+
+  /// The shortest chain of imports from `from` to `to`, both included.
+  /// Returns `None` if `to` can't be reached from `from`.
+  pub fn get_import_path(
+    &self,
+    from: &ModuleIdentifier,
+    to: &ModuleIdentifier,
+  ) -> Option<Vec<ModuleIdentifier>> {
+    let mut importers: IdentifierMap<ModuleIdentifier> = Default::default();
+    let mut visited = IdentifierSet::from_iter([*from]);
+    let mut queue = VecDeque::from([*from]);
+    while let Some(module) = queue.pop_front() {
+      if module == *to {
+        let mut path = vec![module];
+        let mut current = module;
+        while let Some(importer) = importers.get(&current) {
+          path.push(*importer);
+          current = *importer;
+        }
+        path.reverse();
+        return Some(path);
+      }
+      for referenced in self.get_referenced_module_identifiers(&module, |_| true) {
+        if visited.insert(referenced) {
+          importers.insert(referenced, module);
+          queue.push_back(referenced);
+        }
+      }
+    }
+    None
+  }
+
+  /// Groups of modules importing each other, directly or through other modules of the group.
+  /// A module importing itself is a group as well. Both the groups and their modules are sorted.
+  pub fn get_cycles(&self) -> Vec<Vec<ModuleIdentifier>> {
+    self.get_cycles_by(|_| true)
+  }
+
+  /// Same as [ModuleGraph::get_cycles], but only follows the connections accepted by `filter`.
+  pub fn get_cycles_by(
+    &self,
+    filter: impl Fn(&ModuleGraphConnection) -> bool,
+  ) -> Vec<Vec<ModuleIdentifier>> {
+    let mut graph = DiGraphMap::<ModuleIdentifier, ()>::new();
+    let mut modules = self.modules().keys().copied().collect::<Vec<_>>();
+    modules.sort_unstable();
+    for module in &modules {
+      graph.add_node(*module);
+    }
+    for module in &modules {
+      for referenced in self.get_referenced_module_identifiers(module, &filter) {
+        graph.add_edge(*module, referenced, ());
+      }
+    }
+
+    let mut cycles = tarjan_scc(&graph)
+      .into_iter()
+      .filter(|component| component.len() > 1 || graph.contains_edge(component[0], component[0]))
+      .map(|mut component| {
+        component.sort_unstable();
+        component
+      })
+      .collect::<Vec<_>>();
+    cycles.sort_unstable();
+    cycles
+  }
+
   /// Remove a connection and return connection origin module identifier and dependency
   fn revoke_connection(&mut self, connection_id: ConnectionId) -> Option<DependencyId> {
     let connection = match self.connections[*connection_id].take() {
@@ -645,4 +749,52 @@ mod test {
     assert!(mgm_b.outgoing_connections.is_empty());
     assert!(mgm_c.incoming_connections.is_empty());
   }
+
+  #[test]
+  fn test_import_path_and_cycles() {
+    let mut mg = ModuleGraph::default();
+    let a = node!("a");
+    let b = node!("b");
+    let c = node!("c");
+    let d = node!("d");
+    let a_id = a.identifier();
+    let b_id = b.identifier();
+    let c_id = c.identifier();
+    let d_id = d.identifier();
+    add_module_to_graph(&mut mg, box a);
+    add_module_to_graph(&mut mg, box b);
+    add_module_to_graph(&mut mg, box c);
+    add_module_to_graph(&mut mg, box d);
+    // a -> b -> c -> b, d -> d
+    let a_to_b = edge!(Some(a_id), b_id.as_str());
+    link_modules_with_dependency(&mut mg, Some(&a_id), &b_id, box (a_to_b));
+    let b_to_c = edge!(Some(b_id), c_id.as_str());
+    link_modules_with_dependency(&mut mg, Some(&b_id), &c_id, box (b_to_c));
+    let c_to_b = edge!(Some(c_id), b_id.as_str());
+    link_modules_with_dependency(&mut mg, Some(&c_id), &b_id, box (c_to_b));
+    let d_to_d = edge!(Some(d_id), d_id.as_str());
+    link_modules_with_dependency(&mut mg, Some(&d_id), &d_id, box (d_to_d));
+
+    let b = mg.module_by_identifier(&b_id).expect("not found");
+    let mut importers = mg
+      .get_incoming_connections(b)
+      .into_iter()
+      .filter_map(|connection| connection.original_module_identifier)
+      .collect::<Vec<_>>();
+    importers.sort_unstable();
+    assert_eq!(importers, vec![a_id, c_id]);
+
+    assert_eq!(
+      mg.get_import_path(&a_id, &c_id),
+      Some(vec![a_id, b_id, c_id])
+    );
+    assert_eq!(mg.get_import_path(&a_id, &a_id), Some(vec![a_id]));
+    assert_eq!(mg.get_import_path(&c_id, &a_id), None);
+
+    let mut b_and_c = vec![b_id, c_id];
+    b_and_c.sort_unstable();
+    let mut cycles = vec![b_and_c, vec![d_id]];
+    cycles.sort_unstable();
+    assert_eq!(mg.get_cycles(), cycles);
+  }
 }
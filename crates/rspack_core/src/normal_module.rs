@@ -313,6 +313,8 @@ pub struct NormalModule {
   resource_data: ResourceData,
   /// Loaders for the module
   loaders: Vec<BoxLoader>,
+  /// Layer of the module, assigned by entries or [Rule.layer]
+  layer: Option<String>,
 
   /// Original content of this module, will be available after module build
   original_source: Option<BoxSource>,
@@ -374,11 +376,17 @@ impl NormalModule {
     resource_data: ResourceData,
     resolve_options: Option<Resolve>,
     loaders: Vec<BoxLoader>,
+    layer: Option<String>,
     options: Arc<CompilerOptions>,
   ) -> Self {
     let module_type = module_type.into();
+    // The same resource in different layers should be different modules.
+    let id = match &layer {
+      Some(layer) => format!("{module_type}|{request}|{layer}"),
+      None => format!("{module_type}|{request}"),
+    };
     Self {
-      id: ModuleIdentifier::from(format!("{module_type}|{request}")),
+      id: ModuleIdentifier::from(id),
       request,
       user_request,
       raw_request,
@@ -389,6 +397,7 @@ impl NormalModule {
       resource_data,
       resolve_options,
       loaders,
+      layer,
       original_source: None,
       ast_or_source: NormalModuleAstOrSource::Unbuild,
       debug_id: DEBUG_ID.fetch_add(1, Ordering::Relaxed),
@@ -625,13 +634,21 @@ impl Module for NormalModule {
 
   fn lib_ident(&self, options: LibIdentOptions) -> Option<Cow<str>> {
     // Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/NormalModule.js#L362
-    Some(Cow::Owned(contextify(options.context, self.user_request())))
+    let ident = contextify(options.context, self.user_request());
+    match &self.layer {
+      Some(layer) => Some(Cow::Owned(format!("({layer})/{ident}"))),
+      None => Some(Cow::Owned(ident)),
+    }
   }
 
   fn get_resolve_options(&self) -> Option<&Resolve> {
     self.resolve_options.as_ref()
   }
 
+  fn get_layer(&self) -> Option<&str> {
+    self.layer.as_deref()
+  }
+
   fn get_code_generation_dependencies(&self) -> Option<&[Box<dyn ModuleDependency>]> {
     if let Some(deps) = self.code_generation_dependencies.as_deref() && !deps.is_empty() {
       Some(deps)
@@ -5,6 +5,7 @@ use std::{
 
 use rspack_error::{internal_error, IntoTWithDiagnosticArray, Result, TWithDiagnosticArray};
 use rspack_identifier::Identifiable;
+use serde::{Deserialize, Serialize};
 use swc_core::common::Span;
 
 use crate::{
@@ -183,6 +184,7 @@ impl NormalModuleFactory {
           module_rule,
           &resource_data,
           importer.map(|i| i.to_string_lossy()).as_deref(),
+          self.context.issuer_layer.as_deref(),
         ) {
           Ok(val) => val.map(Ok),
           Err(err) => Some(Err(err)),
@@ -216,6 +218,12 @@ impl NormalModuleFactory {
     let resolved_resolve_options = self.calculate_resolve_options(&resolved_module_rules);
     let (resolved_parser_options, resolved_generator_options) =
       self.calculate_parser_and_generator_options(&resolved_module_rules);
+    let layer = self.calculate_layer(&resolved_module_rules);
+    if layer.is_some() && !self.context.options.experiments.layers {
+      return Err(internal_error!(
+        "'layer' is only allowed when 'experiments.layers' is enabled"
+      ));
+    }
 
     let resolved_parser_and_generator = self
       .plugin_driver
@@ -242,6 +250,7 @@ impl NormalModuleFactory {
       resource_data,
       resolved_resolve_options,
       loaders,
+      layer,
       self.context.options.clone(),
     );
 
@@ -278,7 +287,12 @@ impl NormalModuleFactory {
       .rules
       .iter()
       .filter_map(|module_rule| -> Option<Result<&ModuleRule>> {
-        match module_rule_matcher(module_rule, resource_data, self.context.issuer.as_deref()) {
+        match module_rule_matcher(
+          module_rule,
+          resource_data,
+          self.context.issuer.as_deref(),
+          self.context.issuer_layer.as_deref(),
+        ) {
           Ok(val) => val.map(Ok),
           Err(err) => Some(Err(err)),
         }
@@ -286,6 +300,15 @@ impl NormalModuleFactory {
       .collect::<Result<Vec<_>>>()
   }
 
+  /// The layer of the last matched rule wins, otherwise the module inherits the layer of its issuer.
+  fn calculate_layer(&self, module_rules: &[&ModuleRule]) -> Option<String> {
+    module_rules
+      .iter()
+      .rev()
+      .find_map(|rule| rule.layer.clone())
+      .or_else(|| self.context.issuer_layer.clone())
+  }
+
   fn calculate_resolve_options(&self, module_rules: &[&ModuleRule]) -> Option<Resolve> {
     let mut resolved = None;
     module_rules.iter().for_each(|rule| {
@@ -379,6 +402,7 @@ pub struct NormalModuleFactoryContext {
   pub options: Arc<CompilerOptions>,
   pub lazy_visit_modules: std::collections::HashSet<String>,
   pub issuer: Option<String>,
+  pub issuer_layer: Option<String>,
 }
 
 /// Using `u32` instead of `usize` to reduce memory usage,
@@ -386,7 +410,7 @@ pub struct NormalModuleFactoryContext {
 /// Rspan aka `Rspack span`, just avoiding conflict with span in other crate
 /// ## Warning
 /// RSpan is zero based, `Span` of `swc` is 1 based. see https://swc-css.netlify.app/?code=eJzLzC3ILypRSFRIK8rPVVAvSS0u0csqVgcAZaoIKg
-#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct ErrorSpan {
   pub start: u32,
   pub end: u32,
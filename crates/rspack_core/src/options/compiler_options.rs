@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::{
   Builtins, BundleEntries, CacheOptions, Context, DevServerOptions, Devtool, Experiments, Mode,
   ModuleOptions, NodeOption, Optimization, OutputOptions, Resolve, SnapshotOptions, StatsOptions,
@@ -22,6 +24,12 @@ pub struct CompilerOptions {
   pub experiments: Experiments,
   pub node: NodeOption,
   pub optimization: Optimization,
+  /// Time the hooks called for each module, e.g. `factorize` and `module`.
+  pub profile: bool,
+  /// Read the ids of the previous build from this file.
+  pub records_input_path: Option<PathBuf>,
+  /// Write the ids of this build to this file.
+  pub records_output_path: Option<PathBuf>,
 }
 
 impl CompilerOptions {
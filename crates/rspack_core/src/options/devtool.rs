@@ -8,7 +8,7 @@ bitflags! {
     const NO_SOURCES = 0b00001000;
     const HIDDEN = 0b00010000;
     const MODULE = 0b00100000;
-    // TODO: eval source map for better development (re)builds performance.
+    /// Wrap each module in `eval()`, which is faster for development (re)builds.
     const EVAL = 0b01000000;
   }
 }
@@ -52,6 +52,7 @@ impl Devtool {
   pub fn source_map(&self) -> bool {
     self.inner.contains(DevtoolFlags::SOURCE_MAP)
   }
+  /// Only lines are mapped, generators and `MapOptions` should skip columns.
   pub fn cheap(&self) -> bool {
     self.inner.contains(DevtoolFlags::CHEAP)
   }
@@ -1,14 +1,36 @@
 use std::collections::HashMap;
 
+use crate::{ChunkLoading, Filename, LibraryOptions, PublicPath};
+
 pub type BundleEntries = HashMap<String, EntryItem>;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct EntryItem {
   pub import: Vec<String>,
   pub runtime: Option<String>,
+  /// The layer in which the modules of the entry are placed.
+  pub layer: Option<String>,
+  /// Entries that must be loaded before this one, modules of them are not duplicated in this entry.
+  pub depend_on: Option<Vec<String>>,
+  /// Overrides `output.filename` for the entry chunk.
+  pub filename: Option<Filename>,
+  /// Overrides `output.library` for the entry.
+  pub library: Option<LibraryOptions>,
+  pub chunk_loading: Option<ChunkLoading>,
+  /// Set to `false` to put dynamically imported modules into the entry chunk.
+  pub async_chunks: Option<bool>,
+  /// Overrides `output.publicPath` for the entry.
+  pub public_path: Option<PublicPath>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct EntryOptions {
   pub runtime: Option<String>,
+  pub layer: Option<String>,
+  pub depend_on: Option<Vec<String>>,
+  pub filename: Option<Filename>,
+  pub library: Option<LibraryOptions>,
+  pub chunk_loading: Option<ChunkLoading>,
+  pub async_chunks: Option<bool>,
+  pub public_path: Option<PublicPath>,
 }
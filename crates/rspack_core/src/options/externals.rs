@@ -1,3 +1,7 @@
+use std::fmt::{self, Debug};
+
+use futures::future::BoxFuture;
+use rspack_error::Result;
 use rspack_regex::RspackRegex;
 use rustc_hash::FxHashMap as HashMap;
 
@@ -7,16 +11,52 @@ pub type Externals = Vec<ExternalItem>;
 pub enum ExternalItemValue {
   String(String),
   Bool(bool),
-  // TODO: string[] | Record<string, string|string[]>
+  /// The module name followed by the property path into it, e.g. `['lodash', 'chunk']`.
+  Array(Vec<String>),
+  /// The request for each external type, e.g. `{ root: '_', commonjs: 'lodash' }`.
+  Object(HashMap<String, Vec<String>>),
 }
 
 pub type ExternalItemObject = HashMap<String, ExternalItemValue>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+pub struct ContextInfo {
+  /// The resource path of the module issuing the request.
+  pub issuer: String,
+}
+
+/// The argument of function externals, see webpack's `externals` function.
+#[derive(Debug, Clone)]
+pub struct ExternalItemFnCtx {
+  pub request: String,
+  /// The directory of the issuer.
+  pub context: String,
+  /// The category of the dependency, e.g. `esm` or `commonjs`.
+  pub dependency_type: String,
+  pub context_info: ContextInfo,
+}
+
+/// Returns the external value of the request, or `None` if the request is not external.
+pub type ExternalItemFn = Box<
+  dyn Fn(ExternalItemFnCtx) -> BoxFuture<'static, Result<Option<ExternalItemValue>>> + Sync + Send,
+>;
+
 pub enum ExternalItem {
   Object(ExternalItemObject),
   String(String),
   RegExp(RspackRegex),
+  Fn(ExternalItemFn),
+}
+
+impl Debug for ExternalItem {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Object(i) => f.debug_tuple("Object").field(i).finish(),
+      Self::String(i) => f.debug_tuple("String").field(i).finish(),
+      Self::RegExp(i) => f.debug_tuple("RegExp").field(i).finish(),
+      Self::Fn(_) => "Fn(...)".fmt(f),
+    }
+  }
 }
 
 impl From<ExternalItemObject> for ExternalItem {
@@ -31,6 +71,12 @@ impl From<String> for ExternalItem {
   }
 }
 
+impl From<ExternalItemFn> for ExternalItem {
+  fn from(value: ExternalItemFn) -> Self {
+    Self::Fn(value)
+  }
+}
+
 impl From<RspackRegex> for ExternalItem {
   fn from(value: RspackRegex) -> Self {
     Self::RegExp(value)
@@ -124,6 +124,10 @@ pub struct ModuleRule {
   pub generator: Option<AssetGeneratorOptions>,
   pub resolve: Option<Resolve>,
   pub issuer: Option<RuleSetCondition>,
+  /// A condition matcher against the layer of the issuer.
+  pub issuer_layer: Option<RuleSetCondition>,
+  /// The layer in which the matched module is placed.
+  pub layer: Option<String>,
   pub one_of: Option<Vec<ModuleRule>>,
 }
 
@@ -48,4 +48,7 @@ impl SideEffectOption {
 pub struct Optimization {
   pub remove_available_modules: bool,
   pub side_effects: SideEffectOption,
+  /// Analyze the exports provided by the modules, the module library renders them as `export`
+  /// statements.
+  pub provided_exports: bool,
 }
@@ -17,6 +17,12 @@ pub struct OutputOptions {
   pub asset_module_filename: Filename,
   pub webassembly_module_filename: Filename,
   pub unique_name: String,
+  /// Namespace of modules in source maps, `[namespace]` in the module filename templates.
+  pub devtool_namespace: String,
+  /// Template of the names of modules in source maps.
+  pub devtool_module_filename_template: String,
+  /// Template used when `devtool_module_filename_template` produces duplicated names.
+  pub devtool_fallback_module_filename_template: String,
   //todo we are not going to support file_name & chunk_file_name as function in the near feature
   pub filename: Filename,
   pub chunk_filename: Filename,
@@ -24,6 +30,9 @@ pub struct OutputOptions {
   pub css_chunk_filename: Filename,
   pub library: Option<LibraryOptions>,
   pub enabled_library_types: Option<Vec<String>>,
+  /// Chunk loading of the entries without `chunkLoading`.
+  pub chunk_loading: ChunkLoading,
+  pub enabled_chunk_loading_types: Option<Vec<String>>,
   pub strict_module_error_handling: bool,
   pub global_object: String,
   pub import_function_name: String,
@@ -168,7 +177,7 @@ impl Filename {
   }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum PublicPath {
   String(String),
   Auto,
@@ -237,12 +246,14 @@ pub fn get_css_chunk_filename_template<'filename>(
 
 #[allow(clippy::if_same_then_else)]
 pub fn get_js_chunk_filename_template<'filename>(
-  chunk: &Chunk,
+  chunk: &'filename Chunk,
   output_options: &'filename OutputOptions,
   chunk_group_by_ukey: &ChunkGroupByUkey,
 ) -> &'filename Filename {
   // Align with https://github.com/webpack/webpack/blob/8241da7f1e75c5581ba535d127fa66aeb9eb2ac8/lib/javascript/JavascriptModulesPlugin.js#L480
-  if chunk.can_be_initial(chunk_group_by_ukey) {
+  if let Some(filename_template) = &chunk.filename_template {
+    filename_template
+  } else if chunk.can_be_initial(chunk_group_by_ukey) {
     &output_options.filename
   } else if matches!(chunk.kind, ChunkKind::HotUpdate) {
     // TODO: Should return output_options.hotUpdateChunkFilename
@@ -253,7 +264,24 @@ pub fn get_js_chunk_filename_template<'filename>(
   }
 }
 
-#[derive(Debug)]
+/// The method of loading chunks, e.g. `jsonp` for web and `require` for node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkLoading {
+  Enable(String),
+  Disable,
+}
+
+impl From<String> for ChunkLoading {
+  fn from(value: String) -> Self {
+    if value == "false" {
+      Self::Disable
+    } else {
+      Self::Enable(value)
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
 pub struct LibraryOptions {
   pub name: Option<LibraryName>,
   pub export: Option<Vec<String>>,
@@ -263,7 +291,7 @@ pub struct LibraryOptions {
   pub auxiliary_comment: Option<LibraryAuxiliaryComment>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LibraryAuxiliaryComment {
   pub root: Option<String>,
   pub commonjs: Option<String>,
@@ -271,7 +299,7 @@ pub struct LibraryAuxiliaryComment {
   pub amd: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LibraryName {
   pub amd: Option<String>,
   pub commonjs: Option<String>,
@@ -1,9 +1,48 @@
+use rspack_regex::RspackRegex;
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotStrategy {
   pub hash: bool,
   pub timestamp: bool,
 }
 
+/// A path in `snapshot.managedPaths` or `snapshot.immutablePaths`.
+#[derive(Debug, Clone)]
+pub enum SnapshotPath {
+  /// Matches paths starting with this string.
+  String(String),
+  /// Matches paths with the regexp, the first capture group is used as the matched path if it exists.
+  RegExp(RspackRegex),
+}
+
+impl SnapshotPath {
+  /// Returns the leading part of `path` which is matched by this item.
+  pub fn match_prefix<'a>(&self, path: &'a str) -> Option<&'a str> {
+    match self {
+      SnapshotPath::String(prefix) => path
+        .starts_with(prefix.as_str())
+        .then(|| &path[..prefix.len()]),
+      SnapshotPath::RegExp(reg) => {
+        let matched = reg.find(path)?;
+        let range = matched.group(1).unwrap_or_else(|| matched.range());
+        Some(&path[..range.end])
+      }
+    }
+  }
+}
+
+impl From<String> for SnapshotPath {
+  fn from(value: String) -> Self {
+    Self::String(value)
+  }
+}
+
+impl From<RspackRegex> for SnapshotPath {
+  fn from(value: RspackRegex) -> Self {
+    Self::RegExp(value)
+  }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SnapshotOptions {
   // Snapshots for resolving of build dependencies when using the persistent cache.
@@ -14,8 +53,10 @@ pub struct SnapshotOptions {
   pub resolve: SnapshotStrategy,
   /// Snapshots for building modules.
   pub module: SnapshotStrategy,
-  // An array of paths that are managed by a package manager and contain a version or a hash in their paths.
-  // immutable_paths: Vec<String>,
-  // An array of paths that are managed by a package manager.
-  // managed_paths: Vec<String>,
+  /// Paths that are managed by a package manager and contain a version or a hash in their paths,
+  /// files in these paths are never checked again.
+  pub immutable_paths: Vec<SnapshotPath>,
+  /// Paths that are managed by a package manager, files in these paths are checked by the
+  /// name and version in the package.json of the package they belong to.
+  pub managed_paths: Vec<SnapshotPath>,
 }
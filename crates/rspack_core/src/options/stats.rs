@@ -1,5 +1,168 @@
-#[derive(Debug, Default)]
+use std::str::FromStr;
+
+use rspack_error::{internal_error, Error};
+
+/// Presets of [StatsOptions], same as webpack.
+///
+/// See: https://webpack.js.org/configuration/stats/#stats-presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StatsPreset {
+  None,
+  ErrorsOnly,
+  ErrorsWarnings,
+  Minimal,
+  #[default]
+  Normal,
+  Detailed,
+  Verbose,
+}
+
+impl FromStr for StatsPreset {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s {
+      "none" => Ok(Self::None),
+      "errors-only" => Ok(Self::ErrorsOnly),
+      "errors-warnings" => Ok(Self::ErrorsWarnings),
+      "minimal" => Ok(Self::Minimal),
+      "normal" => Ok(Self::Normal),
+      "detailed" => Ok(Self::Detailed),
+      "verbose" => Ok(Self::Verbose),
+      _ => Err(internal_error!("Unknown stats preset: {s}")),
+    }
+  }
+}
+
+/// Everything is left out by default, use [StatsOptions::from_preset] or [StatsOptions::from_printer_preset]
+/// for the options of a preset.
+#[derive(Debug, Clone, Default)]
 pub struct StatsOptions {
   pub colors: bool,
+  pub hash: bool,
+  /// Time taken by the compilation.
+  pub timings: bool,
+  pub built_at: bool,
+  /// Time taken by each plugin in each hook.
+  pub plugin_timings: bool,
+  pub public_path: bool,
+  pub output_path: bool,
+  pub assets: bool,
+  pub chunks: bool,
+  pub chunk_modules: bool,
+  pub chunk_relations: bool,
+  pub modules: bool,
   pub reasons: bool,
+  pub used_exports: bool,
+  pub provided_exports: bool,
+  pub optimization_bailout: bool,
+  pub entrypoints: bool,
+  pub chunk_groups: bool,
+  pub errors: bool,
+  pub errors_count: bool,
+  pub warnings: bool,
+  pub warnings_count: bool,
+}
+
+impl StatsOptions {
+  /// Options of the preset for `Stats::to_json`.
+  pub fn from_preset(preset: StatsPreset) -> Self {
+    Self::preset(preset, true)
+  }
+
+  /// Options of the preset for `Stats::to_string`, the details webpack doesn't print are left out.
+  pub fn from_printer_preset(preset: StatsPreset) -> Self {
+    Self::preset(preset, false)
+  }
+
+  fn preset(preset: StatsPreset, details: bool) -> Self {
+    let all = |value: bool| Self {
+      colors: false,
+      hash: value,
+      timings: value,
+      built_at: value,
+      plugin_timings: value,
+      public_path: value,
+      output_path: value,
+      assets: value,
+      chunks: value,
+      chunk_modules: value,
+      chunk_relations: value,
+      modules: value,
+      reasons: value,
+      used_exports: value,
+      provided_exports: value,
+      optimization_bailout: value,
+      entrypoints: value,
+      chunk_groups: value,
+      errors: value,
+      errors_count: value,
+      warnings: value,
+      warnings_count: value,
+    };
+    match preset {
+      StatsPreset::None => all(false),
+      StatsPreset::ErrorsOnly => Self {
+        errors: true,
+        errors_count: true,
+        ..all(false)
+      },
+      StatsPreset::ErrorsWarnings => Self {
+        errors: true,
+        errors_count: true,
+        warnings: true,
+        warnings_count: true,
+        ..all(false)
+      },
+      StatsPreset::Minimal => Self {
+        timings: true,
+        assets: true,
+        modules: true,
+        errors: true,
+        errors_count: true,
+        warnings: true,
+        warnings_count: true,
+        ..all(false)
+      },
+      StatsPreset::Normal => Self {
+        plugin_timings: false,
+        output_path: details,
+        chunks: details,
+        chunk_modules: details,
+        chunk_relations: details,
+        reasons: details,
+        used_exports: details,
+        provided_exports: details,
+        optimization_bailout: details,
+        chunk_groups: details,
+        ..all(true)
+      },
+      StatsPreset::Detailed => Self {
+        plugin_timings: false,
+        chunk_modules: details,
+        ..all(true)
+      },
+      StatsPreset::Verbose => all(true),
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn stats_presets() {
+    let errors_only =
+      StatsOptions::from_preset("errors-only".parse().expect("should parse the preset"));
+    assert!(errors_only.errors && !errors_only.warnings && !errors_only.assets);
+
+    let normal = StatsOptions::from_preset(StatsPreset::Normal);
+    assert!(normal.chunk_modules && normal.used_exports && !normal.plugin_timings);
+    let normal = StatsOptions::from_printer_preset(StatsPreset::Normal);
+    assert!(normal.assets && !normal.chunk_modules && !normal.used_exports);
+
+    let verbose = StatsOptions::from_printer_preset(StatsPreset::Verbose);
+    assert!(verbose.chunk_modules && verbose.plugin_timings);
+  }
 }
@@ -256,14 +256,43 @@ pub trait Plugin: Debug + Send + Sync {
     Ok(())
   }
 
+  /// Restore module ids from [Compilation::records] before `module_ids`.
+  fn revive_modules(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
   fn module_ids(&mut self, _modules: &mut Compilation) -> Result<()> {
     Ok(())
   }
 
+  /// Restore chunk ids from [Compilation::records] before `chunk_ids`.
+  fn revive_chunks(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
   fn chunk_ids(&mut self, _compilation: &mut Compilation) -> Result<()> {
     Ok(())
   }
 
+  /// Store the assigned module ids in [Compilation::records].
+  fn record_modules(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
+  /// Store the assigned chunk ids in [Compilation::records].
+  fn record_chunks(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
+  /// Called when the compilation starts to seal, before the chunk graph is built.
+  fn seal(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
+  fn before_code_generation(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    Ok(())
+  }
+
   async fn emit(&mut self, _compilation: &mut Compilation) -> Result<()> {
     Ok(())
   }
@@ -171,6 +171,7 @@ impl<'me> RenderChunkArgs<'me> {
 
 #[derive(Debug)]
 pub struct RenderModuleContentArgs<'a> {
+  pub module_identifier: ModuleIdentifier,
   pub module_source: &'a BoxSource,
   pub compilation: &'a Compilation,
 }
@@ -0,0 +1,158 @@
+use std::{
+  future::Future,
+  time::{Duration, Instant},
+};
+
+use dashmap::DashMap;
+use tracing::Instrument;
+
+/// Wall-clock time taken by a plugin in a hook.
+#[derive(Debug, Clone)]
+pub struct PluginHookTiming {
+  pub plugin: &'static str,
+  pub hook: &'static str,
+  pub calls: u32,
+  pub duration: Duration,
+}
+
+/// Records the time taken by each plugin in each hook of [crate::PluginDriver].
+#[derive(Debug, Default)]
+pub struct PluginHookTimings {
+  /// Same as [crate::CompilerOptions::profile], the hooks called for each module are only timed
+  /// when it's enabled.
+  profile: bool,
+  timings: DashMap<(&'static str, &'static str), (u32, Duration)>,
+}
+
+/// Span around the hook of a single plugin, the profile of `rspack_tracing` sums them up by plugin.
+fn plugin_hook_span(plugin: &'static str, hook: &'static str) -> tracing::Span {
+  tracing::trace_span!("plugin_hook", plugin, hook)
+}
+
+impl PluginHookTimings {
+  pub fn new(profile: bool) -> Self {
+    Self {
+      profile,
+      timings: Default::default(),
+    }
+  }
+
+  pub fn time<R>(&self, plugin: &'static str, hook: &'static str, f: impl FnOnce() -> R) -> R {
+    let _span = plugin_hook_span(plugin, hook).entered();
+    let start = Instant::now();
+    let result = f();
+    self.record(plugin, hook, start.elapsed());
+    result
+  }
+
+  pub async fn time_async<R>(
+    &self,
+    plugin: &'static str,
+    hook: &'static str,
+    future: impl Future<Output = R>,
+  ) -> R {
+    let start = Instant::now();
+    let result = future.instrument(plugin_hook_span(plugin, hook)).await;
+    self.record(plugin, hook, start.elapsed());
+    result
+  }
+
+  pub fn time_module_hook<R>(
+    &self,
+    plugin: &'static str,
+    hook: &'static str,
+    f: impl FnOnce() -> R,
+  ) -> R {
+    if self.profile {
+      self.time(plugin, hook, f)
+    } else {
+      f()
+    }
+  }
+
+  pub async fn time_module_hook_async<R>(
+    &self,
+    plugin: &'static str,
+    hook: &'static str,
+    future: impl Future<Output = R>,
+  ) -> R {
+    if self.profile {
+      self.time_async(plugin, hook, future).await
+    } else {
+      future.await
+    }
+  }
+
+  fn record(&self, plugin: &'static str, hook: &'static str, duration: Duration) {
+    let mut timing = self.timings.entry((plugin, hook)).or_default();
+    timing.0 += 1;
+    timing.1 += duration;
+  }
+
+  pub fn clear(&self) {
+    self.timings.clear();
+  }
+
+  /// Timings sorted by the duration in descending order.
+  pub fn to_vec(&self) -> Vec<PluginHookTiming> {
+    let mut timings = self
+      .timings
+      .iter()
+      .map(|entry| {
+        let ((plugin, hook), (calls, duration)) = (*entry.key(), *entry.value());
+        PluginHookTiming {
+          plugin,
+          hook,
+          calls,
+          duration,
+        }
+      })
+      .collect::<Vec<_>>();
+    timings.sort_unstable_by(|a, b| {
+      b.duration
+        .cmp(&a.duration)
+        .then_with(|| a.plugin.cmp(b.plugin))
+        .then_with(|| a.hook.cmp(b.hook))
+    });
+    timings
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn record_hook_timings() {
+    let timings = PluginHookTimings::default();
+    for _ in 0..2 {
+      timings.time("a", "emit", || std::thread::sleep(Duration::from_millis(2)));
+    }
+    timings.time("b", "emit", || {});
+    let result = timings.to_vec();
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+      (result[0].plugin, result[0].hook, result[0].calls),
+      ("a", "emit", 2)
+    );
+    assert!(result[0].duration >= Duration::from_millis(4));
+    assert_eq!((result[1].plugin, result[1].calls), ("b", 1));
+    timings.clear();
+    assert!(timings.to_vec().is_empty());
+  }
+
+  #[test]
+  fn time_module_hooks_only_when_profiling() {
+    let timings = PluginHookTimings::new(false);
+    assert_eq!(timings.time_module_hook("a", "module", || 1), 1);
+    assert!(timings.to_vec().is_empty());
+
+    let timings = PluginHookTimings::new(true);
+    timings.time_module_hook("a", "module", || {});
+    let result = timings.to_vec();
+    assert_eq!(
+      (result[0].plugin, result[0].hook, result[0].calls),
+      ("a", "module", 1)
+    );
+  }
+}
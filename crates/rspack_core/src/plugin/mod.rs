@@ -1,5 +1,7 @@
 mod plugin_driver;
 pub use plugin_driver::*;
+mod hook_timings;
+pub use hook_timings::*;
 mod api;
 pub use api::*;
 mod context;
@@ -14,8 +14,8 @@ use crate::{
   Module, ModuleArgs, ModuleType, NormalModuleFactoryContext,
   NormalModuleFactoryResolveForSchemeArgs, OptimizeChunksArgs, Plugin,
   PluginAdditionalChunkRuntimeRequirementsOutput, PluginBuildEndHookOutput,
-  PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput, PluginMakeHookOutput,
-  PluginModuleHookOutput, PluginNormalModuleFactoryResolveForSchemeOutput,
+  PluginCompilationHookOutput, PluginContext, PluginFactorizeHookOutput, PluginHookTimings,
+  PluginMakeHookOutput, PluginModuleHookOutput, PluginNormalModuleFactoryResolveForSchemeOutput,
   PluginProcessAssetsOutput, PluginRenderChunkHookOutput, PluginRenderHookOutput,
   PluginRenderManifestHookOutput, PluginRenderModuleContentOutput, PluginRenderStartupHookOutput,
   PluginThisCompilationHookOutput, ProcessAssetsArgs, RenderArgs, RenderChunkArgs,
@@ -31,6 +31,8 @@ pub struct PluginDriver {
   pub registered_parser_and_generator_builder: HashMap<ModuleType, BoxedParserAndGeneratorBuilder>,
   /// Collecting error generated by plugin phase, e.g., `Syntax Error`
   pub diagnostics: Arc<Mutex<Vec<Diagnostic>>>,
+  /// Time taken by each plugin in each hook of the current compilation.
+  pub hook_timings: PluginHookTimings,
 }
 
 impl std::fmt::Debug for PluginDriver {
@@ -41,6 +43,7 @@ impl std::fmt::Debug for PluginDriver {
       // field("registered_parser", &self.registered_parser)
       .field("registered_parser_and_generator_builder", &"{..}")
       .field("diagnostics", &self.diagnostics)
+      .field("hook_timings", &self.hook_timings)
       .finish()
   }
 }
@@ -68,6 +71,7 @@ impl PluginDriver {
       })
       .collect::<HashMap<ModuleType, BoxedParserAndGeneratorBuilder>>();
 
+    let hook_timings = PluginHookTimings::new(options.profile);
     Self {
       options,
       plugins,
@@ -75,6 +79,7 @@ impl PluginDriver {
       // registered_parser,
       registered_parser_and_generator_builder,
       diagnostics: Arc::new(Mutex::new(vec![])),
+      hook_timings,
     }
   }
 
@@ -90,7 +95,14 @@ impl PluginDriver {
   /// For the most of time, you would not need this.
   pub async fn read_resource(&self, resource_data: &ResourceData) -> Result<Option<Content>> {
     for plugin in &self.plugins {
-      let result = plugin.read_resource(resource_data).await?;
+      let result = self
+        .hook_timings
+        .time_module_hook_async(
+          plugin.name(),
+          "read_resource",
+          plugin.read_resource(resource_data),
+        )
+        .await?;
       if result.is_some() {
         return Ok(result);
       }
@@ -140,7 +152,14 @@ impl PluginDriver {
     compilation: &mut Compilation,
   ) -> PluginCompilationHookOutput {
     for plugin in &mut self.plugins {
-      plugin.compilation(CompilationArgs { compilation }).await?;
+      self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "compilation",
+          plugin.compilation(CompilationArgs { compilation }),
+        )
+        .await?;
     }
 
     Ok(())
@@ -154,10 +173,15 @@ impl PluginDriver {
     compilation: &mut Compilation,
   ) -> PluginThisCompilationHookOutput {
     for plugin in &mut self.plugins {
-      plugin
-        .this_compilation(ThisCompilationArgs {
-          this_compilation: compilation,
-        })
+      self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "this_compilation",
+          plugin.this_compilation(ThisCompilationArgs {
+            this_compilation: compilation,
+          }),
+        )
         .await?;
     }
 
@@ -170,7 +194,14 @@ impl PluginDriver {
   ) -> Result<Vec<Option<(SourceType, String)>>> {
     let mut result = vec![];
     for plugin in &self.plugins {
-      let hash = plugin.content_hash(PluginContext::new(), args).await?;
+      let hash = self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "content_hash",
+          plugin.content_hash(PluginContext::new(), args),
+        )
+        .await?;
       result.push(hash);
     }
     Ok(result)
@@ -182,8 +213,13 @@ impl PluginDriver {
   ) -> PluginRenderManifestHookOutput {
     let mut assets = vec![];
     for plugin in &self.plugins {
-      let res = plugin
-        .render_manifest(PluginContext::new(), args.clone())
+      let res = self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "render_manifest",
+          plugin.render_manifest(PluginContext::new(), args.clone()),
+        )
         .await?;
       tracing::trace!(
         "For Chunk({:?}), Plugin({}) generate files {:?}",
@@ -201,7 +237,15 @@ impl PluginDriver {
 
   pub async fn render_chunk(&self, args: RenderChunkArgs<'_>) -> PluginRenderChunkHookOutput {
     for plugin in &self.plugins {
-      if let Some(source) = plugin.render_chunk(PluginContext::new(), &args).await? {
+      if let Some(source) = self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "render_chunk",
+          plugin.render_chunk(PluginContext::new(), &args),
+        )
+        .await?
+      {
         return Ok(Some(source));
       }
     }
@@ -210,7 +254,9 @@ impl PluginDriver {
 
   pub fn render(&self, args: RenderArgs) -> PluginRenderHookOutput {
     for plugin in &self.plugins {
-      if let Some(source) = plugin.render(PluginContext::new(), &args)? {
+      if let Some(source) = self.hook_timings.time(plugin.name(), "render", || {
+        plugin.render(PluginContext::new(), &args)
+      })? {
         return Ok(Some(source));
       }
     }
@@ -219,7 +265,12 @@ impl PluginDriver {
 
   pub fn render_startup(&self, args: RenderStartupArgs) -> PluginRenderStartupHookOutput {
     for plugin in &self.plugins {
-      if let Some(source) = plugin.render_startup(PluginContext::new(), &args)? {
+      if let Some(source) = self
+        .hook_timings
+        .time(plugin.name(), "render_startup", || {
+          plugin.render_startup(PluginContext::new(), &args)
+        })?
+      {
         return Ok(Some(source));
       }
     }
@@ -231,7 +282,13 @@ impl PluginDriver {
     args: RenderModuleContentArgs,
   ) -> PluginRenderModuleContentOutput {
     for plugin in &self.plugins {
-      if let Some(source) = plugin.render_module_content(PluginContext::new(), &args)? {
+      if let Some(source) =
+        self
+          .hook_timings
+          .time_module_hook(plugin.name(), "render_module_content", || {
+            plugin.render_module_content(PluginContext::new(), &args)
+          })?
+      {
         return Ok(Some(source));
       }
     }
@@ -244,8 +301,13 @@ impl PluginDriver {
     job_ctx: &mut NormalModuleFactoryContext,
   ) -> PluginFactorizeHookOutput {
     for plugin in &self.plugins {
-      if let Some(module) = plugin
-        .factorize(PluginContext::new(), args.clone(), job_ctx)
+      if let Some(module) = self
+        .hook_timings
+        .time_module_hook_async(
+          plugin.name(),
+          "factorize",
+          plugin.factorize(PluginContext::new(), args.clone(), job_ctx),
+        )
         .await?
       {
         return Ok(Some(module));
@@ -257,7 +319,15 @@ impl PluginDriver {
   pub async fn module(&self, args: ModuleArgs) -> PluginModuleHookOutput {
     for plugin in &self.plugins {
       tracing::trace!("running render runtime:{}", plugin.name());
-      if let Some(module) = plugin.module(PluginContext::new(), &args).await? {
+      if let Some(module) = self
+        .hook_timings
+        .time_module_hook_async(
+          plugin.name(),
+          "module",
+          plugin.module(PluginContext::new(), &args),
+        )
+        .await?
+      {
         return Ok(Some(module));
       }
     }
@@ -270,8 +340,13 @@ impl PluginDriver {
   ) -> PluginNormalModuleFactoryResolveForSchemeOutput {
     for plugin in &self.plugins {
       tracing::trace!("running resolve for scheme:{}", plugin.name());
-      if let Some(data) = plugin
-        .normal_module_factory_resolve_for_scheme(PluginContext::new(), &args)
+      if let Some(data) = self
+        .hook_timings
+        .time_module_hook_async(
+          plugin.name(),
+          "normal_module_factory_resolve_for_scheme",
+          plugin.normal_module_factory_resolve_for_scheme(PluginContext::new(), &args),
+        )
         .await?
       {
         return Ok(Some(data));
@@ -286,7 +361,11 @@ impl PluginDriver {
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
     for plugin in &self.plugins {
-      plugin.additional_chunk_runtime_requirements(PluginContext::new(), args)?;
+      self.hook_timings.time(
+        plugin.name(),
+        "additional_chunk_runtime_requirements",
+        || plugin.additional_chunk_runtime_requirements(PluginContext::new(), args),
+      )?;
     }
     Ok(())
   }
@@ -297,7 +376,11 @@ impl PluginDriver {
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
     for plugin in &self.plugins {
-      plugin.additional_tree_runtime_requirements(PluginContext::new(), args)?;
+      self.hook_timings.time(
+        plugin.name(),
+        "additional_tree_runtime_requirements",
+        || plugin.additional_tree_runtime_requirements(PluginContext::new(), args),
+      )?;
     }
     Ok(())
   }
@@ -308,7 +391,11 @@ impl PluginDriver {
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
     for plugin in &self.plugins {
-      plugin.runtime_requirements_in_tree(PluginContext::new(), args)?;
+      self
+        .hook_timings
+        .time(plugin.name(), "runtime_requirements_in_tree", || {
+          plugin.runtime_requirements_in_tree(PluginContext::new(), args)
+        })?;
     }
     Ok(())
   }
@@ -318,12 +405,17 @@ impl PluginDriver {
     macro_rules! run_stage {
       ($stage: ident) => {
         for plugin in &mut self.plugins {
-          plugin
-            .$stage(
-              PluginContext::new(),
-              ProcessAssetsArgs {
-                compilation: args.compilation,
-              },
+          self
+            .hook_timings
+            .time_async(
+              plugin.name(),
+              stringify!($stage),
+              plugin.$stage(
+                PluginContext::new(),
+                ProcessAssetsArgs {
+                  compilation: args.compilation,
+                },
+              ),
             )
             .await?;
         }
@@ -343,7 +435,14 @@ impl PluginDriver {
   #[instrument(name = "plugin:make", skip_all)]
   pub async fn make(&self, compilation: &Compilation) -> PluginMakeHookOutput {
     for plugin in &self.plugins {
-      plugin.make(PluginContext::new(), compilation).await?;
+      self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "make",
+          plugin.make(PluginContext::new(), compilation),
+        )
+        .await?;
     }
     Ok(())
   }
@@ -351,8 +450,13 @@ impl PluginDriver {
   #[instrument(name = "plugin:done", skip_all)]
   pub async fn done<'s, 'c>(&mut self, stats: &'s mut Stats<'c>) -> PluginBuildEndHookOutput {
     for plugin in &mut self.plugins {
-      plugin
-        .done(PluginContext::new(), DoneArgs { stats })
+      self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "done",
+          plugin.done(PluginContext::new(), DoneArgs { stats }),
+        )
         .await?;
     }
     Ok(())
@@ -360,7 +464,11 @@ impl PluginDriver {
   #[instrument(name = "plugin:optimize_chunks", skip_all)]
   pub fn optimize_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.optimize_chunks(PluginContext::new(), OptimizeChunksArgs { compilation })?;
+      self
+        .hook_timings
+        .time(plugin.name(), "optimize_chunks", || {
+          plugin.optimize_chunks(PluginContext::new(), OptimizeChunksArgs { compilation })
+        })?;
     }
     Ok(())
   }
@@ -368,8 +476,13 @@ impl PluginDriver {
   #[instrument(name = "plugin:optimize_chunk_modules", skip_all)]
   pub async fn optimize_chunk_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin
-        .optimize_chunk_modules(OptimizeChunksArgs { compilation })
+      self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "optimize_chunk_modules",
+          plugin.optimize_chunk_modules(OptimizeChunksArgs { compilation }),
+        )
         .await?;
     }
     Ok(())
@@ -378,21 +491,38 @@ impl PluginDriver {
   #[instrument(name = "plugin:finish_modules", skip_all)]
   pub async fn finish_modules(&mut self, modules: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.finish_modules(modules).await?;
+      self
+        .hook_timings
+        .time_async(
+          plugin.name(),
+          "finish_modules",
+          plugin.finish_modules(modules),
+        )
+        .await?;
     }
     Ok(())
   }
 
   pub async fn build_module(&self, module: &mut dyn Module) -> Result<()> {
     for plugin in &self.plugins {
-      plugin.build_module(module).await?;
+      self
+        .hook_timings
+        .time_module_hook_async(plugin.name(), "build_module", plugin.build_module(module))
+        .await?;
     }
     Ok(())
   }
 
   pub async fn succeed_module(&self, module: &dyn Module) -> Result<()> {
     for plugin in &self.plugins {
-      plugin.succeed_module(module).await?;
+      self
+        .hook_timings
+        .time_module_hook_async(
+          plugin.name(),
+          "succeed_module",
+          plugin.succeed_module(module),
+        )
+        .await?;
     }
     Ok(())
   }
@@ -400,7 +530,9 @@ impl PluginDriver {
   #[instrument(name = "plugin:module_ids", skip_all)]
   pub fn module_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.module_ids(compilation)?;
+      self.hook_timings.time(plugin.name(), "module_ids", || {
+        plugin.module_ids(compilation)
+      })?;
     }
     Ok(())
   }
@@ -408,7 +540,75 @@ impl PluginDriver {
   #[instrument(name = "plugin:chunk_ids", skip_all)]
   pub fn chunk_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.chunk_ids(compilation)?;
+      self
+        .hook_timings
+        .time(plugin.name(), "chunk_ids", || plugin.chunk_ids(compilation))?;
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "plugin:revive_modules", skip_all)]
+  pub fn revive_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &mut self.plugins {
+      self
+        .hook_timings
+        .time(plugin.name(), "revive_modules", || {
+          plugin.revive_modules(compilation)
+        })?;
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "plugin:revive_chunks", skip_all)]
+  pub fn revive_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &mut self.plugins {
+      self.hook_timings.time(plugin.name(), "revive_chunks", || {
+        plugin.revive_chunks(compilation)
+      })?;
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "plugin:record_modules", skip_all)]
+  pub fn record_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &mut self.plugins {
+      self
+        .hook_timings
+        .time(plugin.name(), "record_modules", || {
+          plugin.record_modules(compilation)
+        })?;
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "plugin:record_chunks", skip_all)]
+  pub fn record_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &mut self.plugins {
+      self.hook_timings.time(plugin.name(), "record_chunks", || {
+        plugin.record_chunks(compilation)
+      })?;
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "plugin:seal", skip_all)]
+  pub fn seal(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &mut self.plugins {
+      self
+        .hook_timings
+        .time(plugin.name(), "seal", || plugin.seal(compilation))?;
+    }
+    Ok(())
+  }
+
+  #[instrument(name = "plugin:before_code_generation", skip_all)]
+  pub fn before_code_generation(&mut self, compilation: &mut Compilation) -> Result<()> {
+    for plugin in &mut self.plugins {
+      self
+        .hook_timings
+        .time(plugin.name(), "before_code_generation", || {
+          plugin.before_code_generation(compilation)
+        })?;
     }
     Ok(())
   }
@@ -416,7 +616,10 @@ impl PluginDriver {
   #[instrument(name = "plugin:emit", skip_all)]
   pub async fn emit(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.emit(compilation).await?;
+      self
+        .hook_timings
+        .time_async(plugin.name(), "emit", plugin.emit(compilation))
+        .await?;
     }
     Ok(())
   }
@@ -424,7 +627,10 @@ impl PluginDriver {
   #[instrument(name = "plugin:after_emit", skip_all)]
   pub async fn after_emit(&mut self, compilation: &mut Compilation) -> Result<()> {
     for plugin in &mut self.plugins {
-      plugin.after_emit(compilation).await?;
+      self
+        .hook_timings
+        .time_async(plugin.name(), "after_emit", plugin.after_emit(compilation))
+        .await?;
     }
     Ok(())
   }
@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Ids assigned in a previous build, read from `records_input_path` and written to
+/// `records_output_path`.
+///
+/// Align with the records of webpack, see https://webpack.js.org/configuration/other-options/#recordspath
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Records {
+  #[serde(default)]
+  pub modules: ModuleRecords,
+  #[serde(default)]
+  pub chunks: ChunkRecords,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleRecords {
+  /// Module ids keyed by the module identifier relative to the context.
+  #[serde(default)]
+  pub by_identifier: BTreeMap<String, String>,
+  #[serde(default)]
+  pub used_ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkRecords {
+  #[serde(default)]
+  pub by_name: BTreeMap<String, String>,
+  /// Chunk ids keyed by the root modules of the chunk.
+  #[serde(default)]
+  pub by_source: BTreeMap<String, String>,
+  #[serde(default)]
+  pub used_ids: Vec<String>,
+}
@@ -1,3 +1,7 @@
+mod printer;
+
+use std::{collections::BTreeMap, fmt::Display};
+
 use rspack_error::{
   emitter::{
     DiagnosticDisplay, DiagnosticDisplayer, StdioDiagnosticDisplay, StringDiagnosticDisplay,
@@ -5,9 +9,11 @@ use rspack_error::{
   Result,
 };
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde::{Serialize, Serializer};
 
 use crate::{
-  BoxModule, Chunk, ChunkGroupUkey, Compilation, ModuleIdentifier, ModuleType, SourceType,
+  BoxModule, Chunk, ChunkGroupUkey, Compilation, ModuleIdentifier, ModuleType, PublicPath,
+  SourceType, StatsOptions,
 };
 
 #[derive(Debug, Clone)]
@@ -110,18 +116,35 @@ impl Stats<'_> {
   }
 
   pub fn get_modules(&self) -> Result<Vec<StatsModule>> {
+    self.modules(&self.compilation.options.stats)
+  }
+
+  fn modules(&self, options: &StatsOptions) -> Result<Vec<StatsModule>> {
     let mut modules: Vec<StatsModule> = self
       .compilation
       .module_graph
       .modules()
       .values()
-      .map(|module| self.get_module(module, self.compilation.options.stats.reasons))
+      .map(|module| self.get_module(module, options))
       .collect::<Result<_>>()?;
     Self::sort_modules(&mut modules);
     Ok(modules)
   }
 
   pub fn get_chunks(&self, chunk_modules: bool, chunk_relations: bool) -> Result<Vec<StatsChunk>> {
+    self.chunks(
+      chunk_modules,
+      chunk_relations,
+      &self.compilation.options.stats,
+    )
+  }
+
+  fn chunks(
+    &self,
+    chunk_modules: bool,
+    chunk_relations: bool,
+    options: &StatsOptions,
+  ) -> Result<Vec<StatsChunk>> {
     let mut chunks: Vec<StatsChunk> = self
       .compilation
       .chunk_by_ukey
@@ -136,7 +159,7 @@ impl Stats<'_> {
             .get_chunk_modules(&c.ukey, &self.compilation.module_graph);
           let mut chunk_modules = chunk_modules
             .into_iter()
-            .map(|m| self.get_module(m, self.compilation.options.stats.reasons))
+            .map(|m| self.get_module(m, options))
             .collect::<Result<Vec<_>>>()?;
           Self::sort_modules(&mut chunk_modules);
           Some(chunk_modules)
@@ -243,7 +266,11 @@ impl Stats<'_> {
   }
 
   pub fn get_errors(&self) -> Vec<StatsError> {
-    let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
+    self.errors(self.compilation.options.stats.colors)
+  }
+
+  fn errors(&self, colors: bool) -> Vec<StatsError> {
+    let mut diagnostic_displayer = DiagnosticDisplayer::new(colors);
     self
       .compilation
       .get_errors()
@@ -255,7 +282,11 @@ impl Stats<'_> {
   }
 
   pub fn get_warnings(&self) -> Vec<StatsWarning> {
-    let mut diagnostic_displayer = DiagnosticDisplayer::new(self.compilation.options.stats.colors);
+    self.warnings(self.compilation.options.stats.colors)
+  }
+
+  fn warnings(&self, colors: bool) -> Vec<StatsWarning> {
+    let mut diagnostic_displayer = DiagnosticDisplayer::new(colors);
     self
       .compilation
       .get_warnings()
@@ -270,6 +301,202 @@ impl Stats<'_> {
     self.compilation.hash.to_owned()
   }
 
+  /// Stats in the format of webpack, it's serializable to the JSON read by tools like
+  /// webpack-bundle-analyzer and statoscope.
+  pub fn to_json(&self, options: &StatsOptions) -> Result<StatsCompilation> {
+    let compilation = self.compilation;
+    let (assets, assets_by_chunk_name) = if options.assets {
+      let (assets, assets_by_chunk_name) = self.get_assets();
+      let assets_by_chunk_name = assets_by_chunk_name
+        .into_iter()
+        .map(|assets| (assets.name, assets.files))
+        .collect();
+      (Some(assets), Some(assets_by_chunk_name))
+    } else {
+      (None, None)
+    };
+    let chunks = options
+      .chunks
+      .then(|| self.chunks(options.chunk_modules, options.chunk_relations, options))
+      .transpose()?;
+    let modules = options.modules.then(|| self.modules(options)).transpose()?;
+
+    Ok(StatsCompilation {
+      hash: options.hash.then(|| self.get_hash()),
+      time: compilation
+        .end_time
+        .filter(|_| options.timings)
+        .map(|end_time| end_time.saturating_sub(compilation.start_time)),
+      built_at: compilation.end_time.filter(|_| options.built_at),
+      public_path: options
+        .public_path
+        .then(|| match &compilation.options.output.public_path {
+          PublicPath::String(public_path) => public_path.clone(),
+          PublicPath::Auto => "auto".to_string(),
+        }),
+      output_path: options.output_path.then(|| {
+        compilation
+          .options
+          .output
+          .path
+          .to_string_lossy()
+          .to_string()
+      }),
+      assets,
+      assets_by_chunk_name,
+      chunks,
+      modules,
+      entrypoints: options
+        .entrypoints
+        .then(|| chunk_groups_by_name(self.get_entrypoints())),
+      named_chunk_groups: options
+        .chunk_groups
+        .then(|| chunk_groups_by_name(self.get_named_chunk_groups())),
+      errors: options.errors.then(|| self.errors(options.colors)),
+      errors_count: options
+        .errors_count
+        .then(|| compilation.get_errors().count()),
+      warnings: options.warnings.then(|| self.warnings(options.colors)),
+      warnings_count: options
+        .warnings_count
+        .then(|| compilation.get_warnings().count()),
+      plugin_hook_timings: options
+        .plugin_timings
+        .then(|| self.get_plugin_hook_timings()),
+    })
+  }
+
+  pub fn get_plugin_hook_timings(&self) -> Vec<StatsPluginHookTiming> {
+    self
+      .compilation
+      .plugin_hook_timings
+      .iter()
+      .map(|timing| StatsPluginHookTiming {
+        plugin: timing.plugin.to_string(),
+        hook: timing.hook.to_string(),
+        calls: timing.calls,
+        duration: timing.duration.as_secs_f64() * 1000.0,
+      })
+      .collect()
+  }
+
+  /// The whole module graph, for tools answering why a module is in the bundle.
+  pub fn get_module_graph(&self) -> StatsModuleGraph {
+    let module_graph = &self.compilation.module_graph;
+    let mut entries = self
+      .compilation
+      .entry_module_identifiers
+      .iter()
+      .map(|identifier| identifier.to_string())
+      .collect::<Vec<_>>();
+    entries.sort_unstable();
+
+    let mut modules = module_graph
+      .modules()
+      .values()
+      .map(|module| {
+        let (name, id) = get_stats_module_name_and_id(module, self.compilation);
+        StatsModuleGraphModule {
+          identifier: module.identifier().to_string(),
+          name,
+          id,
+        }
+      })
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| a.identifier.cmp(&b.identifier));
+
+    let mut connections = module_graph
+      .modules()
+      .values()
+      .flat_map(|module| {
+        module_graph
+          .get_outgoing_connections(module)
+          .into_iter()
+          .map(|connection| StatsModuleGraphConnection {
+            origin_identifier: module.identifier().to_string(),
+            module_identifier: connection.module_identifier.to_string(),
+          })
+      })
+      .collect::<Vec<_>>();
+    connections.sort_unstable_by(|a, b| {
+      (&a.origin_identifier, &a.module_identifier)
+        .cmp(&(&b.origin_identifier, &b.module_identifier))
+    });
+    connections.dedup_by(|a, b| {
+      a.origin_identifier == b.origin_identifier && a.module_identifier == b.module_identifier
+    });
+
+    let cycles = module_graph
+      .get_cycles()
+      .into_iter()
+      .map(|cycle| cycle.iter().map(|i| i.to_string()).collect())
+      .collect();
+
+    StatsModuleGraph {
+      entries,
+      modules,
+      connections,
+      cycles,
+    }
+  }
+
+  /// The module graph in the GraphViz DOT format, entry modules are drawn as boxes.
+  pub fn get_module_graph_dot(&self) -> String {
+    let graph = self.get_module_graph();
+    let mut dot = String::from("digraph modules {\n");
+    for module in &graph.modules {
+      let shape = if graph.entries.contains(&module.identifier) {
+        ", shape=box"
+      } else {
+        ""
+      };
+      dot.push_str(&format!(
+        "  {} [label={}{shape}];\n",
+        to_dot_id(&module.identifier),
+        to_dot_id(&module.name)
+      ));
+    }
+    for connection in &graph.connections {
+      dot.push_str(&format!(
+        "  {} -> {};\n",
+        to_dot_id(&connection.origin_identifier),
+        to_dot_id(&connection.module_identifier)
+      ));
+    }
+    dot.push_str("}\n");
+    dot
+  }
+
+  /// The shortest chain of imports from an entry module to the module, both included.
+  pub fn get_module_import_path(&self, identifier: &str) -> Option<Vec<StatsModuleIssuer>> {
+    let module_graph = &self.compilation.module_graph;
+    let identifier = ModuleIdentifier::from(identifier);
+    let mut entries = self
+      .compilation
+      .entry_module_identifiers
+      .iter()
+      .collect::<Vec<_>>();
+    entries.sort_unstable();
+    entries
+      .into_iter()
+      .filter_map(|entry| module_graph.get_import_path(entry, &identifier))
+      .min_by_key(|path| path.len())
+      .map(|path| {
+        path
+          .iter()
+          .filter_map(|i| module_graph.module_by_identifier(i))
+          .map(|module| {
+            let (name, id) = get_stats_module_name_and_id(module, self.compilation);
+            StatsModuleIssuer {
+              identifier: module.identifier().to_string(),
+              name,
+              id,
+            }
+          })
+          .collect()
+      })
+  }
+
   fn sort_modules(modules: &mut [StatsModule]) {
     // TODO: sort by module.depth
     modules.sort_unstable_by(|a, b| {
@@ -281,7 +508,7 @@ impl Stats<'_> {
     });
   }
 
-  fn get_module(&self, module: &BoxModule, show_reasons: bool) -> Result<StatsModule> {
+  fn get_module(&self, module: &BoxModule, options: &StatsOptions) -> Result<StatsModule> {
     let identifier = module.identifier();
     let mgm = self
       .compilation
@@ -306,7 +533,8 @@ impl Stats<'_> {
     }
     issuer_path.reverse();
 
-    let reasons = show_reasons
+    let reasons = options
+      .reasons
       .then(|| -> Result<_> {
         let mut reasons: Vec<StatsModuleReason> = mgm
           .incoming_connections_unordered(&self.compilation.module_graph)?
@@ -346,6 +574,33 @@ impl Stats<'_> {
       .collect();
     chunks.sort_unstable();
 
+    let exports_info = self.compilation.exports_info.get(&identifier);
+    let bailout = self.compilation.bailout_module_identifiers.get(&identifier);
+    let provided_exports = options
+      .provided_exports
+      .then(|| exports_info.map(|info| to_strings(&info.provided_exports)))
+      .flatten();
+    // Exports of a bailed out module are all used.
+    let used_exports = options
+      .used_exports
+      .then(|| {
+        exports_info
+          .filter(|_| bailout.is_none())
+          .map(|info| to_strings(&info.used_exports))
+      })
+      .flatten();
+    let optimization_bailout = options.optimization_bailout.then(|| {
+      bailout
+        .map(|bailout| {
+          bailout
+            .reasons()
+            .into_iter()
+            .map(|reason| format!("Tree shaking bailout: {reason}"))
+            .collect()
+        })
+        .unwrap_or_default()
+    });
+
     Ok(StatsModule {
       r#type: "module",
       module_type: *module.module_type(),
@@ -361,6 +616,9 @@ impl Stats<'_> {
       issuer_id,
       issuer_path,
       reasons,
+      provided_exports,
+      used_exports,
+      optimization_bailout,
     })
   }
 
@@ -409,6 +667,28 @@ impl Stats<'_> {
   }
 }
 
+fn chunk_groups_by_name(chunk_groups: Vec<StatsChunkGroup>) -> BTreeMap<String, StatsChunkGroup> {
+  chunk_groups
+    .into_iter()
+    .map(|chunk_group| (chunk_group.name.clone(), chunk_group))
+    .collect()
+}
+
+fn to_strings(words: &[impl Display]) -> Vec<String> {
+  words.iter().map(ToString::to_string).collect()
+}
+
+fn serialize_to_string<S: Serializer>(
+  value: &impl Display,
+  serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+  serializer.collect_str(value)
+}
+
+fn to_dot_id(s: &str) -> String {
+  format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 fn get_stats_module_name_and_id(module: &BoxModule, compilation: &Compilation) -> (String, String) {
   let identifier = module.identifier();
   let mgm = compilation
@@ -422,19 +702,62 @@ fn get_stats_module_name_and_id(module: &BoxModule, compilation: &Compilation) -
   (name.to_string(), id.to_string())
 }
 
-#[derive(Debug)]
+/// The stats of a compilation, see https://webpack.js.org/api/stats/#structure.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsCompilation {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hash: Option<String>,
+  /// Milliseconds.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub time: Option<u64>,
+  /// Milliseconds since the Unix epoch.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub built_at: Option<u64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub public_path: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub output_path: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub assets: Option<Vec<StatsAsset>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub assets_by_chunk_name: Option<BTreeMap<String, Vec<String>>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub chunks: Option<Vec<StatsChunk>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub modules: Option<Vec<StatsModule>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub entrypoints: Option<BTreeMap<String, StatsChunkGroup>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub named_chunk_groups: Option<BTreeMap<String, StatsChunkGroup>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub errors: Option<Vec<StatsError>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub errors_count: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub warnings: Option<Vec<StatsWarning>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub warnings_count: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub plugin_hook_timings: Option<Vec<StatsPluginHookTiming>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsError {
   pub message: String,
   pub formatted: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsWarning {
   pub message: String,
   pub formatted: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAsset {
   pub r#type: &'static str,
   pub name: String,
@@ -445,22 +768,27 @@ pub struct StatsAsset {
   pub emitted: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetsByChunkName {
   pub name: String,
   pub files: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsAssetInfo {
   pub development: bool,
   pub hot_module_replacement: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModule {
   pub r#type: &'static str,
+  #[serde(serialize_with = "serialize_to_string")]
   pub module_type: ModuleType,
+  #[serde(serialize_with = "serialize_to_string")]
   pub identifier: ModuleIdentifier,
   pub name: String,
   pub id: String,
@@ -470,10 +798,20 @@ pub struct StatsModule {
   pub issuer_name: Option<String>,
   pub issuer_id: Option<String>,
   pub issuer_path: Vec<StatsModuleIssuer>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub reasons: Option<Vec<StatsModuleReason>>,
+  /// Only known when tree shaking is enabled.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub provided_exports: Option<Vec<String>>,
+  /// Only known when tree shaking is enabled and the module is not bailed out.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub used_exports: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub optimization_bailout: Option<Vec<String>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunk {
   pub r#type: &'static str,
   pub files: Vec<String>,
@@ -482,19 +820,25 @@ pub struct StatsChunk {
   pub initial: bool,
   pub names: Vec<String>,
   pub size: f64,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub modules: Option<Vec<StatsModule>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub parents: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub children: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
   pub siblings: Option<Vec<String>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroupAsset {
   pub name: String,
   pub size: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsChunkGroup {
   pub name: String,
   pub assets: Vec<StatsChunkGroupAsset>,
@@ -502,16 +846,54 @@ pub struct StatsChunkGroup {
   pub assets_size: f64,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleIssuer {
   pub identifier: String,
   pub name: String,
   pub id: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsPluginHookTiming {
+  pub plugin: String,
+  pub hook: String,
+  pub calls: u32,
+  /// Milliseconds.
+  pub duration: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct StatsModuleReason {
   pub module_identifier: Option<String>,
   pub module_name: Option<String>,
   pub module_id: Option<String>,
 }
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsModuleGraph {
+  /// Identifiers of the entry modules.
+  pub entries: Vec<String>,
+  pub modules: Vec<StatsModuleGraphModule>,
+  pub connections: Vec<StatsModuleGraphConnection>,
+  /// Groups of module identifiers importing each other.
+  pub cycles: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsModuleGraphModule {
+  pub identifier: String,
+  pub name: String,
+  pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsModuleGraphConnection {
+  pub origin_identifier: String,
+  pub module_identifier: String,
+}
@@ -0,0 +1,339 @@
+use std::fmt::Display;
+
+use colored::{ColoredString, Colorize};
+use rspack_error::Result;
+
+use super::{StatsChunk, StatsChunkGroup, StatsCompilation, StatsModule};
+use crate::{Stats, StatsOptions};
+
+impl Stats<'_> {
+  /// Human readable stats in the format of webpack, the options are usually created by
+  /// `StatsOptions::from_printer_preset`.
+  pub fn to_string(&self, options: &StatsOptions) -> Result<String> {
+    let stats = self.to_json(options)?;
+    Ok(StatsPrinter::new(options.colors).print(&stats))
+  }
+}
+
+struct StatsPrinter {
+  colors: bool,
+  lines: Vec<String>,
+}
+
+impl StatsPrinter {
+  fn new(colors: bool) -> Self {
+    Self {
+      colors,
+      lines: Vec::new(),
+    }
+  }
+
+  fn paint(&self, style: impl Fn(ColoredString) -> ColoredString, text: impl Display) -> String {
+    if !self.colors {
+      return text.to_string();
+    }
+    style(text.to_string().normal()).to_string()
+  }
+
+  fn print(mut self, stats: &StatsCompilation) -> String {
+    if let Some(assets) = &stats.assets {
+      for asset in assets {
+        let mut line = format!(
+          "asset {} {}",
+          self.paint(|s| s.bold().green(), &asset.name),
+          format_size(asset.size)
+        );
+        if asset.emitted {
+          line += &format!(" {}", self.paint(|s| s.green(), "[emitted]"));
+        }
+        if asset.info.development {
+          line += &format!(" {}", self.paint(|s| s.green(), "[dev]"));
+        }
+        if asset.info.hot_module_replacement {
+          line += &format!(" {}", self.paint(|s| s.green(), "[hmr]"));
+        }
+        if !asset.chunk_names.is_empty() {
+          line += &format!(" (name: {})", asset.chunk_names.join(", "));
+        }
+        self.lines.push(line);
+      }
+    }
+
+    if let Some(entrypoints) = &stats.entrypoints {
+      for entrypoint in entrypoints.values() {
+        self.print_chunk_group("entrypoint", entrypoint);
+      }
+    }
+    if let Some(chunk_groups) = &stats.named_chunk_groups {
+      for chunk_group in chunk_groups.values() {
+        let is_entrypoint = stats.entrypoints.as_ref().map_or(false, |entrypoints| {
+          entrypoints.contains_key(&chunk_group.name)
+        });
+        if !is_entrypoint {
+          self.print_chunk_group("chunk group", chunk_group);
+        }
+      }
+    }
+
+    let mut modules_printed = false;
+    if let Some(chunks) = &stats.chunks {
+      for chunk in chunks {
+        self.print_chunk(chunk);
+        if let Some(modules) = &chunk.modules {
+          modules_printed = true;
+          for module in modules {
+            self.print_module(module, "  ");
+          }
+        }
+      }
+    }
+    if let Some(modules) = &stats.modules && !modules_printed {
+      for module in modules {
+        self.print_module(module, "");
+      }
+    }
+
+    if let Some(timings) = &stats.plugin_hook_timings {
+      for timing in timings {
+        let line = format!(
+          "plugin {} {} {} in {} {}",
+          self.paint(|s| s.bold(), &timing.plugin),
+          timing.hook,
+          format_time(timing.duration),
+          timing.calls,
+          plural(timing.calls as usize, "call")
+        );
+        self.lines.push(line);
+      }
+    }
+
+    // Diagnostics are already formatted, the same as `Stats::emit_diagnostics_string`.
+    for warning in stats.warnings.iter().flatten() {
+      self.lines.push(warning.formatted.trim_end().to_string());
+    }
+    for error in stats.errors.iter().flatten() {
+      self.lines.push(error.formatted.trim_end().to_string());
+    }
+
+    if stats.time.is_some() || stats.errors_count.is_some() || stats.warnings_count.is_some() {
+      self.print_summary(stats);
+    }
+
+    self.lines.join("\n")
+  }
+
+  fn print_chunk_group(&mut self, kind: &str, chunk_group: &StatsChunkGroup) {
+    let assets = chunk_group
+      .assets
+      .iter()
+      .map(|asset| self.paint(|s| s.bold().green(), &asset.name))
+      .collect::<Vec<_>>();
+    let line = format!(
+      "{kind} {} {} = {}",
+      self.paint(|s| s.bold(), &chunk_group.name),
+      format_size(chunk_group.assets_size),
+      assets.join(" ")
+    );
+    self.lines.push(line);
+  }
+
+  fn print_chunk(&mut self, chunk: &StatsChunk) {
+    let mut line = format!("chunk {}", self.paint(|s| s.bold(), &chunk.id));
+    for file in &chunk.files {
+      line += &format!(" {}", self.paint(|s| s.bold().green(), file));
+    }
+    if !chunk.names.is_empty() {
+      line += &format!(" ({})", chunk.names.join(", "));
+    }
+    line += &format!(" {}", format_size(chunk.size));
+    if chunk.entry {
+      line += &format!(" {}", self.paint(|s| s.yellow(), "[entry]"));
+    } else if chunk.initial {
+      line += &format!(" {}", self.paint(|s| s.yellow(), "[initial]"));
+    }
+    // Same as webpack, `<parent>`, `>child<` and `=sibling=`.
+    for parent in chunk.parents.iter().flatten() {
+      line += &format!(" <{parent}>");
+    }
+    for child in chunk.children.iter().flatten() {
+      line += &format!(" >{child}<");
+    }
+    for sibling in chunk.siblings.iter().flatten() {
+      line += &format!(" ={sibling}=");
+    }
+    self.lines.push(line);
+  }
+
+  fn print_module(&mut self, module: &StatsModule, indent: &str) {
+    let line = format!(
+      "{indent}{} {}",
+      self.paint(|s| s.bold(), &module.name),
+      format_size(module.size)
+    );
+    self.lines.push(line);
+    if let Some(provided_exports) = &module.provided_exports {
+      let line = format!("{indent}  [exports: {}]", provided_exports.join(", "));
+      self.lines.push(self.paint(|s| s.cyan(), line));
+    }
+    if let Some(used_exports) = &module.used_exports {
+      let line = if used_exports.is_empty() {
+        format!("{indent}  [no exports used]")
+      } else {
+        format!("{indent}  [used exports: {}]", used_exports.join(", "))
+      };
+      self.lines.push(self.paint(|s| s.cyan(), line));
+    }
+    for bailout in module.optimization_bailout.iter().flatten() {
+      let line = format!("{indent}  {bailout}");
+      self.lines.push(self.paint(|s| s.yellow(), line));
+    }
+    for reason in module.reasons.iter().flatten() {
+      let line = match &reason.module_name {
+        Some(module_name) => format!("{indent}  from {module_name}"),
+        None => format!("{indent}  entry"),
+      };
+      self.lines.push(self.paint(|s| s.cyan(), line));
+    }
+  }
+
+  fn print_summary(&mut self, stats: &StatsCompilation) {
+    let errors_count = stats.errors_count.unwrap_or_default();
+    let warnings_count = stats.warnings_count.unwrap_or_default();
+    let status = if errors_count > 0 {
+      let mut status = format!("with {errors_count} {}", plural(errors_count, "error"));
+      if warnings_count > 0 {
+        status += &format!(
+          " and {warnings_count} {}",
+          plural(warnings_count, "warning")
+        );
+      }
+      self.paint(|s| s.bold().red(), status)
+    } else if warnings_count > 0 {
+      self.paint(
+        |s| s.bold().yellow(),
+        format!(
+          "with {warnings_count} {}",
+          plural(warnings_count, "warning")
+        ),
+      )
+    } else {
+      self.paint(|s| s.bold().green(), "successfully")
+    };
+    let mut line = format!("Rspack compiled {status}");
+    if let Some(hash) = &stats.hash {
+      line += &format!(" ({hash})");
+    }
+    if let Some(time) = stats.time {
+      line += &format!(" in {time} ms");
+    }
+    self.lines.push(line);
+  }
+}
+
+fn plural(count: usize, word: &str) -> String {
+  if count == 1 {
+    word.to_string()
+  } else {
+    format!("{word}s")
+  }
+}
+
+/// Same as `formatSize` of webpack.
+fn format_size(size: f64) -> String {
+  const ABBREVIATIONS: [&str; 4] = ["bytes", "KiB", "MiB", "GiB"];
+  if size <= 0.0 {
+    return "0 bytes".to_string();
+  }
+  let index = ((size.ln() / 1024f64.ln()).floor().max(0.0) as usize).min(ABBREVIATIONS.len() - 1);
+  let value = size / 1024f64.powi(index as i32);
+  format!(
+    "{} {}",
+    (value * 100.0).round() / 100.0,
+    ABBREVIATIONS[index]
+  )
+}
+
+fn format_time(milliseconds: f64) -> String {
+  format!("{} ms", (milliseconds * 100.0).round() / 100.0)
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::BTreeMap;
+
+  use super::*;
+  use crate::{StatsAsset, StatsAssetInfo, StatsChunkGroupAsset};
+
+  #[test]
+  fn format_sizes() {
+    assert_eq!(format_size(0.0), "0 bytes");
+    assert_eq!(format_size(30.0), "30 bytes");
+    assert_eq!(format_size(1536.0), "1.5 KiB");
+    assert_eq!(format_size(3.0 * 1024.0 * 1024.0), "3 MiB");
+  }
+
+  #[test]
+  fn print_stats() {
+    let stats = StatsCompilation {
+      hash: Some("a1b2c3".to_string()),
+      time: Some(42),
+      built_at: None,
+      public_path: None,
+      output_path: None,
+      assets: Some(vec![StatsAsset {
+        r#type: "asset",
+        name: "main.js".to_string(),
+        size: 2048.0,
+        chunks: vec!["main".to_string()],
+        chunk_names: vec!["main".to_string()],
+        info: StatsAssetInfo {
+          development: false,
+          hot_module_replacement: false,
+        },
+        emitted: true,
+      }]),
+      assets_by_chunk_name: None,
+      chunks: None,
+      modules: None,
+      entrypoints: Some(BTreeMap::from([(
+        "main".to_string(),
+        StatsChunkGroup {
+          name: "main".to_string(),
+          assets: vec![StatsChunkGroupAsset {
+            name: "main.js".to_string(),
+            size: 2048.0,
+          }],
+          chunks: vec!["main".to_string()],
+          assets_size: 2048.0,
+        },
+      )])),
+      named_chunk_groups: None,
+      errors: Some(vec![]),
+      errors_count: Some(0),
+      warnings: Some(vec![]),
+      warnings_count: Some(1),
+      plugin_hook_timings: None,
+    };
+    assert_eq!(
+      StatsPrinter::new(false).print(&stats),
+      "asset main.js 2 KiB [emitted] (name: main)\n\
+       entrypoint main 2 KiB = main.js\n\
+       Rspack compiled with 1 warning (a1b2c3) in 42 ms"
+    );
+  }
+
+  #[test]
+  fn paint_with_colors() {
+    // Whether `colored` writes the escape codes depends on the process wide override and the terminal,
+    // so only check that the style is applied.
+    let styled = std::cell::Cell::new(false);
+    let style = |s: ColoredString| {
+      styled.set(true);
+      s.green()
+    };
+    assert_eq!(StatsPrinter::new(false).paint(style, "main.js"), "main.js");
+    assert!(!styled.get());
+    StatsPrinter::new(true).paint(style, "main.js");
+    assert!(styled.get());
+  }
+}
@@ -3,7 +3,7 @@ use once_cell::sync::Lazy;
 use rspack_identifier::{IdentifierMap, IdentifierSet};
 use rspack_symbol::{IndirectTopLevelSymbol, StarSymbol, Symbol};
 use rustc_hash::FxHashSet as HashSet;
-use swc_core::ecma::ast::ModuleItem;
+use swc_core::ecma::{ast::ModuleItem, atoms::JsWord};
 
 use self::visitor::{SymbolRef, TreeShakingResult};
 use crate::ModuleGraph;
@@ -22,6 +22,44 @@ pub struct OptimizeDependencyResult {
   pub side_effects_free_modules: IdentifierSet,
   pub module_item_map: IdentifierMap<Vec<ModuleItem>>,
 }
+/// Exports of a module known by tree shaking.
+#[derive(Debug, Clone, Default)]
+pub struct ExportsInfo {
+  /// Names of the exports, including the ones re-exported by `export *`.
+  pub provided_exports: Vec<JsWord>,
+  /// Names of the exports used by other modules.
+  pub used_exports: Vec<JsWord>,
+}
+
+impl OptimizeDependencyResult {
+  pub fn exports_info(&self) -> IdentifierMap<ExportsInfo> {
+    self
+      .analyze_results
+      .iter()
+      .map(|(module_identifier, result)| {
+        let exports = result
+          .export_map
+          .iter()
+          .chain(result.inherit_export_maps.values().flatten())
+          .collect::<std::collections::BTreeMap<_, _>>();
+        let used_exports = exports
+          .iter()
+          .filter(|(_, symbol)| self.used_symbol_ref.contains(symbol))
+          .map(|(name, _)| (*name).clone())
+          .collect();
+        let provided_exports = exports.into_keys().cloned().collect();
+        (
+          *module_identifier,
+          ExportsInfo {
+            provided_exports,
+            used_exports,
+          },
+        )
+      })
+      .collect()
+  }
+}
+
 const ANALYZE_LOGGING: bool = true;
 static CARE_MODULE_ID_FROM_ENV: Lazy<Vec<String>> = Lazy::new(|| {
   let cwd = std::env::current_dir().expect("");
@@ -65,6 +103,26 @@ bitflags::bitflags! {
   }
 }
 
+impl BailoutFlag {
+  /// Why the exports of the module can't be shaken.
+  pub fn reasons(&self) -> Vec<&'static str> {
+    [
+      (Self::HELPER, "it's a helper module"),
+      (Self::COMMONJS_REQUIRE, "it's required by CommonJS require"),
+      (Self::COMMONJS_EXPORTS, "it uses CommonJS exports"),
+      (Self::DYNAMIC_IMPORT, "it's imported by dynamic import"),
+      (
+        Self::CONTEXT_MODULE,
+        "it's a dependency of a context module",
+      ),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| self.contains(*flag))
+    .map(|(_, reason)| reason)
+    .collect()
+  }
+}
+
 bitflags::bitflags! {
   pub struct ModuleUsedType: u8 {
     const DIRECT = 1 << 0;
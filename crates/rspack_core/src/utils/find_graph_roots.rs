@@ -72,8 +72,8 @@ pub fn find_graph_roots<Item: Clone + PartialEq + Eq + Hash + Send + Sync + Ord
   get_dependencies: impl Sync + Fn(Item) -> Vec<Item>,
 ) -> Vec<Item> {
   use rayon::prelude::*;
-  // early exit when there is only a single item
-  if items.len() == 1 {
+  // early exit when there is only a single item or none at all
+  if items.len() <= 1 {
     return items;
   }
 
@@ -8,6 +8,7 @@ pub fn module_rule_matcher<'a>(
   module_rule: &'a ModuleRule,
   resource_data: &ResourceData,
   issuer: Option<&str>,
+  issuer_layer: Option<&str>,
 ) -> Result<Option<&'a ModuleRule>> {
   if module_rule.test.is_none()
     && module_rule.resource.is_none()
@@ -15,6 +16,7 @@ pub fn module_rule_matcher<'a>(
     && module_rule.include.is_none()
     && module_rule.exclude.is_none()
     && module_rule.issuer.is_none()
+    && module_rule.issuer_layer.is_none()
     && module_rule.one_of.is_none()
   {
     return Err(internal_error!(
@@ -22,13 +24,14 @@ pub fn module_rule_matcher<'a>(
     ));
   }
 
-  module_rule_matcher_inner(module_rule, resource_data, issuer)
+  module_rule_matcher_inner(module_rule, resource_data, issuer, issuer_layer)
 }
 
 pub fn module_rule_matcher_inner<'a>(
   module_rule: &'a ModuleRule,
   resource_data: &ResourceData,
   issuer: Option<&str>,
+  issuer_layer: Option<&str>,
 ) -> Result<Option<&'a ModuleRule>> {
   // Include all modules that pass test assertion. If you supply a Rule.test option, you cannot also supply a `Rule.resource`.
   // See: https://webpack.js.org/configuration/module/#ruletest
@@ -66,9 +69,15 @@ pub fn module_rule_matcher_inner<'a>(
     return Ok(None);
   }
 
+  // Same as webpack, a module without an issuer layer is matched against an empty string.
+  if let Some(issuer_layer_rule) = &module_rule.issuer_layer
+    && !issuer_layer_rule.try_match(issuer_layer.unwrap_or_default())? {
+    return Ok(None);
+  }
+
   if let Some(one_of) = &module_rule.one_of {
     for rule in one_of {
-      if let Some(rule) = module_rule_matcher_inner(rule, resource_data, issuer)? {
+      if let Some(rule) = module_rule_matcher_inner(rule, resource_data, issuer, issuer_layer)? {
         return Ok(Some(rule));
       }
     }
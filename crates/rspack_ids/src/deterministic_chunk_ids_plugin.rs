@@ -0,0 +1,91 @@
+use rspack_core::{ChunkUkey, Plugin};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{
+  assign_deterministic_ids, compare_chunks_natural, get_full_chunk_name, get_used_chunk_ids,
+};
+
+#[derive(Debug)]
+pub struct DeterministicChunkIdsPlugin {
+  pub max_length: usize,
+  pub context: Option<String>,
+}
+
+impl DeterministicChunkIdsPlugin {
+  pub fn new(max_length: Option<usize>, context: Option<String>) -> Self {
+    Self {
+      max_length: max_length.unwrap_or(3),
+      context,
+    }
+  }
+}
+
+impl Default for DeterministicChunkIdsPlugin {
+  fn default() -> Self {
+    Self::new(None, None)
+  }
+}
+
+impl Plugin for DeterministicChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "DeterministicChunkIdsPlugin"
+  }
+
+  fn chunk_ids(&mut self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
+    let mut used_ids = get_used_chunk_ids(compilation);
+    let used_ids_len = used_ids.len();
+    let context = self
+      .context
+      .clone()
+      .unwrap_or_else(|| compilation.options.context.to_string_lossy().to_string());
+
+    let chunk_names = compilation
+      .chunk_by_ukey
+      .values()
+      .filter(|chunk| chunk.id.is_none())
+      .map(|chunk| {
+        (
+          chunk.ukey,
+          get_full_chunk_name(
+            chunk,
+            &compilation.chunk_graph,
+            &context,
+            &compilation.module_graph,
+          ),
+        )
+      })
+      .collect::<HashMap<ChunkUkey, String>>();
+
+    let chunk_graph = &compilation.chunk_graph;
+    let chunk_by_ukey = &compilation.chunk_by_ukey;
+    let get_chunk = |ukey: &ChunkUkey| chunk_by_ukey.get(ukey).expect("Chunk should exist");
+    let mut chunk_ids = HashMap::default();
+    assign_deterministic_ids(
+      chunk_names.keys().copied().collect::<Vec<_>>(),
+      |chunk| chunk_names[&chunk].clone(),
+      |a, b| compare_chunks_natural(chunk_graph, get_chunk(a), get_chunk(b)),
+      |chunk, id| {
+        if !used_ids.insert(id.clone()) {
+          return false;
+        }
+        chunk_ids.insert(chunk, id);
+        true
+      },
+      &[usize::pow(10, self.max_length as u32)],
+      10,
+      used_ids_len,
+      0,
+    );
+
+    chunk_ids.into_iter().for_each(|(chunk_ukey, id)| {
+      let chunk = compilation
+        .chunk_by_ukey
+        .get_mut(&chunk_ukey)
+        .expect("Chunk should exist");
+      chunk.id = Some(id.clone());
+      chunk.ids = vec![id];
+    });
+
+    Ok(())
+  }
+}
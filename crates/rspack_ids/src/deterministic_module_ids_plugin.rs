@@ -10,6 +10,10 @@ use crate::id_helpers::{
 pub struct DeterministicModuleIdsPlugin {}
 
 impl Plugin for DeterministicModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "DeterministicModuleIdsPlugin"
+  }
+
   fn module_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
     let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
 
@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use md4::Md4;
+use rspack_core::{Compilation, Plugin};
+use rspack_error::{internal_error, Error, Result};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+use crate::id_helpers::{
+  compare_modules_by_pre_order_index_or_identifier, get_full_module_name,
+  get_used_module_ids_and_modules,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HashFunction {
+  #[default]
+  Md4,
+  Xxhash64,
+  Sha256,
+  Sha384,
+  Sha512,
+}
+
+impl HashFunction {
+  fn hash(&self, data: &str) -> Vec<u8> {
+    match self {
+      HashFunction::Md4 => Md4::digest(data).to_vec(),
+      HashFunction::Xxhash64 => xxhash_rust::xxh64::xxh64(data.as_bytes(), 0)
+        .to_be_bytes()
+        .to_vec(),
+      HashFunction::Sha256 => Sha256::digest(data).to_vec(),
+      HashFunction::Sha384 => Sha384::digest(data).to_vec(),
+      HashFunction::Sha512 => Sha512::digest(data).to_vec(),
+    }
+  }
+}
+
+impl FromStr for HashFunction {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "md4" => Ok(HashFunction::Md4),
+      "xxhash64" => Ok(HashFunction::Xxhash64),
+      "sha256" => Ok(HashFunction::Sha256),
+      "sha384" => Ok(HashFunction::Sha384),
+      "sha512" => Ok(HashFunction::Sha512),
+      _ => Err(internal_error!(
+        "hash function of hashed module ids only support 'md4', 'xxhash64', 'sha256', 'sha384' or 'sha512'"
+      )),
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HashDigest {
+  Hex,
+  #[default]
+  Base64,
+}
+
+impl HashDigest {
+  fn encode(&self, hash: &[u8]) -> String {
+    match self {
+      HashDigest::Hex => hash.iter().map(|byte| format!("{byte:02x}")).collect(),
+      HashDigest::Base64 => rspack_base64::encode_to_string(hash),
+    }
+  }
+}
+
+impl FromStr for HashDigest {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    match s {
+      "hex" => Ok(HashDigest::Hex),
+      "base64" => Ok(HashDigest::Base64),
+      _ => Err(internal_error!(
+        "hash digest of hashed module ids only support 'hex' or 'base64'"
+      )),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct HashedModuleIdsPluginOptions {
+  /// The context directory for creating names, defaults to `options.context`.
+  pub context: Option<String>,
+  pub hash_function: HashFunction,
+  pub hash_digest: HashDigest,
+  /// The prefix length of the hash digest to use, it grows until the id is unique.
+  pub hash_digest_length: usize,
+}
+
+impl Default for HashedModuleIdsPluginOptions {
+  fn default() -> Self {
+    Self {
+      context: None,
+      hash_function: HashFunction::default(),
+      hash_digest: HashDigest::default(),
+      hash_digest_length: 4,
+    }
+  }
+}
+
+/// Uses a hash of the module name as the module id.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/HashedModuleIdsPlugin.js
+#[derive(Debug, Default)]
+pub struct HashedModuleIdsPlugin {
+  options: HashedModuleIdsPluginOptions,
+}
+
+impl HashedModuleIdsPlugin {
+  pub fn new(options: HashedModuleIdsPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+impl Plugin for HashedModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "HashedModuleIdsPlugin"
+  }
+
+  fn module_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let (mut used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &mut compilation.chunk_graph;
+    let context = self
+      .options
+      .context
+      .clone()
+      .unwrap_or_else(|| compilation.options.context.to_string_lossy().to_string());
+
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| {
+      compare_modules_by_pre_order_index_or_identifier(module_graph, a, b)
+    });
+
+    for module in modules {
+      let ident = get_full_module_name(module, &context);
+      let hash_id = self
+        .options
+        .hash_digest
+        .encode(&self.options.hash_function.hash(&ident));
+      let mut len = self.options.hash_digest_length.min(hash_id.len());
+      while len < hash_id.len() && used_ids.contains(&hash_id[..len]) {
+        len += 1;
+      }
+      let module_id = hash_id[..len].to_string();
+      chunk_graph.set_module_id(module.identifier(), module_id.clone());
+      used_ids.insert(module_id);
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn default_options_match_webpack_ids() {
+    let options = HashedModuleIdsPluginOptions::default();
+    let hash_id = options
+      .hash_digest
+      .encode(&options.hash_function.hash("./a.js"));
+    // The id webpack's HashedModuleIdsPlugin generates for `./a.js` with its defaults.
+    assert_eq!(&hash_id[..options.hash_digest_length], "KpHw");
+  }
+}
@@ -9,7 +9,8 @@ use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
-  BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, ModuleGraph, ModuleIdentifier,
+  get_runtime_key, BoxModule, Chunk, ChunkGraph, ChunkUkey, Compilation, ModuleGraph,
+  ModuleIdentifier,
 };
 use rspack_util::{
   comparators::{compare_ids, compare_numbers},
@@ -25,14 +26,11 @@ pub fn get_used_module_ids_and_modules(
 ) -> (HashSet<String>, Vec<ModuleIdentifier>) {
   let chunk_graph = &compilation.chunk_graph;
   let mut modules = vec![];
-  let mut used_ids = HashSet::new();
-
-  // TODO: currently we don't have logic of compilation.usedModuleIds
-  //   if (compilation.usedModuleIds) {
-  //     for (const id of compilation.usedModuleIds) {
-  //         usedIds.add(id + "");
-  //     }
-  //   }
+  let mut used_ids = compilation
+    .used_module_ids
+    .iter()
+    .cloned()
+    .collect::<HashSet<_>>();
 
   compilation
     .module_graph
@@ -322,6 +320,47 @@ pub fn compare_modules_by_pre_order_index_or_identifier(
   }
 }
 
+/// Same as `compareChunksNatural` of webpack, named chunks come first, then chunks are compared
+/// by their runtime and the ids of their modules.
+pub fn compare_chunks_natural(chunk_graph: &ChunkGraph, a: &Chunk, b: &Chunk) -> Ordering {
+  let cmp = match (&a.name, &b.name) {
+    (Some(a), Some(b)) => compare_ids(a, b),
+    (Some(_), None) => Ordering::Less,
+    (None, Some(_)) => Ordering::Greater,
+    (None, None) => Ordering::Equal,
+  };
+  if cmp != Ordering::Equal {
+    return cmp;
+  }
+  let cmp = get_runtime_key(a.runtime.clone()).cmp(&get_runtime_key(b.runtime.clone()));
+  if cmp != Ordering::Equal {
+    return cmp;
+  }
+  let get_module_ids = |chunk: &Chunk| {
+    let mut ids = chunk_graph
+      .get_chunk_module_identifiers(&chunk.ukey)
+      .iter()
+      .map(|module| {
+        chunk_graph
+          .get_module_id(*module)
+          .clone()
+          .unwrap_or_default()
+      })
+      .collect::<Vec<_>>();
+    ids.sort_unstable_by(|a, b| compare_ids(a, b));
+    ids
+  };
+  let a_ids = get_module_ids(a);
+  let b_ids = get_module_ids(b);
+  for (a, b) in a_ids.iter().zip(b_ids.iter()) {
+    let cmp = compare_ids(a, b);
+    if cmp != Ordering::Equal {
+      return cmp;
+    }
+  }
+  compare_numbers(a_ids.len(), b_ids.len())
+}
+
 pub fn get_short_chunk_name(
   chunk: &Chunk,
   chunk_graph: &ChunkGraph,
@@ -407,6 +446,38 @@ pub fn get_long_chunk_name(
   shorten_long_string(chunk_name, delimiter)
 }
 
+pub fn get_full_chunk_name(
+  chunk: &Chunk,
+  chunk_graph: &ChunkGraph,
+  context: &str,
+  module_graph: &ModuleGraph,
+) -> String {
+  if let Some(name) = &chunk.name {
+    return name.clone();
+  }
+  get_chunk_source(chunk, chunk_graph, context, module_graph)
+}
+
+/// The full names of the root modules of the chunk, used to identify unnamed chunks across builds.
+pub fn get_chunk_source(
+  chunk: &Chunk,
+  chunk_graph: &ChunkGraph,
+  context: &str,
+  module_graph: &ModuleGraph,
+) -> String {
+  chunk_graph
+    .get_chunk_root_modules(&chunk.ukey, module_graph)
+    .iter()
+    .map(|id| {
+      let module = module_graph
+        .module_by_identifier(id)
+        .expect("Module not found");
+      get_full_module_name(module, context)
+    })
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
 static REGEX1: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\.\.?/)+").expect("Invalid regex"));
 static REGEX2: Lazy<Regex> =
   Lazy::new(|| Regex::new(r"(^[.-]|[^a-zA-Z0-9_-])+").expect("Invalid regex"));
@@ -2,8 +2,22 @@ mod deterministic_module_ids_plugin;
 pub use deterministic_module_ids_plugin::*;
 mod named_module_ids_plugin;
 pub use named_module_ids_plugin::*;
+mod natural_module_ids_plugin;
+pub use natural_module_ids_plugin::*;
+mod occurrence_module_ids_plugin;
+pub use occurrence_module_ids_plugin::*;
+mod hashed_module_ids_plugin;
+pub use hashed_module_ids_plugin::*;
+mod deterministic_chunk_ids_plugin;
 pub(crate) mod id_helpers;
+pub use deterministic_chunk_ids_plugin::*;
 mod named_chunk_ids_plugin;
 pub use named_chunk_ids_plugin::*;
+mod natural_chunk_ids_plugin;
+pub use natural_chunk_ids_plugin::*;
+mod occurrence_chunk_ids_plugin;
+pub use occurrence_chunk_ids_plugin::*;
+mod record_ids_plugin;
+pub use record_ids_plugin::*;
 mod stable_named_chunk_ids_plugin;
 pub use stable_named_chunk_ids_plugin::StableNamedChunkIdsPlugin;
@@ -0,0 +1,27 @@
+use rspack_core::{Compilation, Plugin};
+use rspack_error::Result;
+
+use crate::id_helpers::{assign_ascending_chunk_ids, compare_chunks_natural};
+
+/// Assigns numeric chunk ids in the natural order of the chunks.
+#[derive(Debug, Default)]
+pub struct NaturalChunkIdsPlugin {}
+
+impl Plugin for NaturalChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "NaturalChunkIdsPlugin"
+  }
+
+  fn chunk_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let chunk_graph = &compilation.chunk_graph;
+    let mut chunks = compilation.chunk_by_ukey.values().collect::<Vec<_>>();
+    chunks.sort_unstable_by(|a, b| compare_chunks_natural(chunk_graph, a, b));
+
+    let chunks = chunks
+      .into_iter()
+      .map(|chunk| chunk.ukey)
+      .collect::<Vec<_>>();
+    assign_ascending_chunk_ids(&chunks, compilation);
+    Ok(())
+  }
+}
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use rspack_core::{Compilation, ModuleIdentifier, Plugin};
+use rspack_error::Result;
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::id_helpers::{
+  assign_ascending_module_ids, compare_modules_by_pre_order_index_or_identifier,
+  get_used_module_ids_and_modules,
+};
+
+/// Assigns numeric module ids by how often the modules are referenced, so the most referenced
+/// modules get the shortest ids. With `prioritise_initial`, modules in initial chunks come first.
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ids/OccurrenceModuleIdsPlugin.js
+#[derive(Debug, Default)]
+pub struct OccurrenceModuleIdsPlugin {
+  pub prioritise_initial: bool,
+}
+
+impl OccurrenceModuleIdsPlugin {
+  pub fn new(prioritise_initial: bool) -> Self {
+    Self { prioritise_initial }
+  }
+}
+
+impl Plugin for OccurrenceModuleIdsPlugin {
+  fn name(&self) -> &'static str {
+    "OccurrenceModuleIdsPlugin"
+  }
+
+  fn module_ids(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let (used_ids, modules) = get_used_module_ids_and_modules(compilation, None);
+
+    let module_graph = &compilation.module_graph;
+    let chunk_graph = &compilation.chunk_graph;
+    let chunk_group_by_ukey = &compilation.chunk_group_by_ukey;
+
+    let mut initial_chunk_count: HashMap<ModuleIdentifier, usize> = Default::default();
+    let mut entry_count: HashMap<ModuleIdentifier, usize> = Default::default();
+    for module in &modules {
+      let mut initial = 0;
+      let mut entry = 0;
+      for chunk in chunk_graph.get_modules_chunks(*module) {
+        if compilation
+          .chunk_by_ukey
+          .get(chunk)
+          .map_or(false, |chunk| chunk.can_be_initial(chunk_group_by_ukey))
+        {
+          initial += 1;
+        }
+        if chunk_graph.is_entry_module_in_chunk(module, *chunk) {
+          entry += 1;
+        }
+      }
+      initial_chunk_count.insert(*module, initial);
+      entry_count.insert(*module, entry);
+    }
+
+    // The origin module of every incoming connection of the module.
+    let get_origin_modules = |module: &ModuleIdentifier| -> Vec<ModuleIdentifier> {
+      module_graph
+        .module_graph_module_by_identifier(module)
+        .map(|mgm| {
+          mgm
+            .incoming_connections
+            .iter()
+            .filter_map(|id| module_graph.connection_by_connection_id(id))
+            .filter_map(|connection| connection.original_module_identifier)
+            .collect()
+        })
+        .unwrap_or_default()
+    };
+
+    let mut occurs_in_initial_chunks: HashMap<ModuleIdentifier, usize> = Default::default();
+    let mut occurs_in_all_chunks: HashMap<ModuleIdentifier, usize> = Default::default();
+    for module in &modules {
+      let origin_modules = get_origin_modules(module);
+      if self.prioritise_initial {
+        let mut origins = origin_modules.clone();
+        origins.sort_unstable();
+        origins.dedup();
+        let occurs_in_entry = origins
+          .iter()
+          .map(|origin| initial_chunk_count.get(origin).copied().unwrap_or_default())
+          .sum::<usize>();
+        occurs_in_initial_chunks.insert(
+          *module,
+          occurs_in_entry + initial_chunk_count[module] + entry_count[module],
+        );
+      }
+      let occurs = origin_modules
+        .iter()
+        .map(|origin| chunk_graph.get_number_of_module_chunks(*origin))
+        .sum::<usize>();
+      occurs_in_all_chunks.insert(
+        *module,
+        occurs + chunk_graph.get_number_of_module_chunks(*module) + entry_count[module],
+      );
+    }
+
+    let mut modules = modules
+      .into_iter()
+      .filter_map(|i| module_graph.module_by_identifier(&i))
+      .collect::<Vec<_>>();
+    modules.sort_unstable_by(|a, b| {
+      let a_identifier = a.identifier();
+      let b_identifier = b.identifier();
+      if self.prioritise_initial {
+        let cmp =
+          occurs_in_initial_chunks[&b_identifier].cmp(&occurs_in_initial_chunks[&a_identifier]);
+        if cmp != Ordering::Equal {
+          return cmp;
+        }
+      }
+      let cmp = occurs_in_all_chunks[&b_identifier].cmp(&occurs_in_all_chunks[&a_identifier]);
+      if cmp != Ordering::Equal {
+        return cmp;
+      }
+      compare_modules_by_pre_order_index_or_identifier(module_graph, a, b)
+    });
+
+    assign_ascending_module_ids(&used_ids, modules, &mut compilation.chunk_graph);
+    Ok(())
+  }
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use rspack_core::{ChunkRecords, Compilation, ModuleRecords, Plugin};
+use rspack_error::Result;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+use crate::id_helpers::{get_chunk_source, get_full_module_name};
+
+/// Keeps module and chunk ids stable across builds with [Compilation::records].
+///
+/// Align with https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/RecordIdsPlugin.js
+#[derive(Debug, Default)]
+pub struct RecordIdsPlugin {}
+
+impl Plugin for RecordIdsPlugin {
+  fn name(&self) -> &'static str {
+    "RecordIdsPlugin"
+  }
+
+  fn revive_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let Some(records) = &compilation.records else {
+      return Ok(());
+    };
+    let context = compilation.options.context.to_string_lossy();
+    let chunk_graph = &compilation.chunk_graph;
+    let mut used_ids = HashSet::default();
+    let mut module_ids = vec![];
+    for module in compilation.module_graph.modules().values() {
+      if chunk_graph.get_module_id(module.identifier()).is_some() {
+        continue;
+      }
+      let identifier = get_full_module_name(module, &context);
+      if let Some(id) = records.modules.by_identifier.get(&identifier) {
+        if used_ids.insert(id.clone()) {
+          module_ids.push((module.identifier(), id.clone()));
+        }
+      }
+    }
+    compilation
+      .used_module_ids
+      .extend(records.modules.used_ids.iter().cloned());
+
+    for (module_identifier, id) in module_ids {
+      compilation.chunk_graph.set_module_id(module_identifier, id);
+    }
+    Ok(())
+  }
+
+  fn revive_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let Some(records) = &compilation.records else {
+      return Ok(());
+    };
+    let context = compilation.options.context.to_string_lossy();
+    let mut used_ids = HashSet::default();
+    let mut chunk_ids = HashMap::default();
+    // Chunks with a name are revived first, the others are revived by their root modules.
+    for chunk in compilation.chunk_by_ukey.values() {
+      if chunk.id.is_some() {
+        continue;
+      }
+      let Some(id) = chunk.name.as_ref().and_then(|name| records.chunks.by_name.get(name)) else {
+        continue;
+      };
+      if used_ids.insert(id.clone()) {
+        chunk_ids.insert(chunk.ukey, id.clone());
+      }
+    }
+    for chunk in compilation.chunk_by_ukey.values() {
+      if chunk.id.is_some() || chunk_ids.contains_key(&chunk.ukey) {
+        continue;
+      }
+      let source = get_chunk_source(
+        chunk,
+        &compilation.chunk_graph,
+        &context,
+        &compilation.module_graph,
+      );
+      if let Some(id) = records.chunks.by_source.get(&source) {
+        if used_ids.insert(id.clone()) {
+          chunk_ids.insert(chunk.ukey, id.clone());
+        }
+      }
+    }
+    compilation
+      .used_chunk_ids
+      .extend(records.chunks.used_ids.iter().cloned());
+
+    for (chunk_ukey, id) in chunk_ids {
+      if let Some(chunk) = compilation.chunk_by_ukey.get_mut(&chunk_ukey) {
+        chunk.id = Some(id.clone());
+        chunk.ids = vec![id];
+      }
+    }
+    Ok(())
+  }
+
+  fn record_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let context = compilation.options.context.to_string_lossy();
+    let mut by_identifier = BTreeMap::new();
+    for module in compilation.module_graph.modules().values() {
+      if let Some(id) = compilation.chunk_graph.get_module_id(module.identifier()) {
+        by_identifier.insert(get_full_module_name(module, &context), id.clone());
+      }
+    }
+    let mut used_ids = by_identifier.values().cloned().collect::<Vec<_>>();
+    used_ids.sort_unstable();
+    used_ids.dedup();
+
+    if let Some(records) = &mut compilation.records {
+      records.modules = ModuleRecords {
+        by_identifier,
+        used_ids,
+      };
+    }
+    Ok(())
+  }
+
+  fn record_chunks(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let context = compilation.options.context.to_string_lossy();
+    let mut by_name = BTreeMap::new();
+    let mut by_source = BTreeMap::new();
+    for chunk in compilation.chunk_by_ukey.values() {
+      let Some(id) = &chunk.id else {
+        continue;
+      };
+      if let Some(name) = &chunk.name {
+        by_name.insert(name.clone(), id.clone());
+      }
+      let source = get_chunk_source(
+        chunk,
+        &compilation.chunk_graph,
+        &context,
+        &compilation.module_graph,
+      );
+      if !source.is_empty() {
+        by_source.insert(source, id.clone());
+      }
+    }
+    let mut used_ids = compilation
+      .chunk_by_ukey
+      .values()
+      .filter_map(|chunk| chunk.id.clone())
+      .collect::<Vec<_>>();
+    used_ids.sort_unstable();
+    used_ids.dedup();
+
+    if let Some(records) = &mut compilation.records {
+      records.chunks = ChunkRecords {
+        by_name,
+        by_source,
+        used_ids,
+      };
+    }
+    Ok(())
+  }
+}
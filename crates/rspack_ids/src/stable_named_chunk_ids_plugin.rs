@@ -2,7 +2,8 @@ use rspack_core::{ChunkUkey, Plugin};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::id_helpers::{
-  assign_ascending_chunk_ids, get_short_module_name, request_to_id, shorten_long_string,
+  assign_ascending_chunk_ids, get_short_module_name, get_used_chunk_ids, request_to_id,
+  shorten_long_string,
 };
 
 #[derive(Debug)]
@@ -21,15 +22,20 @@ impl StableNamedChunkIdsPlugin {
 }
 
 impl Plugin for StableNamedChunkIdsPlugin {
+  fn name(&self) -> &'static str {
+    "StableNamedChunkIdsPlugin"
+  }
+
   fn chunk_ids(&mut self, compilation: &mut rspack_core::Compilation) -> rspack_error::Result<()> {
     let context = self
       .context
       .clone()
       .unwrap_or_else(|| compilation.options.context.to_string_lossy().to_string());
 
-    // If the chunk has a name, use it as the id
+    // Ids revived from records are taken, the new ids must not collide with them.
+    let mut used_ids = get_used_chunk_ids(compilation);
 
-    let mut used_ids: FxHashSet<String> = FxHashSet::default();
+    // If the chunk has a name, use it as the id
 
     let dynamic_imported = compilation
       .chunk_graph
@@ -38,25 +44,29 @@ impl Plugin for StableNamedChunkIdsPlugin {
       .map(|(k, v)| (v, k))
       .collect::<FxHashMap<_, _>>();
 
-    compilation.chunk_by_ukey.values_mut().for_each(|chunk| {
-      if let Some(name) = &chunk.name {
-        chunk.id = Some(name.clone());
-        chunk.ids = vec![name.clone()];
-      } else if let Some(m) = dynamic_imported.get(&chunk.ukey) {
-        let module = compilation
-          .module_graph
-          .module_by_identifier(m)
-          .expect("Module should exist");
-        let name = request_to_id(&get_short_module_name(module, &context));
-        if used_ids.contains(&name) {
-          panic!("Duplicate chunk id: {}", chunk.chunk_reasons.join("_"))
+    compilation
+      .chunk_by_ukey
+      .values_mut()
+      // Chunks with an id were revived from records.
+      .filter(|chunk| chunk.id.is_none())
+      .for_each(|chunk| {
+        let name = if let Some(name) = &chunk.name {
+          name.clone()
+        } else if let Some(m) = dynamic_imported.get(&chunk.ukey) {
+          let module = compilation
+            .module_graph
+            .module_by_identifier(m)
+            .expect("Module should exist");
+          request_to_id(&get_short_module_name(module, &context))
         } else {
-          used_ids.insert(name.clone());
+          return;
+        };
+        // A taken name falls back to the ids of the unnamed chunks below.
+        if used_ids.insert(name.clone()) {
+          chunk.id = Some(name.clone());
+          chunk.ids = vec![name];
         }
-        chunk.id = Some(name.clone());
-        chunk.ids = vec![name];
-      }
-    });
+      });
 
     // Filter out chunks that don't have an id
     let chunks = compilation
@@ -105,30 +115,31 @@ impl Plugin for StableNamedChunkIdsPlugin {
     });
 
     name_to_chunks.into_iter().for_each(|(name, chunks)| {
-      if chunks.len() == 1 {
-        let chunk = chunks.into_iter().next().expect("Must have a chunk");
-        chunk.as_mut(&mut compilation.chunk_by_ukey).id = Some(name.clone());
-        chunk.as_mut(&mut compilation.chunk_by_ukey).ids.push(name);
-      } else {
-        // Multiple chunks have the same name, we need to assign a unique name to each chunk
-        let mut chunks = chunks.into_iter().collect::<Vec<_>>();
-        chunks.sort_unstable_by(|a, b| {
-          // TODO: We might need use more filed in sorting
-          let a_module_ids = compilation.chunk_graph.get_chunk_module_identifiers(a);
-          let b_module_ids = compilation.chunk_graph.get_chunk_module_identifiers(b);
-          a_module_ids.len().cmp(&b_module_ids.len())
-        });
-        chunks.iter().enumerate().for_each(|(index, chunk)| {
-          let chunk = chunk.as_mut(&mut compilation.chunk_by_ukey);
-          let name = if index == 0 {
+      // Multiple chunks may have the same name, we need to assign a unique name to each chunk
+      let mut chunks = chunks.into_iter().collect::<Vec<_>>();
+      chunks.sort_unstable_by(|a, b| {
+        // TODO: We might need use more filed in sorting
+        let a_module_ids = compilation.chunk_graph.get_chunk_module_identifiers(a);
+        let b_module_ids = compilation.chunk_graph.get_chunk_module_identifiers(b);
+        a_module_ids.len().cmp(&b_module_ids.len())
+      });
+      let mut index = 0;
+      chunks.iter().for_each(|chunk| {
+        let chunk = chunk.as_mut(&mut compilation.chunk_by_ukey);
+        let id = loop {
+          let id = if index == 0 {
             name.to_string()
           } else {
             format!("{name}{index}")
           };
-          chunk.id = Some(name.clone());
-          chunk.ids.push(name);
-        });
-      }
+          index += 1;
+          if used_ids.insert(id.clone()) {
+            break id;
+          }
+        };
+        chunk.id = Some(id.clone());
+        chunk.ids.push(id);
+      });
     });
 
     if !unnamed_chunks.is_empty() {
@@ -48,10 +48,15 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
             webassembly_module_filename: rspack_core::Filename::from_str("").expect("TODO:"),
             chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
             unique_name: Default::default(),
+            devtool_namespace: Default::default(),
+            devtool_module_filename_template: Default::default(),
+            devtool_fallback_module_filename_template: Default::default(),
             css_chunk_filename: rspack_core::Filename::from_str("").expect("TODO:"),
             css_filename: rspack_core::Filename::from_str("").expect("TODO:"),
             library: None,
             enabled_library_types: None,
+            chunk_loading: rspack_core::ChunkLoading::Enable("jsonp".to_string()),
+            enabled_chunk_loading_types: Some(vec!["jsonp".to_string()]),
             strict_module_error_handling: false,
             global_object: "self".to_string(),
             import_function_name: "import".to_string(),
@@ -70,7 +75,11 @@ async fn loader_test(actual: impl AsRef<Path>, expected: impl AsRef<Path>) {
           optimization: rspack_core::Optimization {
             remove_available_modules: false,
             side_effects: SideEffectOption::False,
+            provided_exports: false,
           },
+          profile: false,
+          records_input_path: None,
+          records_output_path: None,
         }),
         resolver_factory: Default::default(),
       },
@@ -0,0 +1,205 @@
+use rspack_core::{
+  Compilation, DependencyType, ModuleGraph, ModuleGraphConnection, ModuleIdentifier, Plugin,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_regex::RspackRegex;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+#[derive(Debug, Default)]
+pub struct CircularDependenciesPluginOptions {
+  /// Only report cycles containing a module whose resource matches.
+  pub include: Option<RspackRegex>,
+  /// Don't report cycles containing only modules whose resources match.
+  pub exclude: Option<RspackRegex>,
+  /// Report cycles as errors instead of warnings.
+  pub fail_on_error: bool,
+  /// Ignore dynamic imports when looking for cycles.
+  pub allow_async_cycles: bool,
+}
+
+/// Reports modules importing each other through ESM or CommonJS dependencies, as they are a
+/// common cause of accessing bindings before their initialization at runtime.
+#[derive(Debug, Default)]
+pub struct CircularDependenciesPlugin {
+  options: CircularDependenciesPluginOptions,
+}
+
+impl CircularDependenciesPlugin {
+  pub fn new(options: CircularDependenciesPluginOptions) -> Self {
+    Self { options }
+  }
+
+  fn is_followed(&self, module_graph: &ModuleGraph, connection: &ModuleGraphConnection) -> bool {
+    if connection.original_module_identifier == Some(connection.module_identifier) {
+      return false;
+    }
+    let Some(dependency) = module_graph.dependency_by_id(&connection.dependency_id) else {
+      return false;
+    };
+    match dependency.dependency_type() {
+      DependencyType::EsmImport | DependencyType::EsmExport | DependencyType::CjsRequire => true,
+      DependencyType::DynamicImport => !self.options.allow_async_cycles,
+      _ => false,
+    }
+  }
+
+  fn is_reported(&self, module_graph: &ModuleGraph, module: &ModuleIdentifier) -> bool {
+    let resource = module_graph
+      .normal_module_source_path_by_identifier(module)
+      .map(|resource| resource.to_string())
+      .unwrap_or_else(|| module.to_string());
+    self
+      .options
+      .include
+      .as_ref()
+      .map_or(true, |include| include.test(&resource))
+      && !self
+        .options
+        .exclude
+        .as_ref()
+        .map_or(false, |exclude| exclude.test(&resource))
+  }
+
+  /// Every elementary cycle through the modules of the strongly connected `component`, each
+  /// starting from its first module in `component`.
+  ///
+  /// Same as Johnson's "Finding all the elementary circuits of a directed graph".
+  fn get_elementary_cycles(
+    &self,
+    module_graph: &ModuleGraph,
+    component: &[ModuleIdentifier],
+  ) -> Vec<Vec<ModuleIdentifier>> {
+    let referenced_modules = component
+      .iter()
+      .map(|module| {
+        (
+          *module,
+          module_graph
+            .get_referenced_module_identifiers(module, |c| self.is_followed(module_graph, c)),
+        )
+      })
+      .collect::<HashMap<_, _>>();
+    let mut cycles = vec![];
+    for (index, start) in component.iter().enumerate() {
+      // Cycles through the modules before `start` have been found already.
+      let mut search = CycleSearch {
+        start: *start,
+        modules: component[index..].iter().copied().collect(),
+        referenced_modules: &referenced_modules,
+        stack: vec![],
+        blocked: HashSet::default(),
+        blocked_by: HashMap::default(),
+        cycles: &mut cycles,
+      };
+      search.circuit(*start);
+    }
+    cycles
+  }
+}
+
+struct CycleSearch<'a> {
+  start: ModuleIdentifier,
+  modules: HashSet<ModuleIdentifier>,
+  referenced_modules: &'a HashMap<ModuleIdentifier, Vec<ModuleIdentifier>>,
+  stack: Vec<ModuleIdentifier>,
+  blocked: HashSet<ModuleIdentifier>,
+  blocked_by: HashMap<ModuleIdentifier, HashSet<ModuleIdentifier>>,
+  cycles: &'a mut Vec<Vec<ModuleIdentifier>>,
+}
+
+impl<'a> CycleSearch<'a> {
+  fn referenced(&self, module: ModuleIdentifier) -> Vec<ModuleIdentifier> {
+    self.referenced_modules[&module]
+      .iter()
+      .filter(|referenced| self.modules.contains(referenced))
+      .copied()
+      .collect()
+  }
+
+  fn circuit(&mut self, module: ModuleIdentifier) -> bool {
+    let mut found = false;
+    self.stack.push(module);
+    self.blocked.insert(module);
+    let referenced_modules = self.referenced(module);
+    for referenced in &referenced_modules {
+      if *referenced == self.start {
+        self.cycles.push(self.stack.clone());
+        found = true;
+      } else if !self.blocked.contains(referenced) && self.circuit(*referenced) {
+        found = true;
+      }
+    }
+    if found {
+      self.unblock(module);
+    } else {
+      for referenced in referenced_modules {
+        self
+          .blocked_by
+          .entry(referenced)
+          .or_default()
+          .insert(module);
+      }
+    }
+    self.stack.pop();
+    found
+  }
+
+  fn unblock(&mut self, module: ModuleIdentifier) {
+    self.blocked.remove(&module);
+    for blocked in self.blocked_by.remove(&module).unwrap_or_default() {
+      if self.blocked.contains(&blocked) {
+        self.unblock(blocked);
+      }
+    }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for CircularDependenciesPlugin {
+  fn name(&self) -> &'static str {
+    "CircularDependenciesPlugin"
+  }
+
+  async fn finish_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let plugin = &*self;
+    let module_graph = &compilation.module_graph;
+    let context = &compilation.options.context;
+
+    let mut diagnostics = vec![];
+    for component in module_graph.get_cycles_by(|c| plugin.is_followed(module_graph, c)) {
+      for mut cycle in plugin.get_elementary_cycles(module_graph, &component) {
+        if cycle.len() < 2 {
+          continue;
+        }
+        // Start the chain from the first module that is reported.
+        let Some(start) = cycle
+          .iter()
+          .position(|module| plugin.is_reported(module_graph, module))
+        else {
+          continue;
+        };
+        cycle.rotate_left(start);
+        cycle.push(cycle[0]);
+        let chain = cycle
+          .iter()
+          .map(|module| {
+            module_graph
+              .module_by_identifier(module)
+              .map(|module| module.readable_identifier(context).to_string())
+              .unwrap_or_else(|| module.to_string())
+          })
+          .collect::<Vec<_>>()
+          .join(" -> ");
+        let title = "Circular Dependency".to_string();
+        let message = format!("Circular dependency detected:\n  {chain}");
+        diagnostics.push(if plugin.options.fail_on_error {
+          Diagnostic::error(title, message, 0, 0)
+        } else {
+          Diagnostic::warn(title, message, 0, 0)
+        });
+      }
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+}
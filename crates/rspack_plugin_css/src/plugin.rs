@@ -533,7 +533,7 @@ impl ParserAndGenerator for CssParserAndGenerator {
             inner_source_map: module
               .original_source()
               .expect("Failed to get original source, please file an issue.")
-              .map(&MapOptions::default()),
+              .map(&MapOptions::new(!devtool.cheap())),
             remove_original_source: false,
           })
           .boxed();
@@ -2,19 +2,24 @@
 
 use std::path::Path;
 
+mod module_filename_helpers;
+
 use dashmap::DashMap;
+pub use module_filename_helpers::ModuleOrSource;
+use module_filename_helpers::{create_filename, replace_duplicates, sources_to_modules};
 use once_cell::sync::Lazy;
 use pathdiff::diff_paths;
 use rayon::prelude::*;
 use regex::Regex;
 use rspack_core::{
   rspack_sources::{BoxSource, ConcatSource, MapOptions, RawSource, Source, SourceExt, SourceMap},
-  AssetInfo, Compilation, CompilationAsset, Plugin, PluginContext, PluginProcessAssetsOutput,
-  PluginRenderModuleContentOutput, ProcessAssetsArgs, RenderModuleContentArgs,
+  AssetInfo, BoxModule, Compilation, CompilationAsset, Plugin, PluginContext,
+  PluginProcessAssetsOutput, PluginRenderModuleContentOutput, PluginThisCompilationHookOutput,
+  ProcessAssetsArgs, RenderModuleContentArgs, ThisCompilationArgs,
 };
 use rspack_error::{internal_error, Result};
 use rspack_util::swc::normalize_custom_filename;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use serde_json::json;
 
 static IS_CSS_FILE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\.css($|\?)").expect("TODO:"));
@@ -24,6 +29,10 @@ pub struct DevtoolPluginOptions {
   pub inline: bool,
   pub append: bool,
   pub namespace: String,
+  /// Template of the names of modules in source maps and `sourceURL` comments.
+  pub module_filename_template: String,
+  /// Template used instead of `module_filename_template` when the names are duplicated.
+  pub fallback_module_filename_template: String,
   pub columns: bool,
   pub no_sources: bool,
   pub public_path: Option<String>,
@@ -34,10 +43,13 @@ pub struct DevtoolPlugin {
   inline: bool,
   source_mapping_url_comment: Option<String>,
   module_filename_template: String,
+  fallback_module_filename_template: String,
   namespace: String,
   columns: bool,
   no_sources: bool,
   public_path: Option<String>,
+  /// Eval wrapped modules of the current compilation, keyed by the module source and its `sourceURL`.
+  module_render_cache: DashMap<(BoxSource, String), BoxSource>,
 }
 
 impl DevtoolPlugin {
@@ -47,40 +59,97 @@ impl DevtoolPlugin {
       source_mapping_url_comment: options
         .append
         .then(|| "# sourceMappingURL=[url]".to_string()),
-      module_filename_template: "[resourcePath]".to_string(),
+      module_filename_template: options.module_filename_template,
+      fallback_module_filename_template: options.fallback_module_filename_template,
       namespace: options.namespace,
       columns: options.columns,
       no_sources: options.no_sources,
       public_path: options.public_path,
+      module_render_cache: Default::default(),
     }
   }
 }
 
-static MODULE_RENDER_CACHE: Lazy<DashMap<BoxSource, BoxSource>> = Lazy::new(DashMap::default);
-
 #[async_trait::async_trait]
 impl Plugin for DevtoolPlugin {
   fn name(&self) -> &'static str {
     "devtool"
   }
 
+  async fn this_compilation(
+    &mut self,
+    _args: ThisCompilationArgs<'_>,
+  ) -> PluginThisCompilationHookOutput {
+    // Sources of the previous compilation are not rendered again, drop them.
+    self.module_render_cache.clear();
+    Ok(())
+  }
+
   fn render_module_content(
     &self,
     _ctx: PluginContext,
     args: &RenderModuleContentArgs,
   ) -> PluginRenderModuleContentOutput {
-    let devtool = &args.compilation.options.devtool;
+    let compilation = args.compilation;
+    let devtool = &compilation.options.devtool;
     let origin_source = args.module_source.clone();
-    if devtool.eval() && devtool.source_map() {
-      if let Some(cached) = MODULE_RENDER_CACHE.get(&origin_source) {
-        return Ok(Some(cached.value().clone()));
-      } else if let Some(map) = origin_source.map(&MapOptions::new(devtool.cheap())) {
-        let source = wrap_eval_source_map(&origin_source.source(), map, args.compilation)?;
-        MODULE_RENDER_CACHE.insert(origin_source, source.clone());
-        return Ok(Some(source));
-      }
+    if !devtool.eval() {
+      return Ok(Some(origin_source));
     }
-    Ok(Some(origin_source))
+
+    let module = compilation
+      .module_graph
+      .module_by_identifier(&args.module_identifier);
+    let source_url = if devtool.source_map() {
+      // Same as webpack, modules with an inline source map are named by their id,
+      // the original sources are shown by the source map.
+      let module_id = compilation
+        .chunk_graph
+        .get_module_id(args.module_identifier)
+        .as_deref()
+        .unwrap_or_else(|| args.module_identifier.as_str());
+      format!("webpack-internal:///{module_id}")
+    } else {
+      let module_or_source = match module {
+        Some(module) => ModuleOrSource::Module(module),
+        None => ModuleOrSource::Source(args.module_identifier.as_str()),
+      };
+      create_filename(
+        &self.module_filename_template,
+        module_or_source,
+        &self.namespace,
+        compilation,
+      )
+    };
+
+    let cache_key = (origin_source, source_url);
+    if let Some(cached) = self.module_render_cache.get(&cache_key) {
+      return Ok(Some(cached.value().clone()));
+    }
+    let (origin_source, source_url) = &cache_key;
+    let map = devtool
+      .source_map()
+      .then(|| {
+        if self.columns {
+          origin_source.map(&MapOptions::default())
+        } else {
+          // `SourceMapSource` returns its own map as is, streaming the chunks drops the columns.
+          ConcatSource::new([origin_source.clone()]).map(&MapOptions::new(false))
+        }
+      })
+      .flatten();
+    let source = match map {
+      Some(map) => self.wrap_eval_source_map(
+        &origin_source.source(),
+        map,
+        module,
+        compilation,
+        source_url,
+      )?,
+      None => wrap_eval(&origin_source.source(), source_url),
+    };
+    self.module_render_cache.insert(cache_key, source.clone());
+    Ok(Some(source))
   }
 
   async fn process_assets_stage_dev_tooling(
@@ -91,7 +160,7 @@ impl Plugin for DevtoolPlugin {
     if !args.compilation.options.devtool.source_map() || args.compilation.options.devtool.eval() {
       return Ok(());
     }
-    let maps: HashMap<String, Vec<u8>> = args
+    let maps: Vec<(String, SourceMap)> = args
       .compilation
       .assets
       .par_iter()
@@ -99,30 +168,35 @@ impl Plugin for DevtoolPlugin {
       .filter_map(|(filename, source)| {
         source.map(&MapOptions::new(self.columns)).map(|mut map| {
           map.set_file(Some(filename.clone()));
-          for source in map.sources_mut() {
-            let uri = normalize_custom_filename(source);
-            let resource_path =
-              if let Some(relative_path) = diff_paths(uri, &*args.compilation.options.context) {
-                relative_path.to_string_lossy().to_string()
-              } else {
-                uri.to_owned()
-              };
-            *source = self
-              .module_filename_template
-              .replace("[namespace]", &self.namespace)
-              .replace("[resourcePath]", &resource_path);
+          (filename.to_owned(), map)
+        })
+      })
+      .collect();
+    let module_filenames = self.module_filenames(
+      maps
+        .iter()
+        .flat_map(|(_, map)| map.sources())
+        .map(|source| source.as_str()),
+      args.compilation,
+    );
+    let maps: HashMap<String, Vec<u8>> = maps
+      .into_par_iter()
+      .map(|(filename, mut map)| {
+        for source in map.sources_mut() {
+          if let Some(module_filename) = module_filenames.get(source) {
+            *source = module_filename.clone();
           }
-          if self.no_sources {
-            for content in map.sources_content_mut() {
-              *content = String::default();
-            }
+        }
+        if self.no_sources {
+          for content in map.sources_content_mut() {
+            *content = String::default();
           }
-          let mut map_buffer = Vec::new();
-          map
-            .to_writer(&mut map_buffer)
-            .map_err(|e| internal_error!(e.to_string()))?;
-          Ok((filename.to_owned(), map_buffer))
-        })
+        }
+        let mut map_buffer = Vec::new();
+        map
+          .to_writer(&mut map_buffer)
+          .map_err(|e| internal_error!(e.to_string()))?;
+        Ok((filename, map_buffer))
       })
       .collect::<Result<_>>()?;
     for (filename, map_buffer) in maps {
@@ -185,31 +259,108 @@ impl Plugin for DevtoolPlugin {
   }
 }
 
-pub fn wrap_eval_source_map(
-  source: &str,
-  mut map: SourceMap,
-  compilation: &Compilation,
-) -> Result<BoxSource> {
-  for source in map.sources_mut() {
-    let uri = normalize_custom_filename(source);
-    *source = if let Some(relative_path) = diff_paths(uri, &*compilation.options.context) {
-      relative_path.to_string_lossy().to_string()
-    } else {
-      uri.to_owned()
-    };
+impl DevtoolPlugin {
+  /// Names of the sources in source maps, the duplicated names are renamed by the fallback template.
+  fn module_filenames<'a>(
+    &self,
+    sources: impl Iterator<Item = &'a str>,
+    compilation: &Compilation,
+  ) -> HashMap<String, String> {
+    let mut sources = sources
+      .collect::<HashSet<_>>()
+      .into_iter()
+      .collect::<Vec<_>>();
+    // Keep the renaming of duplicated names stable.
+    sources.sort_unstable();
+    let sources_to_modules = sources_to_modules(compilation);
+    let module_or_sources = sources
+      .iter()
+      .map(|&source| {
+        let source = normalize_custom_filename(source);
+        match sources_to_modules
+          .get(source)
+          .and_then(|id| compilation.module_graph.module_by_identifier(id))
+        {
+          Some(module) => ModuleOrSource::Module(module),
+          None => ModuleOrSource::Source(source),
+        }
+      })
+      .collect::<Vec<_>>();
+    let mut filenames = module_or_sources
+      .iter()
+      .map(|&module_or_source| {
+        create_filename(
+          &self.module_filename_template,
+          module_or_source,
+          &self.namespace,
+          compilation,
+        )
+      })
+      .collect::<Vec<_>>();
+    replace_duplicates(&mut filenames, |index| {
+      create_filename(
+        &self.fallback_module_filename_template,
+        module_or_sources[index],
+        &self.namespace,
+        compilation,
+      )
+    });
+    sources
+      .into_iter()
+      .map(ToOwned::to_owned)
+      .zip(filenames)
+      .collect()
   }
-  if compilation.options.devtool.no_sources() {
-    for content in map.sources_content_mut() {
-      *content = String::default();
+
+  /// Wrap the module in `eval()` with an inline source map, used by the `eval-*source-map` devtools.
+  fn wrap_eval_source_map(
+    &self,
+    source: &str,
+    mut map: SourceMap,
+    module: Option<&BoxModule>,
+    compilation: &Compilation,
+    source_url: &str,
+  ) -> Result<BoxSource> {
+    let normal_module = module.and_then(|module| module.as_normal_module());
+    for source in map.sources_mut() {
+      let uri = normalize_custom_filename(source);
+      let module_or_source = match (module, normal_module) {
+        (Some(module), Some(normal_module))
+          if normal_module.resource_resolved_data().resource_path == Path::new(uri)
+            || normal_module.request() == uri
+            || normal_module.user_request() == uri =>
+        {
+          ModuleOrSource::Module(module)
+        }
+        _ => ModuleOrSource::Source(uri),
+      };
+      *source = create_filename(
+        &self.module_filename_template,
+        module_or_source,
+        &self.namespace,
+        compilation,
+      );
     }
+    if self.no_sources {
+      for content in map.sources_content_mut() {
+        *content = String::default();
+      }
+    }
+    let mut map_buffer = Vec::new();
+    map
+      .to_writer(&mut map_buffer)
+      .map_err(|e| internal_error!(e.to_string()))?;
+    let base64 = rspack_base64::encode_to_string(&map_buffer);
+    let footer = format!(
+      "\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}\n//# sourceURL={source_url}"
+    );
+    let result = RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed();
+    Ok(result)
   }
-  let mut map_buffer = Vec::new();
-  map
-    .to_writer(&mut map_buffer)
-    .map_err(|e| internal_error!(e.to_string()))?;
-  let base64 = rspack_base64::encode_to_string(&map_buffer);
-  let footer =
-    format!("\n//# sourceMappingURL=data:application/json;charset=utf-8;base64,{base64}");
-  let result = RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed();
-  Ok(result)
+}
+
+/// Wrap the module in `eval()`, used by the `eval` devtool.
+pub fn wrap_eval(source: &str, source_url: &str) -> BoxSource {
+  let footer = format!("\n//# sourceURL={source_url}");
+  RawSource::from(format!("eval({});", json!(format!("{source}{footer}")))).boxed()
 }
@@ -0,0 +1,178 @@
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use rspack_core::{calc_hash, BoxModule, Compilation, ModuleIdentifier};
+use rspack_identifier::Identifiable;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+static PLACEHOLDER: Lazy<Regex> =
+  Lazy::new(|| Regex::new(r"\[([\w-]+)\]").expect("should init regex"));
+
+/// What a source in a source map refers to.
+#[derive(Debug, Clone, Copy)]
+pub enum ModuleOrSource<'a> {
+  Module(&'a BoxModule),
+  /// A source which doesn't belong to any module, e.g. a source from the source map of a loader.
+  Source(&'a str),
+}
+
+/// Render `moduleFilenameTemplate` for a module or a source, the placeholders are case insensitive.
+///
+/// See https://github.com/webpack/webpack/blob/4b4ca3bb53f36a5b8fc6bc1bd976ed7af161bd80/lib/ModuleFilenameHelpers.js#L121
+pub fn create_filename(
+  template: &str,
+  module_or_source: ModuleOrSource,
+  namespace: &str,
+  compilation: &Compilation,
+) -> String {
+  let context = &compilation.options.context;
+  let (short_identifier, identifier, module_id, absolute_resource_path) = match module_or_source {
+    ModuleOrSource::Module(module) => {
+      let module_id = compilation
+        .chunk_graph
+        .get_module_id(module.identifier())
+        .clone()
+        .unwrap_or_default();
+      match module.as_normal_module() {
+        Some(normal_module) => (
+          module.readable_identifier(context).into_owned(),
+          normal_module.request().to_string(),
+          module_id,
+          normal_module
+            .resource_resolved_data()
+            .resource_path
+            .to_string_lossy()
+            .to_string(),
+        ),
+        None => {
+          let identifier = module.identifier().to_string();
+          let absolute_resource_path = after_last_loader(&identifier).to_string();
+          (
+            module.readable_identifier(context).into_owned(),
+            identifier,
+            module_id,
+            absolute_resource_path,
+          )
+        }
+      }
+    }
+    ModuleOrSource::Source(source) => (
+      context.shorten(source),
+      source.to_string(),
+      String::new(),
+      after_last_loader(source).to_string(),
+    ),
+  };
+
+  let resource = after_last_loader(&short_identifier);
+  let loaders = before_last_loader(&short_identifier);
+  let all_loaders = before_last_loader(&identifier);
+  let query = resource
+    .find('?')
+    .map(|i| &resource[i..])
+    .unwrap_or_default();
+  let resource_path = &resource[..resource.len() - query.len()];
+  let hash = format!("{:016x}", calc_hash(&identifier));
+
+  PLACEHOLDER
+    .replace_all(template, |caps: &Captures| {
+      match caps[1].to_ascii_lowercase().as_str() {
+        "identifier" => &identifier,
+        "short-identifier" => &short_identifier,
+        "resource" => resource,
+        "resource-path" | "resourcepath" => resource_path,
+        "absolute-resource-path" | "absoluteresourcepath" => &absolute_resource_path,
+        "all-loaders" | "allloaders" => all_loaders,
+        "loaders" => loaders,
+        "query" => query,
+        "id" => &module_id,
+        "hash" => &hash[..4],
+        "namespace" => namespace,
+        _ => &caps[0],
+      }
+      .to_string()
+    })
+    .into_owned()
+}
+
+fn after_last_loader(request: &str) -> &str {
+  request.rsplit('!').next().unwrap_or(request)
+}
+
+fn before_last_loader(request: &str) -> &str {
+  request
+    .rfind('!')
+    .map(|i| &request[..i])
+    .unwrap_or_default()
+}
+
+/// Map the names used by sources in source maps to the modules, these are the resource path
+/// or the requests of normal modules.
+pub fn sources_to_modules(compilation: &Compilation) -> HashMap<String, ModuleIdentifier> {
+  let mut modules = compilation
+    .module_graph
+    .modules()
+    .values()
+    .filter_map(|module| module.as_normal_module())
+    .collect::<Vec<_>>();
+  // The first module wins when several modules share the same resource, keep it stable.
+  modules.sort_unstable_by_key(|module| module.identifier());
+
+  let mut map = HashMap::default();
+  for module in modules {
+    let resource_path = module
+      .resource_resolved_data()
+      .resource_path
+      .to_string_lossy()
+      .to_string();
+    for name in [
+      resource_path,
+      module.request().to_string(),
+      module.user_request().to_string(),
+    ] {
+      map.entry(name).or_insert_with(|| module.identifier());
+    }
+  }
+  map
+}
+
+/// Rename the duplicated names by `fallback`, `*` are appended if the fallback names are still duplicated.
+pub fn replace_duplicates(names: &mut [String], mut fallback: impl FnMut(usize) -> String) {
+  let mut used_names = names.iter().cloned().collect::<HashSet<_>>();
+  let mut seen = HashSet::default();
+  for (index, name) in names.iter_mut().enumerate() {
+    if seen.insert(name.clone()) {
+      continue;
+    }
+    let mut fallback_name = fallback(index);
+    while used_names.contains(&fallback_name) {
+      fallback_name.push('*');
+    }
+    used_names.insert(fallback_name.clone());
+    *name = fallback_name;
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn replace_duplicated_names() {
+    let mut names = vec![
+      "webpack://app/./a.js".to_string(),
+      "webpack://app/./a.js".to_string(),
+      "webpack://app/./a.js".to_string(),
+      "webpack://app/./b.js".to_string(),
+    ];
+    replace_duplicates(&mut names, |_| "webpack://app/./a.js?abcd".to_string());
+    assert_eq!(
+      names,
+      vec![
+        "webpack://app/./a.js",
+        "webpack://app/./a.js?abcd",
+        "webpack://app/./a.js?abcd*",
+        "webpack://app/./b.js",
+      ]
+    );
+  }
+}
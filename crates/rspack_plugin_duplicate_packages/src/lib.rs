@@ -0,0 +1,129 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use rspack_core::{contextify, Compilation, ModuleIdentifier, Plugin};
+use rspack_error::{Diagnostic, Result};
+use rspack_regex::RspackRegex;
+use rustc_hash::FxHashSet as HashSet;
+
+#[derive(Debug, Default)]
+pub struct DuplicatePackagesPluginOptions {
+  /// Don't report packages whose names match, e.g. packages that are meant to be bundled
+  /// several times.
+  pub exclude: Option<RspackRegex>,
+}
+
+/// Reports packages bundled from more than one directory, e.g. when the resolver picks the same
+/// package from two different `node_modules` locations.
+#[derive(Debug, Default)]
+pub struct DuplicatePackagesPlugin {
+  options: DuplicatePackagesPluginOptions,
+}
+
+#[derive(Debug, Default)]
+struct PackageCopy {
+  version: Option<String>,
+  modules: Vec<ModuleIdentifier>,
+}
+
+impl DuplicatePackagesPlugin {
+  pub fn new(options: DuplicatePackagesPluginOptions) -> Self {
+    Self { options }
+  }
+}
+
+#[async_trait::async_trait]
+impl Plugin for DuplicatePackagesPlugin {
+  fn name(&self) -> &'static str {
+    "DuplicatePackagesPlugin"
+  }
+
+  async fn finish_modules(&mut self, compilation: &mut Compilation) -> Result<()> {
+    let module_graph = &compilation.module_graph;
+    let context = &compilation.options.context;
+
+    // package name -> package directory -> modules bundled from that directory
+    let mut packages: BTreeMap<&str, BTreeMap<PathBuf, PackageCopy>> = BTreeMap::new();
+    for module in module_graph.modules().values() {
+      let Some(normal_module) = module.as_normal_module() else {
+        continue;
+      };
+      let Some(description) = &normal_module.resource_resolved_data().resource_description else {
+        continue;
+      };
+      let Some(name) = description.data().name() else {
+        continue;
+      };
+      let copy = packages
+        .entry(name)
+        .or_default()
+        .entry(description.dir().as_ref().to_path_buf())
+        .or_default();
+      copy.version = description
+        .data()
+        .raw()
+        .get("version")
+        .and_then(|version| version.as_str())
+        .map(ToString::to_string);
+      copy.modules.push(module.identifier());
+    }
+
+    let mut diagnostics = vec![];
+    for (name, copies) in packages {
+      if copies.len() < 2
+        || self
+          .options
+          .exclude
+          .as_ref()
+          .map_or(false, |exclude| exclude.test(name))
+      {
+        continue;
+      }
+      let mut message = format!("Multiple copies of package \"{name}\" are bundled:");
+      for (dir, copy) in copies {
+        let modules = copy.modules.iter().collect::<HashSet<_>>();
+        let size: f64 = copy
+          .modules
+          .iter()
+          .filter_map(|module| module_graph.module_by_identifier(module))
+          .map(|module| {
+            module
+              .source_types()
+              .iter()
+              .map(|source_type| module.size(source_type))
+              .sum::<f64>()
+          })
+          .sum();
+        let mut issuers = copy
+          .modules
+          .iter()
+          .filter_map(|module| module_graph.module_graph_module_by_identifier(module))
+          .flat_map(|mgm| mgm.incoming_connections.iter())
+          .filter_map(|id| module_graph.connection_by_connection_id(id))
+          .filter_map(|connection| connection.original_module_identifier)
+          .filter(|issuer| !modules.contains(issuer))
+          .filter_map(|issuer| module_graph.module_by_identifier(&issuer))
+          .map(|issuer| issuer.readable_identifier(context).to_string())
+          .collect::<Vec<_>>();
+        issuers.sort();
+        issuers.dedup();
+
+        message.push_str(&format!(
+          "\n  {name}@{} {} ({size:.0} bytes)",
+          copy.version.as_deref().unwrap_or("unknown"),
+          contextify(context, &dir.to_string_lossy()),
+        ));
+        if !issuers.is_empty() {
+          message.push_str(&format!("\n    imported by {}", issuers.join(", ")));
+        }
+      }
+      diagnostics.push(Diagnostic::warn(
+        "Duplicate Package".to_string(),
+        message,
+        0,
+        0,
+      ));
+    }
+    compilation.push_batch_diagnostic(diagnostics);
+    Ok(())
+  }
+}
@@ -1,9 +1,10 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use rspack_core::{
-  ApplyContext, ExternalItem, ExternalItemValue, ExternalModule, ExternalType, FactorizeArgs,
-  ModuleDependency, ModuleExt, ModuleFactoryResult, NormalModuleFactoryContext, Plugin,
-  PluginContext, PluginFactorizeHookOutput,
+  ApplyContext, ContextInfo, ExternalItem, ExternalItemFnCtx, ExternalItemValue, ExternalModule,
+  ExternalRequest, ExternalRequestValue, ExternalType, FactorizeArgs, ModuleDependency, ModuleExt,
+  ModuleFactoryResult, NormalModuleFactoryContext, Plugin, PluginContext,
+  PluginFactorizeHookOutput,
 };
 use rspack_error::Result;
 
@@ -26,30 +27,44 @@ impl ExternalPlugin {
     config: &ExternalItemValue,
     r#type: Option<String>,
     dependency: &dyn ModuleDependency,
-  ) -> Option<ExternalModule> {
-    let mut external_module_config = match config {
-      ExternalItemValue::String(config) => config,
+  ) -> Result<Option<ExternalModule>> {
+    let mut external_module_config: Vec<String> = match config {
+      ExternalItemValue::String(config) => vec![config.clone()],
       ExternalItemValue::Bool(config) => {
         if *config {
-          dependency.request()
+          vec![dependency.request().to_string()]
         } else {
-          return None;
+          return Ok(None);
         }
       }
+      ExternalItemValue::Array(config) => config.clone(),
+      ExternalItemValue::Object(config) => {
+        let request = config
+          .iter()
+          .map(|(k, v)| Ok((k.clone(), ExternalRequestValue::new(v.clone())?)))
+          .collect::<Result<_>>()?;
+        return Ok(Some(ExternalModule::new(
+          ExternalRequest::Map(request),
+          r#type.unwrap_or_else(|| self.r#type.clone()),
+          dependency.request().to_owned(),
+        )));
+      }
     };
     let external_module_type = r#type.unwrap_or_else(|| {
-      if UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(external_module_config)
-        && let Some((t, c)) = external_module_config.split_once(' ') {
-        external_module_config = c;
-        return t.to_owned();
+      if let Some(primary) = external_module_config.first_mut()
+        && UNSPECIFIED_EXTERNAL_TYPE_REGEXP.is_match(primary)
+        && let Some((t, c)) = primary.split_once(' ') {
+        let t = t.to_owned();
+        *primary = c.to_owned();
+        return t;
       }
       self.r#type.clone()
     });
-    Some(ExternalModule::new(
-      external_module_config.to_owned(),
+    Ok(Some(ExternalModule::new(
+      ExternalRequest::Single(ExternalRequestValue::new(external_module_config)?),
       external_module_type,
       dependency.request().to_owned(),
-    ))
+    )))
   }
 }
 
@@ -67,7 +82,7 @@ impl Plugin for ExternalPlugin {
     &self,
     _ctx: PluginContext,
     args: FactorizeArgs<'_>,
-    _job_ctx: &mut NormalModuleFactoryContext,
+    job_ctx: &mut NormalModuleFactoryContext,
   ) -> PluginFactorizeHookOutput {
     for external_item in &self.externals {
       match external_item {
@@ -76,7 +91,7 @@ impl Plugin for ExternalPlugin {
 
           if let Some(value) = eh.get(request) {
             let maybe_module = self.handle_external(value, None, args.dependency);
-            return Ok(maybe_module.map(|i| ModuleFactoryResult::new(i.boxed())));
+            return Ok(maybe_module?.map(|i| ModuleFactoryResult::new(i.boxed())));
           }
         }
         ExternalItem::RegExp(r) => {
@@ -87,7 +102,7 @@ impl Plugin for ExternalPlugin {
               None,
               args.dependency,
             );
-            return Ok(maybe_module.map(|i| ModuleFactoryResult::new(i.boxed())));
+            return Ok(maybe_module?.map(|i| ModuleFactoryResult::new(i.boxed())));
           }
         }
         ExternalItem::String(s) => {
@@ -98,7 +113,29 @@ impl Plugin for ExternalPlugin {
               None,
               args.dependency,
             );
-            return Ok(maybe_module.map(|i| ModuleFactoryResult::new(i.boxed())));
+            return Ok(maybe_module?.map(|i| ModuleFactoryResult::new(i.boxed())));
+          }
+        }
+        ExternalItem::Fn(f) => {
+          let context = job_ctx
+            .original_resource_path
+            .as_ref()
+            .and_then(|path| path.parent())
+            .unwrap_or(job_ctx.options.context.as_path())
+            .to_string_lossy()
+            .to_string();
+          let value = f(ExternalItemFnCtx {
+            request: args.dependency.request().to_string(),
+            context,
+            dependency_type: args.dependency.category().as_str().to_string(),
+            context_info: ContextInfo {
+              issuer: job_ctx.issuer.clone().unwrap_or_default(),
+            },
+          })
+          .await?;
+          if let Some(value) = value {
+            let maybe_module = self.handle_external(&value, None, args.dependency);
+            return Ok(maybe_module?.map(|i| ModuleFactoryResult::new(i.boxed())));
           }
         }
       }
@@ -369,7 +369,9 @@ impl ParserAndGenerator for JavaScriptParserAndGenerator {
               module
                 .original_source()
                 .expect("Failed to get original source, please file an issue.")
-                .map(&MapOptions::default())
+                .map(&MapOptions::new(
+                  !generate_context.compilation.options.devtool.cheap(),
+                ))
             },
             remove_original_source: false,
           })
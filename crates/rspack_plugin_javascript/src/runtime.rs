@@ -46,6 +46,7 @@ pub fn render_chunk_modules(
       let module_source = if let Some(source) = plugin_driver
         .render_module_content(RenderModuleContentArgs {
           compilation,
+          module_identifier: mgm.module_identifier,
           module_source: &origin_source,
         })
         .expect("render_module_content failed")
@@ -38,8 +38,8 @@ impl VisitMut for AwaitDependenciesVisitor {
     let last_import = items
       .iter()
       .enumerate()
-      .skip_while(|(_, item)| !matches!(item, ModuleItem::Stmt(Stmt::Decl(Decl::Var(_)))))
-      .take_while(|(_, item)| matches!(item, ModuleItem::Stmt(Stmt::Decl(Decl::Var(_)))))
+      .skip_while(|(_, item)| !is_require_decl(item))
+      .take_while(|(_, item)| is_require_decl(item))
       .map(|(i, item)| {
         if let Some(is_async) = self.promises.pop_front() && is_async {
           if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) = item {
@@ -89,6 +89,25 @@ impl VisitMut for AsyncModuleVisitor {
   }
 }
 
+/// `var _x = require(...)`, possibly wrapped by an interop helper like
+/// `_interop_require_wildcard(require(...))`.
+fn is_require_decl(item: &ModuleItem) -> bool {
+  if let ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) = item
+    && let Some(Expr::Call(call)) = var.decls.first().and_then(|decl| decl.init.as_deref())
+  {
+    is_require_call(call)
+      || call.args.first().map_or(false, |arg| {
+        matches!(&*arg.expr, Expr::Call(call) if is_require_call(call))
+      })
+  } else {
+    false
+  }
+}
+
+fn is_require_call(call: &CallExpr) -> bool {
+  matches!(&call.callee, Callee::Expr(callee) if matches!(&**callee, Expr::Ident(ident) if &*ident.sym == "require"))
+}
+
 fn make_arg(arg: &str) -> Option<ExprOrSpread> {
   Some(ExprOrSpread {
     spread: None,
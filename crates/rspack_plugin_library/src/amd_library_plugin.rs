@@ -1,12 +1,12 @@
 use rspack_core::{
   rspack_sources::{ConcatSource, RawSource, SourceExt},
-  AdditionalChunkRuntimeRequirementsArgs, ExternalModule, Filename, LibraryName, LibraryOptions,
-  Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginRenderHookOutput,
-  RenderArgs, RuntimeGlobals, SourceType,
+  AdditionalChunkRuntimeRequirementsArgs, Chunk, Compilation, ExternalModule, Filename,
+  LibraryName, LibraryOptions, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  PluginContext, PluginRenderHookOutput, RenderArgs, RuntimeGlobals, SourceType,
 };
 use rspack_error::Result;
 
-use super::utils::{external_arguments, external_dep_array};
+use super::utils::{external_arguments, external_dep_array, get_options_for_chunk};
 
 #[derive(Debug)]
 pub struct AmdLibraryPlugin {
@@ -18,7 +18,20 @@ impl AmdLibraryPlugin {
     Self { require_as_wrapper }
   }
 
-  pub fn normalize_name(&self, o: &Option<LibraryOptions>) -> Result<Option<String>> {
+  fn get_options_for_chunk<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunk: &Chunk,
+  ) -> Option<&'a LibraryOptions> {
+    let library_type = if self.require_as_wrapper {
+      "amd-require"
+    } else {
+      "amd"
+    };
+    get_options_for_chunk(compilation, chunk, library_type)
+  }
+
+  pub fn normalize_name(&self, o: Option<&LibraryOptions>) -> Result<Option<String>> {
     if let Some(LibraryOptions {
       name: Some(LibraryName {
         root: Some(root), ..
@@ -45,6 +58,13 @@ impl Plugin for AmdLibraryPlugin {
     _ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &args.compilation;
+    let Some(chunk) = compilation.chunk_by_ukey.get(args.chunk) else {
+      return Ok(());
+    };
+    if self.get_options_for_chunk(compilation, chunk).is_none() {
+      return Ok(());
+    }
     args
       .runtime_requirements
       .insert(RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME);
@@ -54,6 +74,9 @@ impl Plugin for AmdLibraryPlugin {
   fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
     let compilation = &args.compilation;
     let chunk = args.chunk();
+    let Some(library) = self.get_options_for_chunk(compilation, chunk) else {
+      return Ok(None);
+    };
     let modules = compilation
       .chunk_graph
       .get_chunk_module_identifiers(args.chunk)
@@ -65,13 +88,13 @@ impl Plugin for AmdLibraryPlugin {
           .and_then(|module| module.as_external_module())
       })
       .collect::<Vec<&ExternalModule>>();
-    let external_deps_array = external_dep_array(&modules);
+    let external_deps_array = external_dep_array(&modules)?;
     let external_arguments = external_arguments(&modules, compilation);
     let mut fn_start = format!("function({external_arguments}){{\n");
     if compilation.options.output.iife && !chunk.has_runtime(&compilation.chunk_group_by_ukey) {
       fn_start.push_str(" return ");
     }
-    let name = self.normalize_name(&compilation.options.output.library)?;
+    let name = self.normalize_name(Some(library))?;
     let mut source = ConcatSource::default();
     if self.require_as_wrapper {
       source.add(RawSource::from(format!(
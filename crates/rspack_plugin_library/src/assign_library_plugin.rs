@@ -4,6 +4,8 @@ use rspack_core::{
   PluginRenderStartupHookOutput, RenderArgs, RenderStartupArgs, SourceType,
 };
 
+use crate::utils::{get_options_for_chunk, property_access};
+
 #[derive(Debug)]
 pub enum Unnamed {
   Error,
@@ -38,7 +40,7 @@ impl AssignLibraryPlugin {
   }
 
   pub fn get_resolved_full_name(&self, compilation: &Compilation, chunk: &Chunk) -> Vec<String> {
-    if let Some(library) = &compilation.options.output.library {
+    if let Some(library) = self.get_options_for_chunk(compilation, chunk) {
       if let Some(name) = &library.name {
         if let Some(root) = &name.root {
           let mut prefix = self.options.prefix.clone();
@@ -56,6 +58,14 @@ impl AssignLibraryPlugin {
     }
     self.options.prefix.clone()
   }
+
+  fn get_options_for_chunk<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunk: &Chunk,
+  ) -> Option<&'a LibraryOptions> {
+    get_options_for_chunk(compilation, chunk, &self.options.library_type)
+  }
 }
 
 impl Plugin for AssignLibraryPlugin {
@@ -64,6 +74,12 @@ impl Plugin for AssignLibraryPlugin {
   }
 
   fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    if self
+      .get_options_for_chunk(args.compilation, args.chunk())
+      .is_none()
+    {
+      return Ok(None);
+    }
     if self.options.declare {
       let base = &self.get_resolved_full_name(args.compilation, args.chunk())[0];
       let mut source = ConcatSource::default();
@@ -79,17 +95,14 @@ impl Plugin for AssignLibraryPlugin {
     _ctx: PluginContext,
     args: &RenderStartupArgs,
   ) -> PluginRenderStartupHookOutput {
+    let Some(library) = self.get_options_for_chunk(args.compilation, args.chunk()) else {
+      return Ok(None);
+    };
     let mut source = ConcatSource::default();
-    // TODO: respect entryOptions.library
-    let library = &args.compilation.options.output.library;
-    let is_copy = if let Some(library) = library {
-      if library.name.is_some() {
-        matches!(self.options.named, Some(Named::Copy))
-      } else {
-        matches!(self.options.unnamed, Unnamed::Copy)
-      }
+    let is_copy = if library.name.is_some() {
+      matches!(self.options.named, Some(Named::Copy))
     } else {
-      false
+      matches!(self.options.unnamed, Unnamed::Copy)
     };
     let full_name_resolved = self.get_resolved_full_name(args.compilation, args.chunk());
     let export_access = property_library(library);
@@ -128,23 +141,13 @@ impl Plugin for AssignLibraryPlugin {
 }
 
 #[inline]
-fn property_library(library: &Option<LibraryOptions>) -> String {
-  if let Some(library) = library {
-    if let Some(export) = &library.export {
-      return property_access(export);
-    }
+fn property_library(library: &LibraryOptions) -> String {
+  if let Some(export) = &library.export {
+    return property_access(export);
   }
   String::default()
 }
 
-fn property_access(o: &Vec<String>) -> String {
-  let mut str = String::default();
-  for property in o {
-    str.push_str(format!(r#"["{property}"]"#).as_str());
-  }
-  str
-}
-
 fn access_with_init(accessor: &Vec<String>, existing_length: usize, init_last: bool) -> String {
   let base = accessor[0].clone();
   if accessor.len() == 1 && !init_last {
@@ -4,4 +4,8 @@ mod umd_library_plugin;
 pub use umd_library_plugin::UmdLibraryPlugin;
 mod amd_library_plugin;
 pub use amd_library_plugin::AmdLibraryPlugin;
+mod module_library_plugin;
+pub use module_library_plugin::ModuleLibraryPlugin;
+mod system_library_plugin;
+pub use system_library_plugin::SystemLibraryPlugin;
 mod utils;
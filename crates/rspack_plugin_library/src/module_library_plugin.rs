@@ -0,0 +1,74 @@
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  to_identifier, Plugin, PluginContext, PluginRenderStartupHookOutput, RenderStartupArgs,
+};
+
+use crate::utils::{get_options_for_chunk, property_access};
+
+/// Exposes the exports of the entry module as `export` statements of the chunk, see
+/// `library.type = "module"`.
+#[derive(Debug, Default)]
+pub struct ModuleLibraryPlugin;
+
+impl Plugin for ModuleLibraryPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleLibraryPlugin"
+  }
+
+  fn render_startup(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderStartupArgs,
+  ) -> PluginRenderStartupHookOutput {
+    let compilation = args.compilation;
+    let chunk = args.chunk();
+    if get_options_for_chunk(compilation, chunk, "module").is_none() {
+      return Ok(None);
+    }
+    // `__webpack_exports__` holds the exports of the last entry dependency of the entrypoint.
+    let Some(entry_module) = compilation
+      .entrypoints
+      .iter()
+      .find(|(_, ukey)| {
+        compilation
+          .chunk_group_by_ukey
+          .get(ukey)
+          .map_or(false, |entrypoint| entrypoint.get_entry_point_chunk() == chunk.ukey)
+      })
+      .and_then(|(name, _)| compilation.entry_dependencies.get(name))
+      .and_then(|dependencies| {
+        dependencies
+          .iter()
+          .rev()
+          .find_map(|dependency| compilation.module_graph.module_identifier_by_dependency_id(dependency))
+      })
+      .copied()
+    else {
+      return Ok(None);
+    };
+    let mut source = ConcatSource::default();
+    if compilation.module_graph.is_async(&entry_module) {
+      source.add(RawSource::from(
+        "__webpack_exports__ = await __webpack_exports__;\n",
+      ));
+    }
+    let mut exports = vec![];
+    if let Some(exports_info) = compilation.exports_info.get(&entry_module) {
+      for name in &exports_info.provided_exports {
+        let var_name = format!("__webpack_exports__{}", to_identifier(name));
+        source.add(RawSource::from(format!(
+          "var {var_name} = __webpack_exports__{};\n",
+          property_access(&vec![name.to_string()])
+        )));
+        exports.push(format!("{var_name} as {name}"));
+      }
+    }
+    if !exports.is_empty() {
+      source.add(RawSource::from(format!(
+        "export {{ {} }};\n",
+        exports.join(", ")
+      )));
+    }
+    Ok(Some(source.boxed()))
+  }
+}
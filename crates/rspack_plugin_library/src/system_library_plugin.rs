@@ -0,0 +1,106 @@
+use rspack_core::{
+  rspack_sources::{ConcatSource, RawSource, SourceExt},
+  AdditionalChunkRuntimeRequirementsArgs, ExternalModule, Filename, LibraryName, Plugin,
+  PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginRenderHookOutput,
+  RenderArgs, RuntimeGlobals, SourceType,
+};
+
+use super::utils::{external_dep_array, external_module_names, get_options_for_chunk};
+
+/// Wraps the entry chunk in `System.register`, the externals of type `system` are the
+/// dependencies of the registered module.
+#[derive(Debug, Default)]
+pub struct SystemLibraryPlugin;
+
+impl Plugin for SystemLibraryPlugin {
+  fn name(&self) -> &'static str {
+    "SystemLibraryPlugin"
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &args.compilation;
+    let Some(chunk) = compilation.chunk_by_ukey.get(args.chunk) else {
+      return Ok(());
+    };
+    if get_options_for_chunk(compilation, chunk, "system").is_none() {
+      return Ok(());
+    }
+    args
+      .runtime_requirements
+      .insert(RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME);
+    Ok(())
+  }
+
+  fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    let compilation = &args.compilation;
+    let chunk = args.chunk();
+    let Some(library) = get_options_for_chunk(compilation, chunk, "system") else {
+      return Ok(None);
+    };
+    let modules = compilation
+      .chunk_graph
+      .get_chunk_module_identifiers(args.chunk)
+      .iter()
+      .filter_map(|identifier| {
+        compilation
+          .module_graph
+          .module_by_identifier(identifier)
+          .and_then(|module| module.as_external_module())
+          .filter(|module| module.external_type() == "system")
+      })
+      .collect::<Vec<&ExternalModule>>();
+    let name = match &library.name {
+      Some(LibraryName {
+        root: Some(root), ..
+      }) => root
+        .get(0)
+        .map(|name| {
+          let name = Filename::from(name.to_string()).render_with_chunk(
+            chunk,
+            ".js",
+            &SourceType::JavaScript,
+          );
+          format!("'{name}', ")
+        })
+        .unwrap_or_default(),
+      _ => String::new(),
+    };
+    let external_arguments = external_module_names(&modules, compilation);
+
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(format!(
+      "System.register({name}{}, function(__WEBPACK_DYNAMIC_EXPORT__, __system_context__) {{\n",
+      external_dep_array(&modules)?
+    )));
+    for argument in &external_arguments {
+      source.add(RawSource::from(format!(
+        "  var {argument} = {{}};\n  Object.defineProperty({argument}, \"__esModule\", {{ value: true }});\n"
+      )));
+    }
+    source.add(RawSource::from("  return {\n"));
+    if !external_arguments.is_empty() {
+      let setters = external_arguments
+        .iter()
+        .map(|argument| {
+          format!(
+            "      function(module) {{\n        Object.keys(module).forEach(function(key) {{\n          {argument}[key] = module[key];\n        }});\n      }}"
+          )
+        })
+        .collect::<Vec<_>>()
+        .join(",\n");
+      source.add(RawSource::from(format!(
+        "    setters: [\n{setters}\n    ],\n"
+      )));
+    }
+    source.add(RawSource::from(
+      "    execute: function() {\n      __WEBPACK_DYNAMIC_EXPORT__(function() {\n        return ",
+    ));
+    source.add(args.source.clone());
+    source.add(RawSource::from("\n      }());\n    }\n  };\n});"));
+    Ok(Some(source.boxed()))
+  }
+}
@@ -1,23 +1,34 @@
 use rspack_core::{
   rspack_sources::{ConcatSource, RawSource, SourceExt},
-  AdditionalChunkRuntimeRequirementsArgs, Chunk, ExternalModule, Filename, LibraryAuxiliaryComment,
-  Plugin, PluginAdditionalChunkRuntimeRequirementsOutput, PluginContext, PluginRenderHookOutput,
-  RenderArgs, RuntimeGlobals, SourceType,
+  AdditionalChunkRuntimeRequirementsArgs, Chunk, Compilation, ExternalModule, Filename,
+  LibraryAuxiliaryComment, LibraryOptions, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  PluginContext, PluginRenderHookOutput, RenderArgs, RuntimeGlobals, SourceType,
 };
+use rspack_error::Result;
 
-use super::utils::{external_arguments, external_dep_array};
+use super::utils::{external_arguments, external_dep_array, get_options_for_chunk};
 
 #[derive(Debug)]
 pub struct UmdLibraryPlugin {
   _optional_amd_external_as_global: bool,
+  library_type: String,
 }
 
 impl UmdLibraryPlugin {
-  pub fn new(_optional_amd_external_as_global: bool) -> Self {
+  pub fn new(_optional_amd_external_as_global: bool, library_type: String) -> Self {
     Self {
       _optional_amd_external_as_global,
+      library_type,
     }
   }
+
+  fn get_options_for_chunk<'a>(
+    &self,
+    compilation: &'a Compilation,
+    chunk: &Chunk,
+  ) -> Option<&'a LibraryOptions> {
+    get_options_for_chunk(compilation, chunk, &self.library_type)
+  }
 }
 
 impl Plugin for UmdLibraryPlugin {
@@ -30,6 +41,13 @@ impl Plugin for UmdLibraryPlugin {
     _ctx: PluginContext,
     args: &mut AdditionalChunkRuntimeRequirementsArgs,
   ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &args.compilation;
+    let Some(chunk) = compilation.chunk_by_ukey.get(args.chunk) else {
+      return Ok(());
+    };
+    if self.get_options_for_chunk(compilation, chunk).is_none() {
+      return Ok(());
+    }
     args
       .runtime_requirements
       .insert(RuntimeGlobals::RETURN_EXPORTS_FROM_RUNTIME);
@@ -39,6 +57,9 @@ impl Plugin for UmdLibraryPlugin {
   fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
     let compilation = &args.compilation;
     let chunk = args.chunk();
+    let Some(library) = self.get_options_for_chunk(compilation, chunk) else {
+      return Ok(None);
+    };
     let modules = compilation
       .chunk_graph
       .get_chunk_module_identifiers(args.chunk)
@@ -61,16 +82,11 @@ impl Plugin for UmdLibraryPlugin {
       ""
     };
 
-    let (name, umd_named_define, auxiliary_comment) =
-      if let Some(library) = &compilation.options.output.library {
-        (
-          &library.name,
-          &library.umd_named_define,
-          &library.auxiliary_comment,
-        )
-      } else {
-        (&None, &None, &None)
-      };
+    let (name, umd_named_define, auxiliary_comment) = (
+      &library.name,
+      &library.umd_named_define,
+      &library.auxiliary_comment,
+    );
 
     let (amd, commonjs, root) = if let Some(name) = &name {
       (&name.amd, &name.commonjs, &name.root)
@@ -81,12 +97,12 @@ impl Plugin for UmdLibraryPlugin {
     let define = if let (Some(amd), Some(_)) = &(amd, umd_named_define) {
       format!(
         "define({amd}, {}, {amd_factory});\n",
-        external_dep_array(&required_externals)
+        external_dep_array(&required_externals)?
       )
     } else {
       format!(
         "define({}, {amd_factory});\n",
-        external_dep_array(&required_externals)
+        external_dep_array(&required_externals)?
       )
     };
 
@@ -100,7 +116,7 @@ impl Plugin for UmdLibraryPlugin {
           .map(|commonjs| library_name(&[commonjs], chunk))
           .or_else(|| root.clone().map(|root| library_name(&root, chunk)))
           .unwrap_or_default(),
-        externals_require_array("commonjs", &externals),
+        externals_require_array("commonjs", &externals)?,
       );
       let root_code = format!(
         "{}
@@ -116,7 +132,7 @@ impl Plugin for UmdLibraryPlugin {
           ),
           chunk
         ),
-        external_root_array(&externals)
+        external_root_array(&externals)?
       );
       format!(
         "}} else if(typeof exports === 'object'){{\n
@@ -131,8 +147,8 @@ impl Plugin for UmdLibraryPlugin {
       } else {
         format!(
           "var a = typeof exports === 'object' ? factory({}) : factory({});\n",
-          externals_require_array("commonjs", &externals),
-          external_root_array(&externals)
+          externals_require_array("commonjs", &externals)?,
+          external_root_array(&externals)?
         )
       };
       format!(
@@ -153,7 +169,7 @@ impl Plugin for UmdLibraryPlugin {
             module.exports = factory({});
         }}"#,
       get_auxiliary_comment("commonjs2", auxiliary_comment),
-      externals_require_array("commonjs2", &externals)
+      externals_require_array("commonjs2", &externals)?
     )));
     source.add(RawSource::from(format!(
       "else if(typeof define === 'function' && define.amd) {{
@@ -181,27 +197,38 @@ fn replace_keys(v: String, chunk: &Chunk) -> String {
   Filename::from(v).render_with_chunk(chunk, ".js", &SourceType::JavaScript)
 }
 
-fn externals_require_array(_t: &str, externals: &[&ExternalModule]) -> String {
-  externals
-    .iter()
-    .map(|m| {
-      let request = &m.request;
-      // TODO: check if external module is optional
-      format!("require('{request}')")
-    })
-    .collect::<Vec<_>>()
-    .join(", ")
+fn externals_require_array(t: &str, externals: &[&ExternalModule]) -> Result<String> {
+  Ok(
+    externals
+      .iter()
+      .map(|m| {
+        let request = m.request.get(t)?;
+        // TODO: check if external module is optional
+        Ok(format!(
+          "require('{}'){}",
+          request.primary(),
+          accessor_to_object_access(request.rest())
+        ))
+      })
+      .collect::<Result<Vec<_>>>()?
+      .join(", "),
+  )
 }
 
-fn external_root_array(modules: &[&ExternalModule]) -> String {
-  modules
-    .iter()
-    .map(|m| {
-      let request = &m.request;
-      format!("root{}", accessor_to_object_access(&[request.to_owned()]))
-    })
-    .collect::<Vec<_>>()
-    .join(", ")
+fn external_root_array(modules: &[&ExternalModule]) -> Result<String> {
+  Ok(
+    modules
+      .iter()
+      .map(|m| {
+        let request = m.request.get("root")?;
+        Ok(format!(
+          "root{}",
+          accessor_to_object_access(request.as_slice())
+        ))
+      })
+      .collect::<Result<Vec<_>>>()?
+      .join(", "),
+  )
 }
 
 fn accessor_to_object_access(accessor: &[String]) -> String {
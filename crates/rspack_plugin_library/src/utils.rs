@@ -1,16 +1,25 @@
-use rspack_core::{to_identifier, Compilation, ExternalModule};
+use rspack_core::{to_identifier, Chunk, Compilation, ExternalModule, LibraryOptions};
+use rspack_error::Result;
 use rspack_identifier::Identifiable;
 
-pub fn external_dep_array(modules: &[&ExternalModule]) -> String {
+pub fn external_dep_array(modules: &[&ExternalModule]) -> Result<String> {
   let value = modules
     .iter()
-    .map(|m| format!("'{}'", m.request))
-    .collect::<Vec<_>>()
+    .map(|m| Ok(format!("'{}'", m.request.get("amd")?.primary())))
+    .collect::<Result<Vec<_>>>()?
     .join(", ");
-  format!("[{value}]")
+  Ok(format!("[{value}]"))
 }
 
 pub fn external_arguments(modules: &[&ExternalModule], compilation: &Compilation) -> String {
+  external_module_names(modules, compilation).join(", ")
+}
+
+/// The variable names of the externals, i.e. `__WEBPACK_EXTERNAL_MODULE_<id>__`.
+pub fn external_module_names(
+  modules: &[&ExternalModule],
+  compilation: &Compilation,
+) -> Vec<String> {
   modules
     .iter()
     .map(|m| {
@@ -26,5 +35,26 @@ pub fn external_arguments(modules: &[&ExternalModule], compilation: &Compilation
       )
     })
     .collect::<Vec<_>>()
-    .join(", ")
+}
+
+/// Same as `getOptionsForChunk` of webpack, the `library` of the entry of the chunk, entries
+/// without a `library` fall back to `output.library`. `None` unless it's of `library_type`.
+pub fn get_options_for_chunk<'a>(
+  compilation: &'a Compilation,
+  chunk: &Chunk,
+  library_type: &str,
+) -> Option<&'a LibraryOptions> {
+  chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.library.as_ref())
+    .or(compilation.options.output.library.as_ref())
+    .filter(|library| library.library_type == library_type)
+}
+
+pub fn property_access(o: &Vec<String>) -> String {
+  let mut str = String::default();
+  for property in o {
+    str.push_str(format!(r#"["{property}"]"#).as_str());
+  }
+  str
 }
@@ -1,50 +1,136 @@
 use std::sync::atomic::Ordering::SeqCst;
-use std::{cmp, sync::atomic::AtomicU32};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use std::{
+  cmp,
+  sync::atomic::{AtomicU32, AtomicU8},
+};
 
-use indicatif::{ProgressBar, ProgressStyle};
 use rspack_core::{
-  Compilation, DoneArgs, Module, OptimizeChunksArgs, Plugin, PluginBuildEndHookOutput,
-  PluginContext, PluginMakeHookOutput, PluginOptimizeChunksOutput, PluginProcessAssetsOutput,
-  ProcessAssetsArgs,
+  Compilation, ContentHashArgs, DoneArgs, Module, OptimizeChunksArgs, Plugin,
+  PluginBuildEndHookOutput, PluginContentHashHookOutput, PluginContext, PluginMakeHookOutput,
+  PluginOptimizeChunksOutput, PluginProcessAssetsOutput, PluginRenderManifestHookOutput,
+  ProcessAssetsArgs, RenderManifestArgs,
 };
 use rspack_error::Result;
 
+mod reporter;
+pub use reporter::*;
+
 #[derive(Debug, Clone, Default)]
 pub struct ProgressPluginConfig {
   // the prefix name of progress bar
   pub prefix: Option<String>,
+  /// Receives the progress events, a progress bar is drawn if it's not set.
+  pub reporter: Option<Arc<dyn ProgressReporter>>,
+}
+
+#[derive(Debug)]
+struct ProgressState {
+  start: Instant,
+  last_event: Instant,
 }
 
+/// Value of [ProgressPlugin::last_phase] before any phase is reported.
+const NO_PHASE: u8 = u8::MAX;
+
 #[derive(Debug)]
 pub struct ProgressPlugin {
   pub options: ProgressPluginConfig,
-  pub progress_bar: ProgressBar,
+  pub reporter: Arc<dyn ProgressReporter>,
   pub modules_count: AtomicU32,
   pub modules_done: AtomicU32,
   pub last_modules_count: Option<u32>,
+  state: Mutex<ProgressState>,
+  /// The last reported [ProgressPhase], as `u8`.
+  last_phase: AtomicU8,
 }
 
 impl ProgressPlugin {
   pub fn new(options: ProgressPluginConfig) -> Self {
-    let progress_bar = ProgressBar::new(100);
-    progress_bar.set_style(
-      ProgressStyle::with_template("{prefix} {bar:40.cyan/blue} {percent}% {wide_msg}")
-        .expect("TODO:"),
-    );
-    progress_bar.set_prefix(
-      options
-        .prefix
-        .clone()
-        .unwrap_or_else(|| "Rspack".to_string()),
-    );
+    let reporter = options.reporter.clone().unwrap_or_else(|| {
+      Arc::new(BarReporter::new(
+        options
+          .prefix
+          .clone()
+          .unwrap_or_else(|| "Rspack".to_string()),
+      ))
+    });
+    let now = Instant::now();
     Self {
       options,
-      progress_bar,
+      reporter,
       modules_count: AtomicU32::new(0),
       modules_done: AtomicU32::new(0),
       last_modules_count: None,
+      state: Mutex::new(ProgressState {
+        start: now,
+        last_event: now,
+      }),
+      last_phase: AtomicU8::new(NO_PHASE),
     }
   }
+
+  fn report(
+    &self,
+    percentage: f32,
+    phase: ProgressPhase,
+    module: Option<String>,
+    details: Vec<String>,
+  ) {
+    let now = Instant::now();
+    let mut state = self.state.lock().expect("should lock progress state");
+    if phase == ProgressPhase::Make {
+      state.start = now;
+      state.last_event = now;
+    }
+    let event = ProgressEvent {
+      percentage,
+      phase,
+      module,
+      details,
+      elapsed: now.duration_since(state.start).as_millis() as u64,
+      duration: now.duration_since(state.last_event).as_millis() as u64,
+    };
+    state.last_event = now;
+    self.last_phase.store(phase as u8, SeqCst);
+    // Report while holding the lock, so events of parallel hooks are kept in order.
+    self.reporter.report(&event);
+  }
+
+  /// Report a phase whose hook is called for each chunk only once.
+  fn report_once(&self, percentage: f32, phase: ProgressPhase) {
+    // The hooks run in parallel, only the first one swapping in the phase reports it.
+    if self.last_phase.swap(phase as u8, SeqCst) != phase as u8 {
+      self.report(percentage, phase, None, vec![]);
+    }
+  }
+
+  fn report_building(&self, module: &dyn Module) {
+    let modules_done = self.modules_done.load(SeqCst);
+    let modules_count = cmp::max(
+      self.last_modules_count.unwrap_or(1),
+      self.modules_count.load(SeqCst),
+    );
+    let percent = (modules_done as f32) / (modules_count as f32);
+    self.report(
+      0.1 + 0.55 * percent,
+      ProgressPhase::Building,
+      module
+        .as_normal_module()
+        .map(|module| module.raw_request().to_string()),
+      vec![format!("{modules_done}/{modules_count} modules")],
+    );
+  }
+
+  fn report_process_assets(&self, percentage: f32, stage: &str) {
+    self.report(
+      percentage,
+      ProgressPhase::ProcessAssets,
+      None,
+      vec![stage.to_string()],
+    );
+  }
 }
 
 #[async_trait::async_trait]
@@ -54,37 +140,31 @@ impl Plugin for ProgressPlugin {
   }
 
   async fn make(&self, _ctx: PluginContext, _compilation: &Compilation) -> PluginMakeHookOutput {
-    self.progress_bar.reset();
     self.modules_count.store(0, SeqCst);
     self.modules_done.store(0, SeqCst);
-    self.progress_bar.set_message("make");
-    self.progress_bar.set_position(1);
+    self.report(0.01, ProgressPhase::Make, None, vec![]);
     Ok(())
   }
 
   async fn build_module(&self, module: &mut dyn Module) -> Result<()> {
-    if let Some(module) = module.as_normal_module() {
-      self
-        .progress_bar
-        .set_message(format!("building {}", module.raw_request()));
-    } else {
-      self.progress_bar.set_message("building");
-    }
     self.modules_count.fetch_add(1, SeqCst);
+    self.report_building(module);
     Ok(())
   }
 
-  async fn succeed_module(&self, _module: &dyn Module) -> Result<()> {
-    let previous_modules_done = self.modules_done.fetch_add(1, SeqCst);
-    let modules_done = previous_modules_done + 1;
-    let percent = (modules_done as f32)
-      / (cmp::max(
-        self.last_modules_count.unwrap_or(1),
-        self.modules_count.load(SeqCst),
-      ) as f32);
-    self
-      .progress_bar
-      .set_position((10.0 + 55.0 * percent) as u64);
+  async fn succeed_module(&self, module: &dyn Module) -> Result<()> {
+    self.modules_done.fetch_add(1, SeqCst);
+    self.report_building(module);
+    Ok(())
+  }
+
+  async fn finish_modules(&mut self, _modules: &mut Compilation) -> Result<()> {
+    self.report(0.7, ProgressPhase::FinishModules, None, vec![]);
+    Ok(())
+  }
+
+  fn seal(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.71, ProgressPhase::Seal, None, vec![]);
     Ok(())
   }
 
@@ -93,18 +173,127 @@ impl Plugin for ProgressPlugin {
     _ctx: PluginContext,
     _args: OptimizeChunksArgs,
   ) -> PluginOptimizeChunksOutput {
-    self.progress_bar.set_position(80);
-    self.progress_bar.set_message("optimizing");
+    self.report(0.73, ProgressPhase::OptimizeChunks, None, vec![]);
+    Ok(())
+  }
+
+  async fn optimize_chunk_modules(&mut self, _args: OptimizeChunksArgs<'_>) -> Result<()> {
+    self.report(0.75, ProgressPhase::OptimizeChunkModules, None, vec![]);
+    Ok(())
+  }
+
+  fn module_ids(&mut self, _modules: &mut Compilation) -> Result<()> {
+    self.report(0.77, ProgressPhase::ModuleIds, None, vec![]);
+    Ok(())
+  }
+
+  fn chunk_ids(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.8, ProgressPhase::ChunkIds, None, vec![]);
+    Ok(())
+  }
+
+  fn before_code_generation(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.82, ProgressPhase::CodeGeneration, None, vec![]);
     Ok(())
   }
 
+  async fn content_hash(
+    &self,
+    _ctx: PluginContext,
+    _args: &ContentHashArgs<'_>,
+  ) -> PluginContentHashHookOutput {
+    self.report_once(0.85, ProgressPhase::Hashing);
+    Ok(None)
+  }
+
+  async fn render_manifest(
+    &self,
+    _ctx: PluginContext,
+    _args: RenderManifestArgs<'_>,
+  ) -> PluginRenderManifestHookOutput {
+    self.report_once(0.87, ProgressPhase::ChunkAssets);
+    Ok(vec![])
+  }
+
   async fn process_assets_stage_additional(
     &mut self,
     _ctx: PluginContext,
     _args: ProcessAssetsArgs<'_>,
   ) -> PluginProcessAssetsOutput {
-    self.progress_bar.set_position(90);
-    self.progress_bar.set_message("processing assets");
+    self.report_process_assets(0.88, "additional");
+    Ok(())
+  }
+
+  async fn process_assets_stage_pre_process(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.89, "pre process");
+    Ok(())
+  }
+
+  async fn process_assets_stage_none(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.9, "none");
+    Ok(())
+  }
+
+  async fn process_assets_stage_optimize_size(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.91, "optimize size");
+    Ok(())
+  }
+
+  async fn process_assets_stage_dev_tooling(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.92, "dev tooling");
+    Ok(())
+  }
+
+  async fn process_assets_stage_optimize_inline(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.93, "optimize inline");
+    Ok(())
+  }
+
+  async fn process_assets_stage_summarize(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.94, "summarize");
+    Ok(())
+  }
+
+  async fn process_assets_stage_report(
+    &mut self,
+    _ctx: PluginContext,
+    _args: ProcessAssetsArgs<'_>,
+  ) -> PluginProcessAssetsOutput {
+    self.report_process_assets(0.95, "report");
+    Ok(())
+  }
+
+  async fn emit(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.96, ProgressPhase::Emit, None, vec![]);
+    Ok(())
+  }
+
+  async fn after_emit(&mut self, _compilation: &mut Compilation) -> Result<()> {
+    self.report(0.98, ProgressPhase::AfterEmit, None, vec![]);
     Ok(())
   }
 
@@ -113,9 +302,120 @@ impl Plugin for ProgressPlugin {
     _ctx: PluginContext,
     _args: DoneArgs<'s, 'c>,
   ) -> PluginBuildEndHookOutput {
-    self.progress_bar.set_message("done");
-    self.progress_bar.finish();
+    self.report(1.0, ProgressPhase::Done, None, vec![]);
     self.last_modules_count = Some(self.modules_count.load(SeqCst));
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[derive(Debug, Default)]
+  struct CollectReporter {
+    events: Mutex<Vec<ProgressEvent>>,
+  }
+
+  impl ProgressReporter for CollectReporter {
+    fn report(&self, event: &ProgressEvent) {
+      self
+        .events
+        .lock()
+        .expect("should lock events")
+        .push(event.clone());
+    }
+  }
+
+  fn new_plugin() -> (ProgressPlugin, Arc<CollectReporter>) {
+    let reporter = Arc::new(CollectReporter::default());
+    let plugin = ProgressPlugin::new(ProgressPluginConfig {
+      prefix: None,
+      reporter: Some(reporter.clone()),
+    });
+    (plugin, reporter)
+  }
+
+  #[test]
+  fn report_once_from_parallel_hooks() {
+    let (plugin, reporter) = new_plugin();
+    plugin.report(0.01, ProgressPhase::Make, None, vec![]);
+    std::thread::scope(|s| {
+      for _ in 0..8 {
+        s.spawn(|| {
+          for _ in 0..100 {
+            plugin.report_once(0.85, ProgressPhase::Hashing);
+          }
+        });
+      }
+    });
+    plugin.report_once(0.87, ProgressPhase::ChunkAssets);
+
+    let phases = reporter
+      .events
+      .lock()
+      .expect("should lock events")
+      .iter()
+      .map(|event| event.phase)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      phases,
+      vec![
+        ProgressPhase::Make,
+        ProgressPhase::Hashing,
+        ProgressPhase::ChunkAssets
+      ]
+    );
+  }
+
+  #[test]
+  fn write_events_as_json_lines() {
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuffer {
+      fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self
+          .0
+          .lock()
+          .map_err(|_| std::io::Error::new(std::io::ErrorKind::Other, "poisoned buffer"))?
+          .write(buf)
+      }
+
+      fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+      }
+    }
+
+    let buffer = SharedBuffer::default();
+    let reporter = JsonLinesReporter::new(buffer.clone());
+    reporter.report(&ProgressEvent {
+      percentage: 0.5,
+      phase: ProgressPhase::ProcessAssets,
+      module: None,
+      details: vec!["dev tooling".to_string()],
+      elapsed: 20,
+      duration: 10,
+    });
+    reporter.report(&ProgressEvent {
+      percentage: 1.0,
+      phase: ProgressPhase::Done,
+      module: None,
+      details: vec![],
+      elapsed: 30,
+      duration: 10,
+    });
+
+    let output = String::from_utf8(buffer.0.lock().expect("should lock buffer").clone())
+      .expect("should be utf8");
+    assert_eq!(
+      output,
+      concat!(
+        r#"{"percentage":0.5,"phase":"process-assets","module":null,"details":["dev tooling"],"elapsed":20,"duration":10}"#,
+        "\n",
+        r#"{"percentage":1.0,"phase":"done","module":null,"details":[],"elapsed":30,"duration":10}"#,
+        "\n"
+      )
+    );
+  }
+}
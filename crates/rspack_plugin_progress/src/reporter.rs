@@ -0,0 +1,144 @@
+use std::{fmt::Debug, io::Write, sync::Mutex};
+
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+
+/// Phases of a compilation, in the order they are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProgressPhase {
+  Make,
+  Building,
+  FinishModules,
+  Seal,
+  OptimizeChunks,
+  OptimizeChunkModules,
+  ModuleIds,
+  ChunkIds,
+  CodeGeneration,
+  Hashing,
+  ChunkAssets,
+  ProcessAssets,
+  Emit,
+  AfterEmit,
+  Done,
+}
+
+impl ProgressPhase {
+  pub fn message(&self) -> &'static str {
+    match self {
+      ProgressPhase::Make => "make",
+      ProgressPhase::Building => "building",
+      ProgressPhase::FinishModules => "finish modules",
+      ProgressPhase::Seal => "sealing",
+      ProgressPhase::OptimizeChunks => "optimizing chunks",
+      ProgressPhase::OptimizeChunkModules => "optimizing chunk modules",
+      ProgressPhase::ModuleIds => "module ids",
+      ProgressPhase::ChunkIds => "chunk ids",
+      ProgressPhase::CodeGeneration => "code generation",
+      ProgressPhase::Hashing => "hashing",
+      ProgressPhase::ChunkAssets => "chunk assets",
+      ProgressPhase::ProcessAssets => "processing assets",
+      ProgressPhase::Emit => "emitting",
+      ProgressPhase::AfterEmit => "after emitting",
+      ProgressPhase::Done => "done",
+    }
+  }
+}
+
+/// An event sent when a phase of the compilation starts, or when a module is built.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressEvent {
+  /// Progress of the compilation, from 0 to 1.
+  pub percentage: f32,
+  pub phase: ProgressPhase,
+  /// The module being built, only set in the `building` phase.
+  pub module: Option<String>,
+  /// Extra information, e.g. the count of built modules or the stage of processing assets.
+  pub details: Vec<String>,
+  /// Milliseconds since the compilation started.
+  pub elapsed: u64,
+  /// Milliseconds since the previous event.
+  pub duration: u64,
+}
+
+impl ProgressEvent {
+  pub fn to_json_line(&self) -> String {
+    serde_json::to_string(self).expect("progress event should be serializable")
+  }
+}
+
+/// Receives the progress events of the [crate::ProgressPlugin].
+pub trait ProgressReporter: Debug + Send + Sync {
+  fn report(&self, event: &ProgressEvent);
+}
+
+/// Draw the progress in a progress bar.
+#[derive(Debug)]
+pub struct BarReporter {
+  progress_bar: ProgressBar,
+}
+
+impl BarReporter {
+  pub fn new(prefix: String) -> Self {
+    let progress_bar = ProgressBar::new(100);
+    progress_bar.set_style(
+      ProgressStyle::with_template("{prefix} {bar:40.cyan/blue} {percent}% {wide_msg}")
+        .expect("TODO:"),
+    );
+    progress_bar.set_prefix(prefix);
+    Self { progress_bar }
+  }
+}
+
+impl ProgressReporter for BarReporter {
+  fn report(&self, event: &ProgressEvent) {
+    if event.phase == ProgressPhase::Make {
+      self.progress_bar.reset();
+    }
+    self
+      .progress_bar
+      .set_position((event.percentage * 100.0) as u64);
+    match &event.module {
+      Some(module) => self
+        .progress_bar
+        .set_message(format!("{} {module}", event.phase.message())),
+      None => self.progress_bar.set_message(event.phase.message()),
+    }
+    if event.phase == ProgressPhase::Done {
+      self.progress_bar.finish();
+    }
+  }
+}
+
+/// Write each progress event as a line of JSON, which is friendly to CI and other tools.
+pub struct JsonLinesReporter {
+  writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl JsonLinesReporter {
+  pub fn new(writer: impl Write + Send + 'static) -> Self {
+    Self {
+      writer: Mutex::new(Box::new(writer)),
+    }
+  }
+
+  pub fn stderr() -> Self {
+    Self::new(std::io::stderr())
+  }
+}
+
+impl Debug for JsonLinesReporter {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JsonLinesReporter").finish()
+  }
+}
+
+impl ProgressReporter for JsonLinesReporter {
+  fn report(&self, event: &ProgressEvent) {
+    let mut writer = self.writer.lock().expect("should lock writer");
+    // Progress is informational, failing to write it should not fail the compilation.
+    let _ = writeln!(writer, "{}", event.to_json_line());
+  }
+}
@@ -33,6 +33,10 @@ impl RemoveEmptyChunksPlugin {
 }
 
 impl Plugin for RemoveEmptyChunksPlugin {
+  fn name(&self) -> &'static str {
+    "RemoveEmptyChunksPlugin"
+  }
+
   fn optimize_chunks(
     &mut self,
     _ctx: rspack_core::PluginContext,
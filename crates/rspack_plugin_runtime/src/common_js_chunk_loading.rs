@@ -5,7 +5,7 @@ use rspack_core::{
 };
 use rspack_error::Result;
 
-use crate::runtime_module::RequireChunkLoadingRuntimeModule;
+use crate::runtime_module::{is_enabled_for_chunk, RequireChunkLoadingRuntimeModule};
 
 #[derive(Debug)]
 pub struct CommonJsChunkLoadingPlugin {}
@@ -31,6 +31,9 @@ impl Plugin for CommonJsChunkLoadingPlugin {
     let compilation = &mut args.compilation;
     let chunk = args.chunk;
     let runtime_requirements = &mut args.runtime_requirements;
+    if !is_enabled_for_chunk(chunk, "require", compilation) {
+      return Ok(());
+    }
 
     let mut has_chunk_loading = false;
     for runtime_requirement in runtime_requirements.iter() {
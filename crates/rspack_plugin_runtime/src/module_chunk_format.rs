@@ -0,0 +1,218 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use rspack_core::rspack_sources::{ConcatSource, RawSource, SourceExt};
+use rspack_core::{
+  get_js_chunk_filename_template, AdditionalChunkRuntimeRequirementsArgs, Chunk, ChunkUkey,
+  Compilation, FilenameRenderOptions, Plugin, PluginAdditionalChunkRuntimeRequirementsOutput,
+  PluginContext, PluginRenderChunkHookOutput, PluginRenderHookOutput, RenderArgs, RenderChunkArgs,
+  RenderStartupArgs, RuntimeGlobals,
+};
+use rspack_error::Result;
+use rspack_plugin_javascript::runtime::{
+  generate_chunk_entry_code, render_chunk_modules, render_chunk_runtime_modules,
+};
+
+/// Renders chunks as ES modules, installed by the runtime chunk from their exports.
+#[derive(Debug)]
+pub struct ModuleChunkFormatPlugin {}
+
+fn get_chunk_output_name(chunk: &Chunk, compilation: &Compilation) -> String {
+  let hash = Some(chunk.get_render_hash());
+  get_js_chunk_filename_template(
+    chunk,
+    &compilation.options.output,
+    &compilation.chunk_group_by_ukey,
+  )
+  .render(FilenameRenderOptions {
+    name: chunk.name_for_filename_template(),
+    extension: Some(".js".to_string()),
+    id: chunk.id.clone(),
+    contenthash: hash.clone(),
+    chunkhash: hash.clone(),
+    hash,
+    ..Default::default()
+  })
+}
+
+/// The `module` externals of the chunk are imported as namespaces at the top of the chunk.
+fn render_external_module_imports(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Result<String> {
+  let mut imports = compilation
+    .chunk_graph
+    .get_chunk_module_identifiers(chunk_ukey)
+    .iter()
+    .filter_map(|identifier| {
+      compilation
+        .module_graph
+        .module_by_identifier(identifier)
+        .and_then(|module| module.as_external_module())
+    })
+    .filter(|module| module.external_type() == "module")
+    .map(|module| {
+      Ok(format!(
+        "import * as {} from '{}';\n",
+        module.module_namespace_name()?,
+        module.request.get("module")?.primary()
+      ))
+    })
+    .collect::<Result<Vec<_>>>()?;
+  imports.sort();
+  Ok(imports.concat())
+}
+
+#[async_trait]
+impl Plugin for ModuleChunkFormatPlugin {
+  fn name(&self) -> &'static str {
+    "ModuleChunkFormatPlugin"
+  }
+
+  fn apply(
+    &mut self,
+    _ctx: rspack_core::PluginContext<&mut rspack_core::ApplyContext>,
+  ) -> Result<()> {
+    Ok(())
+  }
+
+  fn additional_chunk_runtime_requirements(
+    &self,
+    _ctx: PluginContext,
+    args: &mut AdditionalChunkRuntimeRequirementsArgs,
+  ) -> PluginAdditionalChunkRuntimeRequirementsOutput {
+    let compilation = &mut args.compilation;
+    let chunk_ukey = args.chunk;
+    let runtime_requirements = &mut args.runtime_requirements;
+    let chunk = compilation
+      .chunk_by_ukey
+      .get(chunk_ukey)
+      .ok_or_else(|| anyhow!("chunk not found"))?;
+
+    if chunk.has_runtime(&compilation.chunk_group_by_ukey) {
+      return Ok(());
+    }
+
+    if compilation
+      .chunk_graph
+      .get_number_of_entry_modules(chunk_ukey)
+      > 0
+    {
+      runtime_requirements.insert(RuntimeGlobals::REQUIRE);
+      runtime_requirements.insert(RuntimeGlobals::EXTERNAL_INSTALL_CHUNK);
+    }
+
+    Ok(())
+  }
+
+  async fn render_chunk(
+    &self,
+    _ctx: PluginContext,
+    args: &RenderChunkArgs,
+  ) -> PluginRenderChunkHookOutput {
+    let chunk = args.chunk();
+    let mut sources = ConcatSource::default();
+    sources.add(RawSource::from(render_external_module_imports(
+      args.compilation,
+      args.chunk_ukey,
+    )?));
+    sources.add(RawSource::from(format!(
+      "export const ids = ['{}'];\n",
+      &chunk.expect_id().to_string()
+    )));
+    sources.add(RawSource::from("export const modules = "));
+    sources.add(render_chunk_modules(args.compilation, args.chunk_ukey)?);
+    sources.add(RawSource::from(";\n"));
+    if !args
+      .compilation
+      .chunk_graph
+      .get_chunk_runtime_modules_in_order(args.chunk_ukey)
+      .is_empty()
+    {
+      sources.add(RawSource::from("export const runtime = "));
+      sources.add(render_chunk_runtime_modules(
+        args.compilation,
+        args.chunk_ukey,
+      )?);
+      sources.add(RawSource::from(";\n"));
+    }
+
+    if chunk.has_entry_module(&args.compilation.chunk_graph) {
+      let entry_point = {
+        let entry_points = args
+          .compilation
+          .chunk_graph
+          .get_chunk_entry_modules_with_chunk_group(&chunk.ukey);
+
+        let entry_point_ukey = entry_points
+          .iter()
+          .next()
+          .ok_or_else(|| anyhow!("should has entry point ukey"))?;
+
+        args
+          .compilation
+          .chunk_group_by_ukey
+          .get(entry_point_ukey)
+          .ok_or_else(|| anyhow!("should has entry point"))?
+      };
+
+      let runtime_chunk = args
+        .compilation
+        .chunk_by_ukey
+        .get(&entry_point.get_runtime_chunk())
+        .ok_or_else(|| anyhow!("should has runtime chunk"))?;
+
+      sources.add(RawSource::from(format!(
+        "\nimport {} from './{}';\n",
+        RuntimeGlobals::REQUIRE,
+        get_chunk_output_name(runtime_chunk, args.compilation)
+      )));
+      sources.add(RawSource::from(format!(
+        "import * as __webpack_self_exports__ from './{}';\n",
+        get_chunk_output_name(chunk, args.compilation)
+      )));
+      sources.add(RawSource::from(format!(
+        "{}(__webpack_self_exports__);\n",
+        RuntimeGlobals::EXTERNAL_INSTALL_CHUNK,
+      )));
+      sources.add(generate_chunk_entry_code(args.compilation, args.chunk_ukey));
+      if let Some(s) =
+        args
+          .compilation
+          .plugin_driver
+          .read()
+          .await
+          .render_startup(RenderStartupArgs {
+            compilation: args.compilation,
+            chunk: &chunk.ukey,
+          })?
+      {
+        sources.add(s);
+      }
+    }
+    Ok(Some(sources.boxed()))
+  }
+
+  fn render(&self, _ctx: PluginContext, args: &RenderArgs) -> PluginRenderHookOutput {
+    let compilation = args.compilation;
+    let external_imports = render_external_module_imports(compilation, args.chunk)?;
+    // Other entry chunks of the runtime import `__webpack_require__` from the runtime chunk.
+    let export_require = args.chunk().has_runtime(&compilation.chunk_group_by_ukey)
+      && compilation
+        .chunk_graph
+        .get_tree_runtime_requirements(args.chunk)
+        .contains(RuntimeGlobals::EXTERNAL_INSTALL_CHUNK);
+    if external_imports.is_empty() && !export_require {
+      return Ok(None);
+    }
+    let mut source = ConcatSource::default();
+    source.add(RawSource::from(external_imports));
+    source.add(args.source.clone());
+    if export_require {
+      source.add(RawSource::from(format!(
+        "export default {};\n",
+        RuntimeGlobals::REQUIRE
+      )));
+    }
+    Ok(Some(source.boxed()))
+  }
+}
@@ -4,7 +4,7 @@ use rspack_core::{
 };
 use rspack_identifier::Identifier;
 
-use super::utils::chunk_has_js;
+use super::utils::{chunk_has_js, get_js_chunk_matcher};
 use crate::impl_runtime_module;
 use crate::runtime_module::utils::{get_initial_chunk_ids, stringify_chunks};
 
@@ -31,13 +31,6 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
   }
 
   fn generate(&self, compilation: &Compilation) -> BoxSource {
-    // let condition_map = compilation.chunk_graph.get_chunk_condition_map(
-    //   &chunk_ukey,
-    //   &compilation.chunk_by_ukey,
-    //   &compilation.chunk_group_by_ukey,
-    //   &compilation.module_graph,
-    //   chunk_hash_js,
-    // );
     let initial_chunks = get_initial_chunk_ids(self.chunk, compilation, chunk_has_js);
     let mut source = ConcatSource::default();
     // object to store loaded and loading chunks
@@ -54,9 +47,14 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
 
     if with_loading {
       source.add(RawSource::from(
-        include_str!("runtime/jsonp_chunk_loading.js")
-          // TODO
-          .replace("JS_MATCHER", "chunkId"),
+        include_str!("runtime/jsonp_chunk_loading.js").replace(
+          "JS_MATCHER",
+          &get_js_chunk_matcher(
+            &self.chunk.expect("The chunk should be attached."),
+            compilation,
+            "chunkId",
+          ),
+        ),
       ));
     }
 
@@ -11,6 +11,7 @@ mod hot_module_replacement;
 mod jsonp_chunk_loading;
 mod load_chunk_with_module;
 mod load_script;
+mod module_chunk_loading;
 mod on_chunk_loaded;
 mod public_path;
 mod require_js_chunk_loading;
@@ -28,9 +29,11 @@ pub use hot_module_replacement::HotModuleReplacementRuntimeModule;
 pub use jsonp_chunk_loading::JsonpChunkLoadingRuntimeModule;
 pub use load_chunk_with_module::LoadChunkWithModuleRuntimeModule;
 pub use load_script::LoadScriptRuntimeModule;
+pub use module_chunk_loading::ModuleChunkLoadingRuntimeModule;
 pub use on_chunk_loaded::OnChunkLoadedRuntimeModule;
 pub use public_path::PublicPathRuntimeModule;
 pub use require_js_chunk_loading::RequireChunkLoadingRuntimeModule;
+pub(crate) use utils::is_enabled_for_chunk;
 mod module_macro;
 mod normal;
 pub use normal::NormalRuntimeModule;
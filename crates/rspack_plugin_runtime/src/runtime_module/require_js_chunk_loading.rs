@@ -5,7 +5,7 @@ use rspack_core::{
 };
 use rspack_identifier::Identifier;
 
-use super::utils::{chunk_has_js, get_undo_path};
+use super::utils::{chunk_has_js, get_js_chunk_matcher, get_undo_path};
 use crate::impl_runtime_module;
 use crate::runtime_module::utils::{get_initial_chunk_ids, stringify_chunks};
 
@@ -78,8 +78,10 @@ impl RuntimeModule for RequireChunkLoadingRuntimeModule {
       let output_dir = filename.render_with_chunk(chunk, ".js", &SourceType::JavaScript);
       source.add(RawSource::from(
         include_str!("runtime/require_chunk_loading_with_loading.js")
-          // TODO
-          .replace("JS_MATCHER", "chunkId")
+          .replace(
+            "JS_MATCHER",
+            &get_js_chunk_matcher(&chunk.ukey, compilation, "chunkId"),
+          )
           .replace(
             "$OUTPUT_DIR$",
             &get_undo_path(
@@ -0,0 +1,21 @@
+// object to store loaded and loading chunks
+// undefined = chunk not loaded, [resolve, Promise] = chunk loading, 0 = chunk loaded
+
+var installChunk = function (data) {
+	var ids = data.ids,
+		modules = data.modules,
+		runtime = data.runtime;
+	for (var moduleId in modules) {
+		if (__webpack_require__.o(modules, moduleId)) {
+			__webpack_require__.m[moduleId] = modules[moduleId];
+		}
+	}
+	if (runtime) runtime(__webpack_require__);
+	for (var i = 0; i < ids.length; i++) {
+		var chunkId = ids[i];
+		if (__webpack_require__.o(installedChunks, chunkId) && installedChunks[chunkId]) {
+			installedChunks[chunkId][0]();
+		}
+		installedChunks[chunkId] = 0;
+	}
+};
@@ -0,0 +1 @@
+__webpack_require__.C = installChunk;
@@ -0,0 +1,31 @@
+// import() chunk loading for javascript
+__webpack_require__.f.j = function (chunkId, promises) {
+	var installedChunkData = __webpack_require__.o(installedChunks, chunkId)
+		? installedChunks[chunkId]
+		: undefined;
+	// 0 means "already installed".
+	if (installedChunkData !== 0) {
+		// a Promise means "currently loading".
+		if (installedChunkData) {
+			promises.push(installedChunkData[1]);
+		} else {
+			if (JS_MATCHER) {
+				// setup Promise in chunk cache
+				var promise = import("$OUTPUT_DIR$" + __webpack_require__.u(chunkId)).then(
+					installChunk,
+					function (e) {
+						if (installedChunks[chunkId] !== 0) installedChunks[chunkId] = undefined;
+						throw e;
+					}
+				);
+				promise = Promise.race([
+					promise,
+					new Promise(function (resolve) {
+						installedChunkData = installedChunks[chunkId] = [resolve];
+					})
+				]);
+				promises.push((installedChunkData[1] = promise));
+			} else installedChunks[chunkId] = 0;
+		}
+	}
+};
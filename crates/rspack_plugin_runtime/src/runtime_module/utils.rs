@@ -1,26 +1,69 @@
-use rspack_core::{ChunkUkey, Compilation, SourceType};
-use rustc_hash::FxHashSet as HashSet;
+use rspack_core::{ChunkLoading, ChunkUkey, Compilation, SourceType};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
-// pub fn condition_map_to_string(map: &HashMap<String, bool>, _value: String) -> String {
-//   let positive_items = map
-//     .iter()
-//     .filter(|(_, v)| **v)
-//     .map(|(k, _)| k)
-//     .collect::<Vec<_>>();
-//   if positive_items.len() == 0 {
-//     return "false".to_string();
-//   }
-//   let negative_items = map
-//     .iter()
-//     .filter(|(_, v)| !**v)
-//     .map(|(k, _)| k)
-//     .collect::<Vec<_>>();
-//   if negative_items.len() == 0 {
-//     return "true".to_string();
-//   }
-//   // TODO
-//   return "true".to_string();
-// }
+/// The js expression testing whether the chunk id `value` is one of the chunks referenced by
+/// `chunk` that have javascript, same as `hasJsMatcher` of webpack.
+pub fn get_js_chunk_matcher(chunk: &ChunkUkey, compilation: &Compilation, value: &str) -> String {
+  let condition_map = compilation.chunk_graph.get_chunk_condition_map(
+    chunk,
+    &compilation.chunk_by_ukey,
+    &compilation.chunk_group_by_ukey,
+    &compilation.module_graph,
+    |chunk, _, _| chunk_has_js(chunk, compilation),
+  );
+  compile_boolean_matcher(&condition_map, value)
+}
+
+/// Same as `compileBooleanMatcher` of webpack, without shortening the regexp of the ids.
+pub fn compile_boolean_matcher(map: &HashMap<String, bool>, value: &str) -> String {
+  let mut positive_items = map
+    .iter()
+    .filter(|(_, v)| **v)
+    .map(|(k, _)| k.as_str())
+    .collect::<Vec<_>>();
+  if positive_items.is_empty() {
+    return "false".to_string();
+  }
+  let mut negative_items = map
+    .iter()
+    .filter(|(_, v)| !**v)
+    .map(|(k, _)| k.as_str())
+    .collect::<Vec<_>>();
+  if negative_items.is_empty() {
+    return "true".to_string();
+  }
+  if positive_items.len() == 1 {
+    return format!("\"{}\" == {value}", positive_items[0]);
+  }
+  if negative_items.len() == 1 {
+    return format!("\"{}\" != {value}", negative_items[0]);
+  }
+  positive_items.sort_unstable();
+  negative_items.sort_unstable();
+  let to_regexp = |items: &[&str]| {
+    items
+      .iter()
+      .map(|item| escape_regexp(item))
+      .collect::<Vec<_>>()
+      .join("|")
+  };
+  if positive_items.len() <= negative_items.len() {
+    format!("/^({})$/.test({value})", to_regexp(&positive_items))
+  } else {
+    format!("!/^({})$/.test({value})", to_regexp(&negative_items))
+  }
+}
+
+fn escape_regexp(item: &str) -> String {
+  let mut escaped = String::with_capacity(item.len());
+  for c in item.chars() {
+    if "\\^$.*+?()[]{}|/-".contains(c) {
+      escaped.push('\\');
+    }
+    escaped.push(c);
+  }
+  escaped
+}
 
 pub fn get_initial_chunk_ids(
   chunk: Option<ChunkUkey>,
@@ -103,6 +146,27 @@ pub fn chunk_has_js(chunk_ukey: &ChunkUkey, compilation: &Compilation) -> bool {
     .is_empty()
 }
 
+/// Whether the chunk loading of the entry of the chunk is `chunk_loading`, chunks without
+/// `chunkLoading` in their entry options use `output.chunkLoading`.
+pub fn is_enabled_for_chunk(
+  chunk_ukey: &ChunkUkey,
+  chunk_loading: &str,
+  compilation: &Compilation,
+) -> bool {
+  let chunk = compilation
+    .chunk_by_ukey
+    .get(chunk_ukey)
+    .expect("chunk should exist");
+  match chunk
+    .get_entry_options(&compilation.chunk_group_by_ukey)
+    .and_then(|options| options.chunk_loading.as_ref())
+    .unwrap_or(&compilation.options.output.chunk_loading)
+  {
+    ChunkLoading::Enable(value) => value == chunk_loading,
+    ChunkLoading::Disable => false,
+  }
+}
+
 pub fn get_undo_path(filename: &str, p: String, enforce_relative: bool) -> String {
   let mut depth: i32 = -1;
   let mut append = String::new();
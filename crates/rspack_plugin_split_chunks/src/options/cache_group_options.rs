@@ -1,7 +1,7 @@
 use derivative::Derivative;
 use rspack_core::ModuleType;
 
-use crate::{ChunkType, OptimizationSplitChunksSizes, TestFn};
+use crate::{ChunkType, LayerFn, OptimizationSplitChunksSizes, TestFn};
 
 #[derive(Derivative)]
 #[derivative(Debug)]
@@ -14,7 +14,9 @@ pub struct CacheGroupOptions {
   pub enforce_size_threshold: Option<OptimizationSplitChunksSizes>,
   pub filename: Option<String>,
   pub id_hint: Option<String>,
-  // TODO: supports pub fn layer: RegExp | string | Function;
+  /// Select modules by the layer they are placed in.
+  #[derivative(Debug = "ignore")]
+  pub layer: Option<LayerFn>,
   pub max_async_requests: Option<u32>,
   pub max_async_size: Option<f64>,
   pub max_initial_requests: Option<u32>,
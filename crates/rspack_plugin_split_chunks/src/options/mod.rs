@@ -68,6 +68,7 @@ pub struct NormalizedOptions {
   pub max_async_requests: u32,
   pub max_initial_requests: u32,
   pub filename: Option<String>,
+  pub hide_path_info: bool,
   #[derivative(Debug = "ignore")]
   pub get_name: SplitChunksNameFn,
   #[derivative(Debug = "ignore")]
@@ -18,7 +18,8 @@ pub struct SplitChunksOptions {
   pub enforce_size_threshold: Option<OptimizationSplitChunksSizes>,
   pub fallback_cache_group: Option<SplitChunksOptionsCacheGroup>,
   pub filename: Option<String>,
-  // TODO: Supports pub hide_path_info: Option<bool>,
+  /// Replace the names of the split chunks, which are usually derived from module paths, with their hashes.
+  pub hide_path_info: Option<bool>,
   pub max_async_requests: Option<u32>,
   pub max_async_size: Option<f64>,
   pub max_initial_requests: Option<u32>,
@@ -30,7 +31,8 @@ pub struct SplitChunksOptions {
   pub min_size_reduction: Option<f64>,
   #[derivative(Debug = "ignore")]
   pub name: Option<SplitChunksNameFn>,
-  // TODO: Supports used_exports: bool,
+  // TODO: Supports used_exports: bool, it needs the code generation of each runtime, otherwise
+  // splitting by the used exports only duplicates the chunks.
 }
 
 #[derive(Debug, Default, Clone)]
@@ -10,7 +10,7 @@ use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
   chunks_info_item::ChunksInfoItem,
-  utils::{get_violating_min_sizes, merge_sizes2, normalize_sizes},
+  utils::{get_violating_min_sizes, hash_filename, merge_sizes2, normalize_sizes},
   CacheGroup, CacheGroupOptions, CacheGroupSource, ChunkFilterFn, ChunkType, NormalizedOptions,
   SizeType, SplitChunksNameFn,
 };
@@ -96,10 +96,17 @@ pub fn create_cache_group(
         .then_some(1)
         .unwrap_or(options.max_initial_requests)
     }),
-    get_name: group_source
-      .get_name
-      .clone()
-      .unwrap_or_else(|| options.get_name.clone()),
+    get_name: {
+      let get_name = group_source
+        .get_name
+        .clone()
+        .unwrap_or_else(|| options.get_name.clone());
+      if options.hide_path_info {
+        Arc::new(move |module| get_name(module).map(|name| hash_filename(&name)))
+      } else {
+        get_name
+      }
+    },
     // used_exports: group_source.used_exports,
     automatic_name_delimiter: group_source.automatic_name_delimiter.clone(),
     filename: group_source
@@ -5,7 +5,7 @@
 
 use std::{fmt::Debug, sync::Arc};
 
-use rspack_core::{Chunk, ChunkGroupByUkey, ChunkUkey, Compilation, Module, Plugin};
+use rspack_core::{Chunk, ChunkGroupByUkey, ChunkUkey, Compilation, Filename, Module, Plugin};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
 use crate::{
@@ -50,8 +50,6 @@ impl SplitChunksPlugin {
     let max_initial_size = normalize_sizes(options.max_initial_size, &default_size_types);
     let min_remaining_size = normalize_sizes(options.min_remaining_size, &default_size_types);
 
-    let name = options.name.clone().unwrap_or_else(|| Arc::new(|_| None));
-
     let normalized_options = NormalizedOptions {
       chunk_filter: {
         let chunks = options.chunks;
@@ -80,6 +78,7 @@ impl SplitChunksPlugin {
       max_async_requests: options.min_chunks.unwrap_or(1),
       max_initial_requests: options.min_chunks.unwrap_or(1),
       filename: options.filename.clone(),
+      hide_path_info: options.hide_path_info.unwrap_or_default(),
       get_name: options.name.clone().unwrap_or_else(|| Arc::new(|_| None)),
       fallback_cache_group: NormalizedFallbackCacheGroup {
         chunks_filter: normalize_chunks_filter(
@@ -174,7 +173,10 @@ impl SplitChunksPlugin {
             .r#type
             .as_ref()
             .map_or(true, |ty| ty == module.module_type())
-        // TODO: check module layer
+          && group_option
+            .layer
+            .as_ref()
+            .map_or(true, |layer| (layer)(module.get_layer()))
       })
       // TODO: Supports filter with module type
       .map(|(key, _group_option)| key.clone())
@@ -852,11 +854,19 @@ impl Plugin for SplitChunksPlugin {
         self.link_module_new_chunk_and_remove_in_old_chunks(
           is_reused_with_all_modules,
           &item,
-          new_chunk.ukey,
+          new_chunk_ukey,
           &used_chunks,
           compilation,
         );
 
+        if let Some(filename) = &item_cache_group.filename {
+          let new_chunk = compilation
+            .chunk_by_ukey
+            .get_mut(&new_chunk_ukey)
+            .expect("Chunk should exist");
+          new_chunk.filename_template = Some(Filename::from(filename.clone()));
+        }
+
         let mut max_size_queue_map: HashMap<ChunkUkey, MaxSizeQueueItem> = Default::default();
 
         if !item
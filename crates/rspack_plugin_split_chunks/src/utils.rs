@@ -3,6 +3,7 @@ use std::{cmp::Ordering, sync::Arc};
 use rspack_core::{Chunk, ChunkGroupByUkey, ModuleIdentifier, SourceType};
 use rspack_util::comparators::compare_ids;
 use rustc_hash::FxHashMap as HashMap;
+use xxhash_rust::xxh3::xxh3_64;
 
 use crate::{
   CacheGroupByKey, ChunkFilterFn, ChunkType, ChunksInfoItem, SizeType, SplitChunkSizes,
@@ -202,3 +203,9 @@ pub(crate) fn merge_sizes(sizes: Vec<SplitChunksSizes>) -> SplitChunkSizes {
 pub(crate) fn normalize_chunks_filter(chunk_type: ChunkType) -> ChunkFilterFn {
   Arc::new(move |chunk, chunk_group_by_ukey| chunk_type.is_selected(chunk, chunk_group_by_ukey))
 }
+
+/// Align with `hashFilename` of webpack, used to hide path info in chunk names.
+pub(crate) fn hash_filename(name: &str) -> String {
+  let hash = format!("{:016x}", xxh3_64(name.as_bytes()));
+  hash[..8].to_string()
+}
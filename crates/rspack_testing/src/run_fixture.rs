@@ -28,7 +28,9 @@ pub async fn test_fixture(fixture_path: &Path) -> Compiler<AsyncNativeFileSystem
 
   let (mut options, plugins) = apply_from_fixture(fixture_path);
   for (_, entry) in options.entry.iter_mut() {
-    entry.runtime = Some("runtime".to_string());
+    if entry.depend_on.is_none() && entry.runtime.is_none() {
+      entry.runtime = Some("runtime".to_string());
+    }
   }
   // clean output
   if options.output.path.exists() {
@@ -30,6 +30,9 @@ fn default_entry() -> HashMap<String, EntryItem> {
     EntryItem {
       import: vec!["./index".to_string()],
       runtime: None,
+      layer: None,
+      depend_on: None,
+      async_chunks: None,
     },
   );
   map
@@ -54,6 +57,10 @@ fn default_optimization_module_ids() -> String {
   "named".to_string()
 }
 
+fn default_optimization_chunk_ids() -> String {
+  "named".to_string()
+}
+
 fn default_optimization_side_effects() -> String {
   "false".to_string()
 }
@@ -62,6 +69,10 @@ fn true_by_default() -> bool {
   true
 }
 
+fn default_stats_preset() -> String {
+  "none".to_string()
+}
+
 /// The configuration is used to configure the test in Rust.
 /// The structure should be closed to the webpack configuration.
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -85,6 +96,14 @@ pub struct TestConfig {
   pub devtool: String,
   #[serde(default)]
   pub experiments: Experiments,
+  #[serde(default)]
+  pub stats: Stats,
+  /// Relative to the fixture directory.
+  #[serde(default)]
+  pub records_input_path: Option<String>,
+  /// Relative to the fixture directory.
+  #[serde(default)]
+  pub records_output_path: Option<String>,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -93,6 +112,8 @@ pub struct Experiments {
   // True by default to reduce code in snapshots.
   #[serde(default = "true_by_default")]
   pub async_web_assembly: bool,
+  #[serde(default)]
+  pub layers: bool,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -103,16 +124,34 @@ pub struct Optimization {
   pub remove_available_modules: bool,
   #[serde(default = "default_optimization_module_ids")]
   pub module_ids: String,
+  #[serde(default = "default_optimization_chunk_ids")]
+  pub chunk_ids: String,
   #[serde(default = "default_optimization_side_effects")]
   pub side_effects: String,
 }
 
+#[derive(Debug, JsonSchema, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Stats {
+  /// One of the webpack presets, e.g. `normal`, nothing is in the stats by default.
+  #[serde(default = "default_stats_preset")]
+  pub preset: String,
+  #[serde(default)]
+  pub colors: bool,
+}
+
 #[derive(Debug, JsonSchema, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct EntryItem {
   pub import: Vec<String>,
   #[serde(default = "enable_runtime_by_default")]
   pub runtime: Option<String>,
+  #[serde(default)]
+  pub layer: Option<String>,
+  #[serde(default)]
+  pub depend_on: Option<Vec<String>>,
+  #[serde(default)]
+  pub async_chunks: Option<bool>,
 }
 
 #[derive(Debug, Default, JsonSchema, Deserialize)]
@@ -226,6 +265,12 @@ pub struct Output {
   pub css_filename: String,
   #[serde(default = "default_chunk_filename")]
   pub css_chunk_filename: String,
+  /// Output the chunks as ES modules.
+  #[serde(default)]
+  pub module: bool,
+  /// The `library.type`, only `module` and `system` are supported.
+  #[serde(default)]
+  pub library: Option<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -242,6 +287,7 @@ pub struct Rule {
   pub r#use: Vec<ModuleRuleUse>,
   pub side_effect: Option<bool>,
   pub r#type: Option<String>,
+  pub layer: Option<String>,
 }
 
 #[derive(Debug, JsonSchema, Deserialize)]
@@ -263,6 +309,7 @@ impl_serde_default!(Builtins);
 impl_serde_default!(EntryItem);
 impl_serde_default!(Module);
 impl_serde_default!(Optimization);
+impl_serde_default!(Stats);
 
 impl From<PresetEnv> for rspack_core::PresetEnv {
   fn from(preset_env: PresetEnv) -> Self {
@@ -331,6 +378,7 @@ impl TestConfig {
         r#type: rule
           .r#type
           .map(|i| ModuleType::try_from(i.as_str()).expect("should give a right module_type")),
+        layer: rule.layer,
         ..Default::default()
       }
     }));
@@ -344,6 +392,17 @@ impl TestConfig {
 
     assert!(context.is_absolute());
 
+    let target = c::Target::new(&self.target).expect("Can't construct target");
+    let chunk_loading = if self.output.module {
+      Some("import")
+    } else {
+      match target.platform {
+        TargetPlatform::Web => Some("jsonp"),
+        TargetPlatform::Node(_) => Some("require"),
+        _ => None,
+      }
+    };
+
     let options = CompilerOptions {
       context: c::Context::new(context.clone()),
       entry: self
@@ -354,12 +413,20 @@ impl TestConfig {
             k,
             c::EntryItem {
               import: v.import,
-              runtime: v
-                .runtime
-                .map(Some)
-                // Splitting runtime code into a separate chunk
-                // is friendly to snapshot testing.
-                .unwrap_or_else(|| Some("runtime".to_string())),
+              // Entries with `dependOn` use the runtime of the entries they depend on.
+              runtime: if v.depend_on.is_some() {
+                None
+              } else {
+                v.runtime
+                  .map(Some)
+                  // Splitting runtime code into a separate chunk
+                  // is friendly to snapshot testing.
+                  .unwrap_or_else(|| Some("runtime".to_string()))
+              },
+              layer: v.layer,
+              depend_on: v.depend_on,
+              async_chunks: v.async_chunks,
+              ..Default::default()
             },
           )
         })
@@ -375,17 +442,36 @@ impl TestConfig {
           .expect("Should exist"),
         public_path: c::PublicPath::String("/".to_string()),
         unique_name: "__rspack_test__".to_string(),
+        devtool_namespace: "__rspack_test__".to_string(),
+        devtool_module_filename_template: "webpack://[namespace]/[resource-path]".to_string(),
+        devtool_fallback_module_filename_template: "webpack://[namespace]/[resource-path]?[hash]"
+          .to_string(),
         path: context.join("dist"),
-        library: None,
-        enabled_library_types: None,
+        library: self
+          .output
+          .library
+          .clone()
+          .map(|library_type| c::LibraryOptions {
+            name: None,
+            export: None,
+            library_type,
+            umd_named_define: None,
+            auxiliary_comment: None,
+          }),
+        enabled_library_types: self.output.library.clone().map(|library| vec![library]),
+        chunk_loading: chunk_loading.map_or(c::ChunkLoading::Disable, |chunk_loading| {
+          c::ChunkLoading::Enable(chunk_loading.to_string())
+        }),
+        enabled_chunk_loading_types: chunk_loading
+          .map(|chunk_loading| vec![chunk_loading.to_string()]),
         strict_module_error_handling: false,
         global_object: "self".to_string(),
         import_function_name: "import".to_string(),
-        iife: true,
-        module: false,
+        iife: !self.output.module,
+        module: self.output.module,
       },
       mode: c::Mode::from(self.mode),
-      target: c::Target::new(&self.target).expect("Can't construct target"),
+      target,
       resolve: c::Resolve {
         extensions: Some(
           [
@@ -413,10 +499,23 @@ impl TestConfig {
         ..Default::default()
       },
       devtool: c::Devtool::from(self.devtool),
-      stats: Default::default(),
+      // The stats of the Rust tests are only printed by `Stats::to_string`.
+      stats: c::StatsOptions {
+        colors: self.stats.colors,
+        ..c::StatsOptions::from_printer_preset(
+          self
+            .stats
+            .preset
+            .parse()
+            .expect("should be a valid stats preset"),
+        )
+      },
       snapshot: Default::default(),
       cache: c::CacheOptions::Disabled,
-      experiments: Default::default(),
+      experiments: c::Experiments {
+        layers: self.experiments.layers,
+        ..Default::default()
+      },
       dev_server: Default::default(),
       node: c::NodeOption {
         dirname: "mock".to_string(),
@@ -426,7 +525,11 @@ impl TestConfig {
       optimization: c::Optimization {
         remove_available_modules: self.optimization.remove_available_modules,
         side_effects: c::SideEffectOption::from(self.optimization.side_effects.as_str()),
+        provided_exports: self.output.module,
       },
+      profile: false,
+      records_input_path: self.records_input_path.map(|path| context.join(path)),
+      records_output_path: self.records_output_path.map(|path| context.join(path)),
     };
     let mut plugins = Vec::new();
     if self.builtins.dev_friendly_split_chunks {
@@ -462,25 +565,41 @@ impl TestConfig {
       .boxed(),
     );
     plugins.push(rspack_plugin_json::JsonPlugin {}.boxed());
-    match &options.target.platform {
-      TargetPlatform::Web => {
-        plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
-      }
-      TargetPlatform::Node(_) => {
-        plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
-        plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
-      }
-      _ => {
-        plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
-      }
-    };
+    if options.output.module {
+      plugins.push(rspack_plugin_runtime::ModuleChunkFormatPlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+      plugins.push(rspack_plugin_runtime::ModuleChunkLoadingPlugin {}.boxed());
+    } else {
+      match &options.target.platform {
+        TargetPlatform::Web => {
+          plugins.push(rspack_plugin_runtime::ArrayPushCallbackChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::CssModulesPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::JsonpChunkLoadingPlugin {}.boxed());
+        }
+        TargetPlatform::Node(_) => {
+          plugins.push(rspack_plugin_runtime::CommonJsChunkFormatPlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+          plugins.push(rspack_plugin_runtime::CommonJsChunkLoadingPlugin {}.boxed());
+        }
+        _ => {
+          plugins.push(rspack_plugin_runtime::RuntimePlugin {}.boxed());
+        }
+      };
+    }
     if options.dev_server.hot {
       plugins.push(rspack_plugin_runtime::HotModuleReplacementPlugin {}.boxed());
     }
+    match options
+      .output
+      .library
+      .as_ref()
+      .map(|library| library.library_type.as_str())
+    {
+      Some("module") => plugins.push(rspack_plugin_library::ModuleLibraryPlugin::default().boxed()),
+      Some("system") => plugins.push(rspack_plugin_library::SystemLibraryPlugin::default().boxed()),
+      _ => {}
+    }
     plugins.push(rspack_plugin_runtime::BasicRuntimeRequirementPlugin {}.boxed());
     if options.experiments.lazy_compilation {
       plugins.push(rspack_plugin_runtime::LazyCompilationPlugin {}.boxed());
@@ -491,19 +610,33 @@ impl TestConfig {
       rspack_plugin_devtool::DevtoolPlugin::new(rspack_plugin_devtool::DevtoolPluginOptions {
         inline: options.devtool.inline(),
         append: !options.devtool.hidden(),
-        namespace: options.output.unique_name.clone(),
+        namespace: options.output.devtool_namespace.clone(),
+        module_filename_template: options.output.devtool_module_filename_template.clone(),
+        fallback_module_filename_template: options
+          .output
+          .devtool_fallback_module_filename_template
+          .clone(),
         columns: !options.devtool.cheap(),
         no_sources: options.devtool.no_sources(),
         public_path: None,
       })
       .boxed(),
     );
-    if self.optimization.module_ids == "named" {
-      plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed());
-    } else {
-      plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed());
+    match self.optimization.module_ids.as_str() {
+      "natural" => plugins.push(rspack_ids::NaturalModuleIdsPlugin::default().boxed()),
+      "named" => plugins.push(rspack_ids::NamedModuleIdsPlugin::default().boxed()),
+      "hashed" => plugins.push(rspack_ids::HashedModuleIdsPlugin::default().boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceModuleIdsPlugin::new(true).boxed()),
+      _ => plugins.push(rspack_ids::DeterministicModuleIdsPlugin::default().boxed()),
+    }
+    plugins.push(rspack_ids::RecordIdsPlugin::default().boxed());
+    match self.optimization.chunk_ids.as_str() {
+      "natural" => plugins.push(rspack_ids::NaturalChunkIdsPlugin::default().boxed()),
+      "named" => plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed()),
+      "size" => plugins.push(rspack_ids::OccurrenceChunkIdsPlugin::new(true).boxed()),
+      "total-size" => plugins.push(rspack_ids::OccurrenceChunkIdsPlugin::new(false).boxed()),
+      _ => plugins.push(rspack_ids::DeterministicChunkIdsPlugin::default().boxed()),
     }
-    plugins.push(rspack_ids::StableNamedChunkIdsPlugin::new(None, None).boxed());
     // Notice the plugin need to be placed after SplitChunksPlugin
     plugins.push(rspack_plugin_remove_empty_chunks::RemoveEmptyChunksPlugin.boxed());
 
@@ -1,5 +1,11 @@
-use std::sync::atomic::AtomicBool;
+mod profile;
 
+use std::{path::Path, sync::atomic::AtomicBool};
+
+pub use profile::{
+  HookSummary, PluginSummary, ProfileGuard, ProfileLayer, ProfileSummary, SUMMARY_FILENAME,
+  TRACE_FILENAME,
+};
 use tracing::Level;
 use tracing_chrome::FlushGuard;
 use tracing_subscriber::{fmt::format::FmtSpan, layer::Filter};
@@ -79,3 +85,39 @@ pub fn enable_tracing_by_env_with_chrome_layer() -> Option<FlushGuard> {
     None
   }
 }
+
+/// Profile the compilation, a chrome trace and a summary of the time taken by each plugin
+/// and each hook are written to the `output` directory.
+///
+/// The max trace level can still be set by `TRACE`, it's `TRACE` by default.
+pub fn enable_tracing_profile(output: impl AsRef<Path>) -> std::io::Result<Option<ProfileGuard>> {
+  if IS_TRACING_ENABLED.swap(true, std::sync::atomic::Ordering::SeqCst) {
+    return Ok(None);
+  }
+  use tracing_chrome::ChromeLayerBuilder;
+  use tracing_subscriber::{prelude::*, EnvFilter};
+
+  let output = output.as_ref().to_path_buf();
+  std::fs::create_dir_all(&output)?;
+  let (chrome_layer, chrome_guard) = ChromeLayerBuilder::new()
+    .include_args(true)
+    .file(output.join(TRACE_FILENAME))
+    .build();
+  let profile_layer = ProfileLayer::default();
+  tracing_subscriber::registry()
+    .with(chrome_layer.with_filter(FilterEvent {}))
+    .with(profile_layer.clone())
+    .with(
+      tracing_subscriber::filter::Targets::new().with_targets(vec![
+        ("rspack_core", Level::TRACE),
+        ("rspack", Level::TRACE),
+        ("rspack_node", Level::TRACE),
+        ("rspack_plugin_javascript", Level::TRACE),
+        ("rspack_plugin_split_chunks", Level::TRACE),
+        ("rspack_binding_options", Level::TRACE),
+      ]),
+    )
+    .with(EnvFilter::try_from_env("TRACE").unwrap_or_else(|_| EnvFilter::new("trace")))
+    .init();
+  Ok(Some(ProfileGuard::new(output, profile_layer, chrome_guard)))
+}
@@ -0,0 +1,266 @@
+use std::{
+  collections::HashMap,
+  fmt::Debug,
+  fs,
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+  time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use tracing::{
+  field::{Field, Visit},
+  span, Subscriber,
+};
+use tracing_chrome::FlushGuard;
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Name of the chrome trace file written in the profile output directory.
+pub const TRACE_FILENAME: &str = "trace.json";
+/// Name of the hook time summary written in the profile output directory.
+pub const SUMMARY_FILENAME: &str = "summary.json";
+
+#[derive(Debug, Clone, Default)]
+struct Timing {
+  calls: u64,
+  duration: Duration,
+}
+
+impl Timing {
+  fn add(&mut self, duration: Duration) {
+    self.calls += 1;
+    self.duration += duration;
+  }
+}
+
+#[derive(Debug, Default)]
+struct Timings {
+  /// Keyed by the hook, collected from the `plugin:<hook>` spans of `PluginDriver`.
+  hooks: HashMap<String, Timing>,
+  /// Keyed by the plugin and the hook, collected from the `plugin_hook` spans of `PluginDriver`.
+  plugins: HashMap<(String, String), Timing>,
+}
+
+#[derive(Debug, Clone)]
+enum SpanKey {
+  Hook(String),
+  Plugin { plugin: String, hook: String },
+}
+
+struct ProfiledSpan {
+  key: SpanKey,
+  start: Instant,
+}
+
+#[derive(Default)]
+struct PluginHookVisitor {
+  plugin: Option<String>,
+  hook: Option<String>,
+}
+
+impl Visit for PluginHookVisitor {
+  fn record_str(&mut self, field: &Field, value: &str) {
+    match field.name() {
+      "plugin" => self.plugin = Some(value.to_string()),
+      "hook" => self.hook = Some(value.to_string()),
+      _ => {}
+    }
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+    self.record_str(field, format!("{value:?}").trim_matches('"'));
+  }
+}
+
+/// Sum up the wall-clock time of the spans around the hooks of `PluginDriver`.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileLayer {
+  timings: Arc<Mutex<Timings>>,
+}
+
+impl<S> Layer<S> for ProfileLayer
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+{
+  fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+    let name = attrs.metadata().name();
+    let key = if let Some(hook) = name.strip_prefix("plugin:") {
+      SpanKey::Hook(hook.to_string())
+    } else if name == "plugin_hook" {
+      let mut visitor = PluginHookVisitor::default();
+      attrs.record(&mut visitor);
+      let (Some(plugin), Some(hook)) = (visitor.plugin, visitor.hook) else {
+        return;
+      };
+      SpanKey::Plugin { plugin, hook }
+    } else {
+      return;
+    };
+    if let Some(span) = ctx.span(id) {
+      span.extensions_mut().insert(ProfiledSpan {
+        key,
+        start: Instant::now(),
+      });
+    }
+  }
+
+  fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(&id) else {
+      return;
+    };
+    let Some(profiled) = span.extensions_mut().remove::<ProfiledSpan>() else {
+      return;
+    };
+    let duration = profiled.start.elapsed();
+    let mut timings = self.timings.lock().expect("should lock timings");
+    match profiled.key {
+      SpanKey::Hook(hook) => timings.hooks.entry(hook).or_default().add(duration),
+      SpanKey::Plugin { plugin, hook } => timings
+        .plugins
+        .entry((plugin, hook))
+        .or_default()
+        .add(duration),
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookSummary {
+  pub hook: String,
+  pub calls: u64,
+  /// Milliseconds.
+  pub duration: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginSummary {
+  pub plugin: String,
+  /// Milliseconds.
+  pub duration: f64,
+  pub hooks: Vec<HookSummary>,
+}
+
+/// Time taken by each hook and by each plugin, sorted by the duration in descending order.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileSummary {
+  pub hooks: Vec<HookSummary>,
+  pub plugins: Vec<PluginSummary>,
+}
+
+fn sort_by_duration<T>(items: &mut [T], duration: impl Fn(&T) -> f64) {
+  items.sort_by(|a, b| duration(b).total_cmp(&duration(a)));
+}
+
+impl ProfileLayer {
+  pub fn summary(&self) -> ProfileSummary {
+    let timings = self.timings.lock().expect("should lock timings");
+    let hook_summary = |hook: &str, timing: &Timing| HookSummary {
+      hook: hook.to_string(),
+      calls: timing.calls,
+      duration: timing.duration.as_secs_f64() * 1000.0,
+    };
+
+    let mut hooks = timings
+      .hooks
+      .iter()
+      .map(|(hook, timing)| hook_summary(hook, timing))
+      .collect::<Vec<_>>();
+    sort_by_duration(&mut hooks, |hook| hook.duration);
+
+    let mut plugins: HashMap<&str, Vec<HookSummary>> = HashMap::new();
+    for ((plugin, hook), timing) in &timings.plugins {
+      plugins
+        .entry(plugin)
+        .or_default()
+        .push(hook_summary(hook, timing));
+    }
+    let mut plugins = plugins
+      .into_iter()
+      .map(|(plugin, mut hooks)| {
+        sort_by_duration(&mut hooks, |hook| hook.duration);
+        PluginSummary {
+          plugin: plugin.to_string(),
+          duration: hooks.iter().map(|hook| hook.duration).sum(),
+          hooks,
+        }
+      })
+      .collect::<Vec<_>>();
+    sort_by_duration(&mut plugins, |plugin| plugin.duration);
+
+    ProfileSummary { hooks, plugins }
+  }
+}
+
+/// Keep it alive until the compilation finishes, the chrome trace and the summary are written
+/// when it's flushed or dropped.
+pub struct ProfileGuard {
+  output: PathBuf,
+  layer: ProfileLayer,
+  chrome_guard: FlushGuard,
+}
+
+impl ProfileGuard {
+  pub(crate) fn new(output: PathBuf, layer: ProfileLayer, chrome_guard: FlushGuard) -> Self {
+    Self {
+      output,
+      layer,
+      chrome_guard,
+    }
+  }
+
+  pub fn output(&self) -> &Path {
+    &self.output
+  }
+
+  pub fn flush(&self) -> std::io::Result<()> {
+    self.chrome_guard.flush();
+    self.write_summary()
+  }
+
+  fn write_summary(&self) -> std::io::Result<()> {
+    let summary = serde_json::to_vec_pretty(&self.layer.summary())?;
+    fs::write(self.output.join(SUMMARY_FILENAME), summary)
+  }
+}
+
+impl Drop for ProfileGuard {
+  fn drop(&mut self) {
+    // Profiling is a diagnostic, failing to write it should not fail the compilation.
+    if let Err(e) = self.write_summary() {
+      tracing::warn!("Failed to write the profile summary: {e}");
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use tracing_subscriber::prelude::*;
+
+  use super::*;
+
+  #[test]
+  fn summarize_plugin_hooks() {
+    let layer = ProfileLayer::default();
+    let subscriber = tracing_subscriber::registry().with(layer.clone());
+    tracing::subscriber::with_default(subscriber, || {
+      let _hook = tracing::trace_span!("plugin:emit").entered();
+      for plugin in ["a", "b", "a"] {
+        let _plugin = tracing::trace_span!("plugin_hook", plugin, hook = "emit").entered();
+      }
+    });
+    let summary = layer.summary();
+    assert_eq!(summary.hooks.len(), 1);
+    assert_eq!(summary.hooks[0].hook, "emit");
+    assert_eq!(summary.hooks[0].calls, 1);
+    let mut plugins = summary
+      .plugins
+      .iter()
+      .map(|plugin| (plugin.plugin.as_str(), plugin.hooks[0].calls))
+      .collect::<Vec<_>>();
+    plugins.sort_unstable();
+    assert_eq!(plugins, vec![("a", 2), ("b", 1)]);
+  }
+}
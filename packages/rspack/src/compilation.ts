@@ -27,6 +27,10 @@ import {
 	StatsValue
 } from "./config";
 import { createRawFromSource, createSourceFromRaw } from "./util/createSource";
+import {
+	interceptPluginHookTimings,
+	PluginHookTimings
+} from "./util/pluginHookTimings";
 import { ChunkGroup } from "./chunk_group";
 import { Compiler } from "./compiler";
 import ResolverFactory from "./ResolverFactory";
@@ -89,6 +93,10 @@ export class Compilation {
 	startTime?: number;
 	endTime?: number;
 	normalModuleFactory?: NormalModuleFactory;
+	/**
+	 * Time taken by the JS plugins in the hooks called by the native side.
+	 */
+	__internal_pluginHookTimings = new PluginHookTimings();
 
 	constructor(compiler: Compiler, inner: JsCompilation) {
 		this.name = undefined;
@@ -114,6 +122,23 @@ export class Compilation {
 		this.outputOptions = compiler.options.output;
 		this.logging = new Map();
 		this.#inner = inner;
+
+		const timedHooks = {
+			process_assets_stage_additional: processAssetsHooks.stageAdditional,
+			process_assets_stage_pre_process: processAssetsHooks.stagePreProcess,
+			process_assets_stage_none: processAssetsHooks.stageNone,
+			process_assets_stage_optimize_inline:
+				processAssetsHooks.stageOptimizeInline,
+			process_assets_stage_summarize: processAssetsHooks.stageSummarize,
+			process_assets_stage_report: processAssetsHooks.stageReport,
+			optimize_chunk_modules: this.hooks.optimizeChunkModules,
+			finish_modules: this.hooks.finishModules
+		};
+		for (const [name, hook] of Object.entries(timedHooks)) {
+			interceptPluginHookTimings(hook, (plugin, duration) =>
+				this.__internal_pluginHookTimings.record(plugin, name, duration)
+			);
+		}
 	}
 
 	get hash() {
@@ -238,6 +263,8 @@ export class Compilation {
 			options.builtAt,
 			!context.forToString
 		);
+		// Durations differ between builds, so they are not enabled by `all`.
+		options.pluginTimings = options.pluginTimings ?? false;
 
 		return options;
 	}
@@ -23,6 +23,7 @@ import ConcurrentCompilationError from "./error/ConcurrentCompilationError";
 import { getRawOptions } from "./config/adapter";
 import { createThreadsafeNodeFSFromRaw } from "./fileSystem";
 import { NormalModuleFactory } from "./normalModuleFactory";
+import { interceptPluginHookTimings } from "./util/pluginHookTimings";
 
 class EntryPlugin {
 	apply() {}
@@ -130,6 +131,22 @@ class Compiler {
 			afterResolvers: new tapable.SyncHook(["compiler"]),
 			make: new tapable.AsyncParallelHook(["compilation"])
 		};
+		const timedHooks = {
+			make: this.hooks.make,
+			emit: this.hooks.emit,
+			after_emit: this.hooks.afterEmit,
+			compilation: this.hooks.compilation,
+			this_compilation: this.hooks.thisCompilation
+		};
+		for (const [name, hook] of Object.entries(timedHooks)) {
+			interceptPluginHookTimings(hook, (plugin, duration) =>
+				this.compilation.__internal_pluginHookTimings.record(
+					plugin,
+					name,
+					duration
+				)
+			);
+		}
 		this.modifiedFiles = undefined;
 		this.removedFiles = undefined;
 		this.#disabledHooks = [];
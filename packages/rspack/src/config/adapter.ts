@@ -86,6 +86,9 @@ export const getRawOptions = (
 		},
 		experiments: getRawExperiments(options.experiments),
 		node: getRawNode(options.node),
+		profile: options.profile ?? false,
+		recordsInputPath: options.recordsInputPath || undefined,
+		recordsOutputPath: options.recordsOutputPath || undefined,
 		// TODO: refactor builtins
 		builtins: options.builtins as any
 	};
@@ -94,10 +97,18 @@ export const getRawOptions = (
 function getRawEntry(entry: EntryNormalized): RawOptions["entry"] {
 	const raw: RawOptions["entry"] = {};
 	for (const key of Object.keys(entry)) {
-		const runtime = entry[key].runtime;
+		const { runtime, chunkLoading, library } = entry[key];
 		raw[key] = {
 			import: entry[key].import!,
-			runtime: runtime === false ? undefined : runtime
+			runtime: runtime === false ? undefined : runtime,
+			layer: entry[key].layer ?? undefined,
+			dependOn: entry[key].dependOn,
+			filename: entry[key].filename,
+			library: library && getRawLibrary(library),
+			chunkLoading:
+				chunkLoading === false ? "false" : chunkLoading ?? undefined,
+			asyncChunks: entry[key].asyncChunks,
+			publicPath: entry[key].publicPath
 		};
 	}
 	return raw;
@@ -154,6 +165,11 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 			!isNil(output.cssFilename) &&
 			!isNil(output.cssChunkFilename) &&
 			!isNil(output.uniqueName) &&
+			!isNil(output.devtoolNamespace) &&
+			!isNil(output.devtoolModuleFilenameTemplate) &&
+			!isNil(output.devtoolFallbackModuleFilenameTemplate) &&
+			!isNil(output.chunkLoading) &&
+			!isNil(output.enabledChunkLoadingTypes) &&
 			!isNil(output.enabledLibraryTypes) &&
 			!isNil(output.strictModuleErrorHandling) &&
 			!isNil(output.globalObject) &&
@@ -173,6 +189,12 @@ function getRawOutput(output: OutputNormalized): RawOptions["output"] {
 		cssFilename: output.cssFilename,
 		cssChunkFilename: output.cssChunkFilename,
 		uniqueName: output.uniqueName,
+		devtoolNamespace: output.devtoolNamespace,
+		devtoolModuleFilenameTemplate: output.devtoolModuleFilenameTemplate,
+		devtoolFallbackModuleFilenameTemplate:
+			output.devtoolFallbackModuleFilenameTemplate,
+		chunkLoading: output.chunkLoading === false ? "false" : output.chunkLoading,
+		enabledChunkLoadingTypes: output.enabledChunkLoadingTypes,
 		enabledLibraryTypes: output.enabledLibraryTypes,
 		library: output.library && getRawLibrary(output.library),
 		strictModuleErrorHandling: output.strictModuleErrorHandling,
@@ -275,6 +297,10 @@ const getRawModuleRule = (
 		generator: rule.generator,
 		resolve: rule.resolve ? getRawResolve(rule.resolve) : undefined,
 		issuer: rule.issuer ? getRawRuleSetCondition(rule.issuer) : undefined,
+		issuerLayer: rule.issuerLayer
+			? getRawRuleSetCondition(rule.issuerLayer)
+			: undefined,
+		layer: rule.layer,
 		oneOf: rule.oneOf
 			? rule.oneOf.map(i => getRawModuleRule(i, options))
 			: undefined
@@ -337,6 +363,28 @@ function getRawExternals(externals: Externals): RawOptions["externals"] {
 			return { type: "string", stringPayload: item };
 		} else if (item instanceof RegExp) {
 			return { type: "regexp", regexpPayload: item.source };
+		} else if (typeof item === "function") {
+			return {
+				type: "function",
+				fnPayload: async ctx => {
+					const data = {
+						request: ctx.request,
+						context: ctx.context,
+						dependencyType: ctx.dependencyType,
+						contextInfo: { issuer: ctx.contextInfo.issuer }
+					};
+					const value = await new Promise<ExternalItemValue | undefined>(
+						(resolve, reject) => {
+							const promise = item(data, (err, result) => {
+								if (err) reject(err);
+								else resolve(result);
+							});
+							if (promise) promise.then(resolve, reject);
+						}
+					);
+					return value === undefined ? undefined : getRawExternalItemValue(value);
+				}
+			};
 		}
 		return {
 			type: "object",
@@ -352,8 +400,15 @@ function getRawExternals(externals: Externals): RawOptions["externals"] {
 			return { type: "string", stringPayload: value };
 		} else if (typeof value === "boolean") {
 			return { type: "bool", boolPayload: value };
+		} else if (Array.isArray(value)) {
+			return { type: "array", arrayPayload: value };
 		}
-		throw new Error("unreachable");
+		return {
+			type: "object",
+			objectPayload: Object.fromEntries(
+				Object.entries(value).map(([k, v]) => [k, Array.isArray(v) ? v : [v]])
+			)
+		};
 	}
 
 	if (Array.isArray(externals)) {
@@ -367,17 +422,23 @@ function getRawOptimization(
 ): RawOptions["optimization"] {
 	assert(
 		!isNil(optimization.moduleIds) &&
+			!isNil(optimization.chunkIds) &&
 			!isNil(optimization.removeAvailableModules) &&
-			!isNil(optimization.sideEffects),
-		"optimization.moduleIds, optimization.removeAvailableModules, optimization.sideEffects should not be nil after defaults"
+			!isNil(optimization.sideEffects) &&
+			!isNil(optimization.providedExports),
+		"optimization.moduleIds, optimization.chunkIds, optimization.removeAvailableModules, optimization.sideEffects, optimization.providedExports should not be nil after defaults"
 	);
 	return {
 		splitChunks: optimization.splitChunks
 			? getRawSplitChunksOptions(optimization.splitChunks)
 			: undefined,
 		moduleIds: optimization.moduleIds,
+		chunkIds: optimization.chunkIds,
+		hashedModuleIds: optimization.hashedModuleIds,
+		deterministicChunkIds: optimization.deterministicChunkIds,
 		removeAvailableModules: optimization.removeAvailableModules,
-		sideEffects: String(optimization.sideEffects)
+		sideEffects: String(optimization.sideEffects),
+		providedExports: optimization.providedExports
 	};
 }
 
@@ -393,13 +454,17 @@ function getRawSplitChunksOptions(
 							name: group.name,
 							priority: group.priority,
 							minChunks: group.minChunks,
-							chunks: group.chunks
+							chunks: group.chunks,
+							filename: group.filename,
+							layer: group.layer
 						};
 						return [key, normalizedGroup];
 					})
 			  )
 			: {},
 		chunks: sc.chunks,
+		filename: sc.filename,
+		hidePathInfo: sc.hidePathInfo,
 		maxAsyncRequests: sc.maxAsyncRequests,
 		maxInitialRequests: sc.maxInitialRequests,
 		minChunks: sc.minChunks,
@@ -412,8 +477,13 @@ function getRawSplitChunksOptions(
 function getRawSnapshotOptions(
 	snapshot: SnapshotOptions
 ): RawOptions["snapshot"] {
-	const { resolve, module } = snapshot;
-	assert(!isNil(resolve) && !isNil(module));
+	const { resolve, module, immutablePaths, managedPaths } = snapshot;
+	assert(
+		!isNil(resolve) &&
+			!isNil(module) &&
+			!isNil(immutablePaths) &&
+			!isNil(managedPaths)
+	);
 	const { timestamp: resolveTimestamp, hash: resolveHash } = resolve;
 	const { timestamp: moduleTimestamp, hash: moduleHash } = module;
 	assert(
@@ -430,23 +500,37 @@ function getRawSnapshotOptions(
 		module: {
 			timestamp: moduleTimestamp,
 			hash: moduleHash
-		}
+		},
+		immutablePaths: immutablePaths.map(getRawSnapshotPath),
+		managedPaths: managedPaths.map(getRawSnapshotPath)
 	};
 }
 
+function getRawSnapshotPath(
+	path: string | RegExp
+): RawOptions["snapshot"]["managedPaths"][number] {
+	if (path instanceof RegExp) {
+		return { type: "regexp", regexpPayload: path.source };
+	}
+	return { type: "string", stringPayload: path };
+}
+
 function getRawExperiments(
 	experiments: Experiments
 ): RawOptions["experiments"] {
-	const { lazyCompilation, incrementalRebuild, asyncWebAssembly } = experiments;
+	const { lazyCompilation, incrementalRebuild, asyncWebAssembly, layers } =
+		experiments;
 	assert(
 		!isNil(lazyCompilation) &&
 			!isNil(incrementalRebuild) &&
-			!isNil(asyncWebAssembly)
+			!isNil(asyncWebAssembly) &&
+			!isNil(layers)
 	);
 	return {
 		lazyCompilation,
 		incrementalRebuild,
-		asyncWebAssembly
+		asyncWebAssembly,
+		layers
 	};
 }
 
@@ -465,6 +549,27 @@ function getRawStats(stats: StatsValue): RawOptions["stats"] {
 	const statsOptions = normalizeStatsPreset(stats);
 	return {
 		colors: statsOptions.colors ?? false,
-		reasons: statsOptions.reasons ?? false
+		hash: statsOptions.hash ?? false,
+		timings: statsOptions.timings ?? false,
+		builtAt: statsOptions.builtAt ?? false,
+		pluginTimings: statsOptions.pluginTimings ?? false,
+		publicPath: statsOptions.publicPath ?? false,
+		outputPath: statsOptions.outputPath ?? false,
+		assets: statsOptions.assets ?? false,
+		chunks: statsOptions.chunks ?? false,
+		chunkModules: statsOptions.chunkModules ?? false,
+		chunkRelations: statsOptions.chunkRelations ?? false,
+		modules: statsOptions.modules ?? false,
+		reasons: statsOptions.reasons ?? false,
+		usedExports: statsOptions.usedExports ?? false,
+		providedExports: statsOptions.providedExports ?? false,
+		optimizationBailout: statsOptions.optimizationBailout ?? false,
+		// `"auto"` is passed as enabled.
+		entrypoints: !!statsOptions.entrypoints,
+		chunkGroups: statsOptions.chunkGroups ?? false,
+		errors: statsOptions.errors ?? false,
+		errorsCount: statsOptions.errorsCount ?? false,
+		warnings: statsOptions.warnings ?? false,
+		warningsCount: statsOptions.warningsCount ?? false
 	};
 }
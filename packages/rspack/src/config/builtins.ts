@@ -13,7 +13,9 @@ import type {
 	RawPresetEnv,
 	RawPluginImportConfig,
 	RawCssModulesConfig,
-	RawRelayConfig
+	RawRelayConfig,
+	RawCircularDependenciesPluginConfig,
+	RawDuplicatePackagesPluginConfig
 } from "@rspack/binding";
 import { loadConfig } from "browserslist";
 import { Optimization } from "..";
@@ -39,6 +41,32 @@ export type EmotionConfig =
 			importMap?: EmotionConfigImportMap;
 	  };
 
+export type CircularDependenciesConfig = {
+	/**
+	 * Only report cycles containing a module whose resource matches.
+	 */
+	include?: RegExp;
+	/**
+	 * Don't report cycles containing only modules whose resources match.
+	 */
+	exclude?: RegExp;
+	/**
+	 * Report cycles as errors instead of warnings.
+	 */
+	failOnError?: boolean;
+	/**
+	 * Ignore dynamic imports when looking for cycles.
+	 */
+	allowAsyncCycles?: boolean;
+};
+
+export type DuplicatePackagesConfig = {
+	/**
+	 * Don't report packages whose names match.
+	 */
+	exclude?: RegExp;
+};
+
 export type CssPluginConfig = {
 	modules?: Partial<RawCssModulesConfig>;
 };
@@ -58,6 +86,8 @@ export interface Builtins {
 	presetEnv?: Partial<RawBuiltins["presetEnv"]>;
 	polyfill?: boolean;
 	devFriendlySplitChunks?: boolean;
+	circularDependencies?: boolean | CircularDependenciesConfig;
+	duplicatePackages?: boolean | DuplicatePackagesConfig;
 	copy?: CopyConfig;
 	pluginImport?: PluginImportConfig[];
 	relay?: RelayConfig;
@@ -184,6 +214,41 @@ function resolveDecorator(
 	);
 }
 
+function resolveCircularDependencies(
+	circularDependencies: Builtins["circularDependencies"]
+): RawCircularDependenciesPluginConfig | undefined {
+	if (!circularDependencies) {
+		return undefined;
+	}
+
+	if (circularDependencies === true) {
+		circularDependencies = {};
+	}
+
+	return {
+		include: circularDependencies.include?.source,
+		exclude: circularDependencies.exclude?.source,
+		failOnError: circularDependencies.failOnError ?? false,
+		allowAsyncCycles: circularDependencies.allowAsyncCycles ?? false
+	};
+}
+
+function resolveDuplicatePackages(
+	duplicatePackages: Builtins["duplicatePackages"]
+): RawDuplicatePackagesPluginConfig | undefined {
+	if (!duplicatePackages) {
+		return undefined;
+	}
+
+	if (duplicatePackages === true) {
+		duplicatePackages = {};
+	}
+
+	return {
+		exclude: duplicatePackages.exclude?.source
+	};
+}
+
 function resolveProgress(
 	progress: Builtins["progress"]
 ): RawProgressPluginConfig | undefined {
@@ -333,6 +398,10 @@ export function resolveBuiltinsOptions(
 		minifyOptions: resolveMinifyOptions(builtins, optimization),
 		emotion: resolveEmotion(builtins.emotion, production),
 		devFriendlySplitChunks: builtins.devFriendlySplitChunks ?? false,
+		circularDependencies: resolveCircularDependencies(
+			builtins.circularDependencies
+		),
+		duplicatePackages: resolveDuplicatePackages(builtins.duplicatePackages),
 		copy: resolveCopy(builtins.copy),
 		pluginImport: resolvePluginImport(builtins.pluginImport),
 		relay: builtins.relay
@@ -17,6 +17,8 @@ import {
 } from "./target";
 import type {
 	Context,
+	EntryDescriptionNormalized,
+	EntryNormalized,
 	Experiments,
 	ExternalsPresets,
 	InfrastructureLogging,
@@ -64,6 +66,9 @@ export const applyRspackOptionsDefaults = (
 
 	F(options, "devtool", () => false as const);
 	D(options, "watch", false);
+	D(options, "profile", false);
+	D(options, "recordsInputPath", false);
+	D(options, "recordsOutputPath", false);
 
 	applyExperimentsDefaults(options.experiments);
 
@@ -78,7 +83,8 @@ export const applyRspackOptionsDefaults = (
 
 	applyOutputDefaults(options.output, {
 		context: options.context!,
-		targetProperties
+		targetProperties,
+		entry: options.entry
 	});
 
 	applyExternalsPresetsDefaults(options.externalsPresets, {
@@ -96,7 +102,11 @@ export const applyRspackOptionsDefaults = (
 
 	applyNodeDefaults(options.node, { targetProperties });
 
-	applyOptimizationDefaults(options.optimization, { production, development });
+	applyOptimizationDefaults(options.optimization, {
+		production,
+		development,
+		outputModule: options.output.module
+	});
 
 	options.resolve = cleverMerge(
 		getResolveDefaults({
@@ -137,6 +147,7 @@ const applyExperimentsDefaults = (experiments: Experiments) => {
 	D(experiments, "incrementalRebuild", true);
 	D(experiments, "lazyCompilation", false);
 	D(experiments, "asyncWebAssembly", false);
+	D(experiments, "layers", false);
 };
 
 const applySnapshotDefaults = (
@@ -153,6 +164,8 @@ const applySnapshotDefaults = (
 			? { timestamp: true, hash: true }
 			: { timestamp: true, hash: false }
 	);
+	F(snapshot, "immutablePaths", () => []);
+	F(snapshot, "managedPaths", () => [/^(.+?[\\/]node_modules[\\/])/]);
 };
 
 const applyModuleDefaults = (
@@ -262,7 +275,11 @@ const applyModuleDefaults = (
 
 const applyOutputDefaults = (
 	output: OutputNormalized,
-	{ context, targetProperties: tp }: { context: Context; targetProperties: any }
+	{
+		context,
+		targetProperties: tp,
+		entry
+	}: { context: Context; targetProperties: any; entry: EntryNormalized }
 ) => {
 	F(output, "uniqueName", () => {
 		const pkgPath = path.resolve(context, "package.json");
@@ -278,6 +295,23 @@ const applyOutputDefaults = (
 		}
 	});
 
+	const forEachEntry = (fn: (desc: EntryDescriptionNormalized) => void) => {
+		for (const name of Object.keys(entry)) {
+			fn(entry[name]);
+		}
+	};
+
+	F(output, "devtoolNamespace", () => output.uniqueName!);
+	D(
+		output,
+		"devtoolModuleFilenameTemplate",
+		"webpack://[namespace]/[resource-path]"
+	);
+	D(
+		output,
+		"devtoolFallbackModuleFilenameTemplate",
+		"webpack://[namespace]/[resource-path]?[hash]"
+	);
 	D(output, "filename", "[name].js");
 	F(output, "chunkFilename", () => {
 		const filename = output.filename!;
@@ -335,7 +369,11 @@ const applyOutputDefaults = (
 		if (output.library) {
 			enabledLibraryTypes.push(output.library.type);
 		}
-		// TODO respect entryOptions.library
+		forEachEntry(desc => {
+			if (desc.library && !enabledLibraryTypes.includes(desc.library.type)) {
+				enabledLibraryTypes.push(desc.library.type);
+			}
+		});
 		return enabledLibraryTypes;
 	});
 	// A(output, "enabledWasmLoadingTypes", () => {
@@ -359,6 +397,29 @@ const applyOutputDefaults = (
 	D(output, "importFunctionName", "import");
 	F(output, "iife", () => !output.module);
 	F(output, "module", () => false); // TODO experiments.outputModule
+	F(output, "chunkLoading", () => {
+		if (output.module) return "import";
+		if (tp) {
+			if (tp.document) return "jsonp";
+			if (tp.require) return "require";
+		}
+		return false;
+	});
+	A(output, "enabledChunkLoadingTypes", () => {
+		const enabledChunkLoadingTypes = [];
+		if (output.chunkLoading) {
+			enabledChunkLoadingTypes.push(output.chunkLoading);
+		}
+		forEachEntry(desc => {
+			if (
+				desc.chunkLoading &&
+				!enabledChunkLoadingTypes.includes(desc.chunkLoading)
+			) {
+				enabledChunkLoadingTypes.push(desc.chunkLoading);
+			}
+		});
+		return enabledChunkLoadingTypes;
+	});
 };
 
 const applyExternalsPresetsDefaults = (
@@ -388,13 +449,23 @@ const applyNodeDefaults = (
 
 const applyOptimizationDefaults = (
 	optimization: Optimization,
-	{ production, development }: { production: boolean; development: boolean }
+	{
+		production,
+		development,
+		outputModule
+	}: { production: boolean; development: boolean; outputModule?: boolean }
 ) => {
 	D(optimization, "removeAvailableModules", true);
+	// The analysis is costly, only the module library of the ESM output needs it now
+	D(optimization, "providedExports", !!outputModule);
 	F(optimization, "moduleIds", () => {
 		if (production) return "deterministic";
 		return "named";
 	});
+	F(optimization, "chunkIds", () => {
+		if (production) return "deterministic";
+		return "named";
+	});
 	F(optimization, "sideEffects", () => (production ? true : "flag"));
 	D(optimization, "runtimeChunk", false);
 	D(optimization, "minimize", production);
@@ -404,7 +475,7 @@ const applyOptimizationDefaults = (
 		// A(splitChunks, "defaultSizeTypes", () =>
 		// 	css ? ["javascript", "css", "unknown"] : ["javascript", "unknown"]
 		// );
-		// D(splitChunks, "hidePathInfo", production);
+		D(splitChunks, "hidePathInfo", production);
 		D(splitChunks, "chunks", "async");
 		// D(splitChunks, "usedExports", optimization.usedExports === true);
 		D(splitChunks, "minChunks", 1);
@@ -54,6 +54,14 @@ export const getNormalizedRspackOptions = (
 				assetModuleFilename: output.assetModuleFilename,
 				webassemblyModuleFilename: output.webassemblyModuleFilename,
 				uniqueName: output.uniqueName,
+				devtoolNamespace: output.devtoolNamespace,
+				devtoolModuleFilenameTemplate: output.devtoolModuleFilenameTemplate,
+				devtoolFallbackModuleFilenameTemplate:
+					output.devtoolFallbackModuleFilenameTemplate,
+				chunkLoading: output.chunkLoading,
+				enabledChunkLoadingTypes: output.enabledChunkLoadingTypes
+					? [...output.enabledChunkLoadingTypes]
+					: ["..."],
 				enabledLibraryTypes: output.enabledLibraryTypes
 					? [...output.enabledLibraryTypes]
 					: ["..."],
@@ -115,7 +123,9 @@ export const getNormalizedRspackOptions = (
 			module: optionalNestedConfig(snapshot.module, module => ({
 				timestamp: module.timestamp,
 				hash: module.hash
-			}))
+			})),
+			immutablePaths: optionalNestedArray(snapshot.immutablePaths, p => [...p]),
+			managedPaths: optionalNestedArray(snapshot.managedPaths, p => [...p])
 		})),
 		cache: optionalNestedConfig(config.cache, cache => cache),
 		stats: nestedConfig(config.stats, stats => {
@@ -158,6 +168,15 @@ export const getNormalizedRspackOptions = (
 		experiments: nestedConfig(config.experiments, experiments => ({
 			...experiments
 		})),
+		profile: config.profile,
+		recordsInputPath:
+			config.recordsInputPath !== undefined
+				? config.recordsInputPath
+				: config.recordsPath,
+		recordsOutputPath:
+			config.recordsOutputPath !== undefined
+				? config.recordsOutputPath
+				: config.recordsPath,
 		watch: config.watch,
 		watchOptions: cloneObject(config.watchOptions),
 		devServer: config.devServer,
@@ -196,7 +215,17 @@ const getNormalizedEntryStatic = (entry: EntryStatic) => {
 		} else {
 			result[key] = {
 				import: Array.isArray(value.import) ? value.import : [value.import],
-				runtime: value.runtime
+				runtime: value.runtime,
+				layer: value.layer,
+				dependOn:
+					typeof value.dependOn === "string"
+						? [value.dependOn]
+						: value.dependOn,
+				filename: value.filename,
+				library: value.library,
+				chunkLoading: value.chunkLoading,
+				asyncChunks: value.asyncChunks,
+				publicPath: value.publicPath
 			};
 		}
 	}
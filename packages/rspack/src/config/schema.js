@@ -62,6 +62,30 @@ module.exports = {
 				}
 			]
 		},
+		ChunkLoading: {
+			description:
+				"The method of loading chunks (methods included by default are 'jsonp' (web), 'import' (ESM), 'importScripts' (WebWorker), 'require' (sync node.js), 'async-node' (async node.js), but others might be added by plugins).",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					$ref: "#/definitions/ChunkLoadingType"
+				}
+			]
+		},
+		ChunkLoadingType: {
+			description:
+				"The method of loading chunks (methods included by default are 'jsonp' (web), 'import' (ESM), 'importScripts' (WebWorker), 'require' (sync node.js), 'async-node' (async node.js), but others might be added by plugins).",
+			anyOf: [
+				{
+					enum: ["jsonp", "import-scripts", "require", "async-node", "import"]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
 		Context: {
 			description:
 				"The base directory (absolute path!) for resolving the `entry` option. If `output.pathinfo` is set, the included pathinfo is shortened to this directory.",
@@ -139,7 +163,7 @@ module.exports = {
 				"A developer tool to enhance debugging (false | eval | [inline-|hidden-|eval-][nosources-][cheap-[module-]]source-map).",
 			anyOf: [
 				{
-					enum: [false]
+					enum: [false, "eval"]
 				},
 				{
 					type: "string",
@@ -148,6 +172,29 @@ module.exports = {
 				}
 			]
 		},
+		DevtoolFallbackModuleFilenameTemplate: {
+			description:
+				"Similar to `output.devtoolModuleFilenameTemplate`, but used in the case of duplicate module identifiers.",
+			type: "string"
+		},
+		DevtoolModuleFilenameTemplate: {
+			description:
+				"Filename template string for the sources array in a generated SourceMap.",
+			type: "string"
+		},
+		DevtoolNamespace: {
+			description:
+				"Module namespace to use when interpolating filename template string for the sources array in a generated SourceMap. Defaults to `output.uniqueName` if not set. It's useful for avoiding runtime collisions in sourcemaps from multiple projects built as libraries.",
+			type: "string"
+		},
+		EnabledChunkLoadingTypes: {
+			description:
+				"List of chunk loading types enabled for use by entry points.",
+			type: "array",
+			items: {
+				$ref: "#/definitions/ChunkLoadingType"
+			}
+		},
 		EnabledLibraryTypes: {
 			description: "List of library types enabled for use by entry points.",
 			type: "array",
@@ -168,9 +215,53 @@ module.exports = {
 			type: "object",
 			additionalProperties: false,
 			properties: {
+				asyncChunks: {
+					description:
+						"Enable/disable creating async chunks that are loaded on demand.",
+					type: "boolean"
+				},
+				chunkLoading: {
+					$ref: "#/definitions/ChunkLoading"
+				},
+				dependOn: {
+					description:
+						"The entrypoints that the current entrypoint depend on. They must be loaded when this entrypoint is loaded.",
+					anyOf: [
+						{
+							description:
+								"The entrypoints that the current entrypoint depend on. They must be loaded when this entrypoint is loaded.",
+							type: "array",
+							items: {
+								description: "An entrypoint that the current entrypoint depend on.",
+								type: "string",
+								minLength: 1
+							},
+							minItems: 1,
+							uniqueItems: true
+						},
+						{
+							description:
+								"An entrypoint that the current entrypoint depend on. It must be loaded when this entrypoint is loaded.",
+							type: "string",
+							minLength: 1
+						}
+					]
+				},
+				filename: {
+					$ref: "#/definitions/EntryFilename"
+				},
 				import: {
 					$ref: "#/definitions/EntryItem"
 				},
+				layer: {
+					$ref: "#/definitions/Layer"
+				},
+				library: {
+					$ref: "#/definitions/LibraryOptions"
+				},
+				publicPath: {
+					$ref: "#/definitions/PublicPath"
+				},
 				runtime: {
 					$ref: "#/definitions/EntryRuntime"
 				},
@@ -275,6 +366,10 @@ module.exports = {
 					description: "Rebuild incrementally",
 					type: "boolean"
 				},
+				layers: {
+					description: "Enable module layers.",
+					type: "boolean"
+				},
 				lazyCompilation: {
 					description:
 						"Compile entrypoints and import()s only when they are accessed.",
@@ -306,12 +401,27 @@ module.exports = {
 					additionalProperties: {
 						$ref: "#/definitions/ExternalItemValue"
 					}
+				},
+				{
+					description:
+						"The function is called on each dependency (`function(data, callback)` or `async function(data)`), the result value is used as dependency, `undefined` means not external.",
+					instanceof: "Function"
 				}
 			]
 		},
 		ExternalItemValue: {
 			description: "The dependency used for the external.",
 			anyOf: [
+				{
+					description: "The target of the external.",
+					type: "array",
+					minItems: 1,
+					items: {
+						description: "The target of the external.",
+						type: "string",
+						minLength: 1
+					}
+				},
 				{
 					description: "The target of the external.",
 					type: "string"
@@ -320,6 +430,27 @@ module.exports = {
 					description:
 						"`true`: The dependency name is used as target of the external.",
 					type: "boolean"
+				},
+				{
+					description:
+						"The target of the external for each library type, e.g. `{ root, commonjs, commonjs2, amd }`.",
+					type: "object",
+					additionalProperties: {
+						anyOf: [
+							{
+								type: "array",
+								minItems: 1,
+								items: {
+									type: "string",
+									minLength: 1
+								}
+							},
+							{
+								type: "string",
+								minLength: 1
+							}
+						]
+					}
 				}
 			]
 		},
@@ -483,6 +614,19 @@ module.exports = {
 				}
 			}
 		},
+		Layer: {
+			description:
+				"Specifies the layer in which modules of this entrypoint are placed.",
+			anyOf: [
+				{
+					enum: [null]
+				},
+				{
+					type: "string",
+					minLength: 1
+				}
+			]
+		},
 		Library: {
 			description:
 				"Make the output files a library, exporting the exports of the entry point.",
@@ -726,7 +870,13 @@ module.exports = {
 				chunkIds: {
 					description:
 						"Define the algorithm to choose chunk ids (named: readable ids for better debugging, deterministic: numeric hash ids for better long term caching, size: numeric ids focused on minimal initial download size, total-size: numeric ids focused on minimal total download size, false: no algorithm used, as custom one can be provided via plugin).",
-					enum: ["named", "deterministic"]
+					enum: ["natural", "named", "deterministic", "size", "total-size"]
+				},
+				deterministicChunkIds: {
+					$ref: "#/definitions/OptimizationDeterministicChunkIdsOptions"
+				},
+				hashedModuleIds: {
+					$ref: "#/definitions/OptimizationHashedModuleIdsOptions"
 				},
 				minimize: {
 					description:
@@ -754,7 +904,12 @@ module.exports = {
 				moduleIds: {
 					description:
 						"Define the algorithm to choose module ids (natural: numeric ids in order of usage, named: readable ids for better debugging, hashed: (deprecated) short hashes as ids for better long term caching, deterministic: numeric hash ids for better long term caching, size: numeric ids focused on minimal initial download size, false: no algorithm used, as custom one can be provided via plugin).",
-					enum: ["named", "deterministic"]
+					enum: ["natural", "named", "hashed", "deterministic", "size"]
+				},
+				providedExports: {
+					description:
+						"Figure out which exports are provided by modules to generate more efficient code.",
+					type: "boolean"
 				},
 				removeAvailableModules: {
 					description:
@@ -790,6 +945,50 @@ module.exports = {
 				}
 			}
 		},
+		OptimizationDeterministicChunkIdsOptions: {
+			description:
+				"Options for the deterministic chunk ids, used when 'optimization.chunkIds' is 'deterministic'.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				context: {
+					description:
+						"The context directory for creating names, defaults to 'context'.",
+					type: "string"
+				},
+				maxLength: {
+					description: "The maximum length of the chunk ids.",
+					type: "number",
+					minimum: 1
+				}
+			}
+		},
+		OptimizationHashedModuleIdsOptions: {
+			description:
+				"Options for the hashed module ids, used when 'optimization.moduleIds' is 'hashed'.",
+			type: "object",
+			additionalProperties: false,
+			properties: {
+				context: {
+					description:
+						"The context directory for creating names, defaults to 'context'.",
+					type: "string"
+				},
+				hashDigest: {
+					description: "The encoding to use when generating the hash.",
+					enum: ["hex", "base64"]
+				},
+				hashDigestLength: {
+					description: "The prefix length of the hash digest to use.",
+					type: "number",
+					minimum: 1
+				},
+				hashFunction: {
+					description: "The hashing algorithm to use.",
+					enum: ["md4", "xxhash64", "sha256", "sha384", "sha512"]
+				}
+			}
+		},
 		OptimizationRuntimeChunk: {
 			description:
 				"Create an additional chunk which contains only the rspack runtime and chunk hash maps.",
@@ -837,6 +1036,16 @@ module.exports = {
 						}
 					]
 				},
+				filename: {
+					description:
+						"Sets the template for the filename for created chunks.",
+					type: "string",
+					minLength: 1
+				},
+				layer: {
+					description: "Assign modules to a cache group by module layer.",
+					type: "string"
+				},
 				minChunks: {
 					description:
 						"Minimum number of times a module has to be duplicated until it's considered for splitting.",
@@ -913,6 +1122,17 @@ module.exports = {
 						}
 					]
 				},
+				filename: {
+					description:
+						"Sets the template for the filename for created chunks.",
+					type: "string",
+					minLength: 1
+				},
+				hidePathInfo: {
+					description:
+						"Prevents exposing path info when creating names for split chunks.",
+					type: "boolean"
+				},
 				maxAsyncRequests: {
 					description:
 						"Maximum number of requests which are accepted for on-demand loading.",
@@ -993,6 +1213,15 @@ module.exports = {
 				cssFilename: {
 					$ref: "#/definitions/CssFilename"
 				},
+				devtoolFallbackModuleFilenameTemplate: {
+					$ref: "#/definitions/DevtoolFallbackModuleFilenameTemplate"
+				},
+				devtoolModuleFilenameTemplate: {
+					$ref: "#/definitions/DevtoolModuleFilenameTemplate"
+				},
+				devtoolNamespace: {
+					$ref: "#/definitions/DevtoolNamespace"
+				},
 				enabledWasmLoadingTypes: {
 					$ref: "#/definitions/EnabledWasmLoadingTypes"
 				},
@@ -1002,6 +1231,12 @@ module.exports = {
 				webassemblyModuleFilename: {
 					$ref: "#/definitions/WebassemblyModuleFilename"
 				},
+				chunkLoading: {
+					$ref: "#/definitions/ChunkLoading"
+				},
+				enabledChunkLoadingTypes: {
+					$ref: "#/definitions/EnabledChunkLoadingTypes"
+				},
 				enabledLibraryTypes: {
 					$ref: "#/definitions/EnabledLibraryTypes"
 				},
@@ -1092,6 +1327,10 @@ module.exports = {
 				]
 			}
 		},
+		Profile: {
+			description: "Capture timing information for each module.",
+			type: "boolean"
+		},
 		PublicPath: {
 			description:
 				"The 'publicPath' specifies the public URL address of the output files when referenced in a browser.",
@@ -1384,6 +1623,19 @@ module.exports = {
 						}
 					]
 				},
+				issuerLayer: {
+					description: "Match layer of the issuer of this module.",
+					oneOf: [
+						{
+							$ref: "#/definitions/RuleSetConditionOrConditions"
+						}
+					]
+				},
+				layer: {
+					description:
+						"Specifies the layer in which the module should be placed in.",
+					type: "string"
+				},
 				oneOf: {
 					description: "Only execute the first matching rule in this array.",
 					type: "array",
@@ -1543,6 +1795,46 @@ module.exports = {
 			type: "object",
 			additionalProperties: false,
 			properties: {
+				immutablePaths: {
+					description:
+						"List of paths that are managed by a package manager and contain a version or hash in its path so all files are immutable.",
+					type: "array",
+					items: {
+						anyOf: [
+							{
+								description:
+									"A RegExp matching an immutable directory (usually a package manager cache directory, including the tailing slash)",
+								instanceof: "RegExp"
+							},
+							{
+								description:
+									"A path to an immutable directory (usually a package manager cache directory).",
+								type: "string",
+								minLength: 1
+							}
+						]
+					}
+				},
+				managedPaths: {
+					description:
+						"List of paths that are managed by a package manager and can be trusted to not be modified otherwise.",
+					type: "array",
+					items: {
+						anyOf: [
+							{
+								description:
+									"A RegExp matching a managed directory (usually a node_modules directory, including the tailing slash)",
+								instanceof: "RegExp"
+							},
+							{
+								description:
+									"A path to a managed directory (usually a node_modules directory).",
+								type: "string",
+								minLength: 1
+							}
+						]
+					}
+				},
 				module: {
 					description:
 						"Options for snapshotting dependencies of modules to determine if they need to be built again.",
@@ -1684,6 +1976,24 @@ module.exports = {
 				builtAt: {
 					description: "Add built at time information.",
 					type: "boolean"
+				},
+				pluginTimings: {
+					description:
+						"Add the time taken by each plugin in each hook of the compilation.",
+					type: "boolean"
+				},
+				usedExports: {
+					description: "Show which exports of a module are used.",
+					type: "boolean"
+				},
+				providedExports: {
+					description: "Show the exports of the modules.",
+					type: "boolean"
+				},
+				optimizationBailout: {
+					description:
+						"Show reasons why optimization bailed out for modules.",
+					type: "boolean"
 				}
 			}
 		},
@@ -1701,6 +2011,40 @@ module.exports = {
 				}
 			]
 		},
+		RecordsInputPath: {
+			description: "Load compiler state from a json file.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
+		RecordsOutputPath: {
+			description: "Store compiler state to a json file.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
+		RecordsPath: {
+			description:
+				"Store/Load compiler state from/to a json file. This will result in persistent ids of modules and chunks. An absolute path is expected. `recordsPath` is used for `recordsInputPath` and `recordsOutputPath` if they left undefined.",
+			anyOf: [
+				{
+					enum: [false]
+				},
+				{
+					type: "string"
+				}
+			]
+		},
 		StrictModuleErrorHandling: {
 			description:
 				"Handles error in module loading correctly at a performance cost. This will handle module error compatible with the EcmaScript Modules spec.",
@@ -1877,6 +2221,18 @@ module.exports = {
 		plugins: {
 			$ref: "#/definitions/Plugins"
 		},
+		profile: {
+			$ref: "#/definitions/Profile"
+		},
+		recordsInputPath: {
+			$ref: "#/definitions/RecordsInputPath"
+		},
+		recordsOutputPath: {
+			$ref: "#/definitions/RecordsOutputPath"
+		},
+		recordsPath: {
+			$ref: "#/definitions/RecordsPath"
+		},
 		resolve: {
 			$ref: "#/definitions/Resolve"
 		},
@@ -39,6 +39,10 @@ export interface RspackOptions {
 	optimization?: Optimization;
 	plugins?: Plugins;
 	experiments?: Experiments;
+	profile?: Profile;
+	recordsPath?: RecordsPath;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 	watch?: Watch;
 	watchOptions?: WatchOptions;
 	devServer?: DevServer;
@@ -67,6 +71,9 @@ export interface RspackOptionsNormalized {
 	optimization: Optimization;
 	plugins: Plugins;
 	experiments: Experiments;
+	profile?: Profile;
+	recordsInputPath?: RecordsInputPath;
+	recordsOutputPath?: RecordsOutputPath;
 	watch?: Watch;
 	watchOptions: WatchOptions;
 	devServer?: DevServer;
@@ -79,6 +86,14 @@ export type Name = string;
 ///// Dependencies /////
 export type Dependencies = Name[];
 
+///// Profile /////
+export type Profile = boolean;
+
+///// Records /////
+export type RecordsPath = false | string;
+export type RecordsInputPath = false | string;
+export type RecordsOutputPath = false | string;
+
 ///// Context /////
 export type Context = string;
 
@@ -97,7 +112,20 @@ export interface EntryObject {
 export interface EntryDescription {
 	import: EntryItem;
 	runtime?: EntryRuntime;
+	layer?: Layer;
+	dependOn?: string[] | string;
+	filename?: EntryFilename;
+	library?: LibraryOptions;
+	chunkLoading?: ChunkLoading;
+	asyncChunks?: boolean;
+	publicPath?: PublicPath;
 }
+export type Layer = null | string;
+export type EntryFilename = FilenameTemplate;
+export type ChunkLoading = false | ChunkLoadingType;
+export type ChunkLoadingType =
+	| ("jsonp" | "import-scripts" | "require" | "async-node" | "import")
+	| string;
 
 export type EntryNormalized = EntryStaticNormalized;
 export interface EntryStaticNormalized {
@@ -106,6 +134,13 @@ export interface EntryStaticNormalized {
 export interface EntryDescriptionNormalized {
 	import?: string[];
 	runtime?: EntryRuntime;
+	layer?: Layer;
+	dependOn?: string[];
+	filename?: EntryFilename;
+	library?: LibraryOptions;
+	chunkLoading?: ChunkLoading;
+	asyncChunks?: boolean;
+	publicPath?: PublicPath;
 }
 
 ///// Output /////
@@ -118,6 +153,11 @@ export interface Output {
 	cssChunkFilename?: CssChunkFilename;
 	assetModuleFilename?: AssetModuleFilename;
 	uniqueName?: UniqueName;
+	devtoolNamespace?: DevtoolNamespace;
+	devtoolModuleFilenameTemplate?: DevtoolModuleFilenameTemplate;
+	devtoolFallbackModuleFilenameTemplate?: DevtoolFallbackModuleFilenameTemplate;
+	chunkLoading?: ChunkLoading;
+	enabledChunkLoadingTypes?: EnabledChunkLoadingTypes;
 	enabledLibraryTypes?: EnabledLibraryTypes;
 	libraryExport?: LibraryExport;
 	libraryTarget?: LibraryType;
@@ -144,6 +184,9 @@ export type CssFilename = FilenameTemplate;
 export type CssChunkFilename = FilenameTemplate;
 export type FilenameTemplate = string;
 export type UniqueName = string;
+export type DevtoolNamespace = string;
+export type DevtoolModuleFilenameTemplate = string;
+export type DevtoolFallbackModuleFilenameTemplate = string;
 export type Library = LibraryName | LibraryOptions;
 export type StrictModuleErrorHandling = boolean;
 export type OutputModule = boolean;
@@ -198,6 +241,7 @@ export type LibraryType =
 	| string;
 export type AuxiliaryComment = string | LibraryCustomUmdCommentObject;
 export type UmdNamedDefine = boolean;
+export type EnabledChunkLoadingTypes = ChunkLoadingType[];
 export type EnabledLibraryTypes = LibraryType[];
 export type GlobalObject = string;
 export type ImportFunctionName = string;
@@ -211,6 +255,11 @@ export interface OutputNormalized {
 	cssChunkFilename?: CssChunkFilename;
 	assetModuleFilename?: AssetModuleFilename;
 	uniqueName?: UniqueName;
+	devtoolNamespace?: DevtoolNamespace;
+	devtoolModuleFilenameTemplate?: DevtoolModuleFilenameTemplate;
+	devtoolFallbackModuleFilenameTemplate?: DevtoolFallbackModuleFilenameTemplate;
+	chunkLoading?: ChunkLoading;
+	enabledChunkLoadingTypes?: EnabledChunkLoadingTypes;
 	enabledLibraryTypes?: EnabledLibraryTypes;
 	library?: LibraryOptions;
 	module?: OutputModule;
@@ -260,6 +309,8 @@ export interface RuleSetRule {
 	exclude?: RuleSetCondition;
 	include?: RuleSetCondition;
 	issuer?: RuleSetCondition;
+	issuerLayer?: RuleSetCondition;
+	layer?: string;
 	resource?: RuleSetCondition;
 	resourceFragment?: RuleSetCondition;
 	resourceQuery?: RuleSetCondition;
@@ -322,11 +373,33 @@ export type Target = false | string[] | string;
 
 ///// Externals /////
 export type Externals = ExternalItem[] | ExternalItem;
-export type ExternalItem = string | RegExp | ExternalItemObjectUnknown;
+export type ExternalItem =
+	| string
+	| RegExp
+	| ExternalItemObjectUnknown
+	| ExternalItemFunction;
+export interface ExternalItemFunctionData {
+	context: string;
+	request: string;
+	dependencyType: string;
+	contextInfo: {
+		issuer: string;
+	};
+}
+export type ExternalItemFunction = (
+	data: ExternalItemFunctionData,
+	callback: (err?: Error, result?: ExternalItemValue) => void
+) => Promise<ExternalItemValue | undefined> | void;
 export interface ExternalItemObjectUnknown {
 	[k: string]: ExternalItemValue;
 }
-export type ExternalItemValue = string | boolean;
+export type ExternalItemValue =
+	| string
+	| boolean
+	| string[]
+	| {
+			[k: string]: string | string[];
+	  };
 
 ///// ExternalsType /////
 export type ExternalsType =
@@ -389,6 +462,7 @@ export type DevTool =
 	| "hidden-cheap-source-map"
 	| "hidden-cheap-module-source-map"
 	| "hidden-source-map"
+	| "eval"
 	| "eval-cheap-source-map"
 	| "eval-cheap-module-source-map"
 	| "eval-source-map"
@@ -416,6 +490,8 @@ export interface SnapshotOptions {
 		hash?: boolean;
 		timestamp?: boolean;
 	};
+	immutablePaths?: (string | RegExp)[];
+	managedPaths?: (string | RegExp)[];
 }
 
 ///// Cache /////
@@ -448,23 +524,43 @@ export interface StatsOptions {
 	chunkRelations?: boolean;
 	timings?: boolean;
 	builtAt?: boolean;
+	pluginTimings?: boolean;
+	usedExports?: boolean;
+	providedExports?: boolean;
+	optimizationBailout?: boolean;
 }
 
 ///// Optimization /////
 export interface Optimization {
-	moduleIds?: "named" | "deterministic";
+	moduleIds?: "natural" | "named" | "hashed" | "deterministic" | "size";
+	chunkIds?: "natural" | "named" | "deterministic" | "size" | "total-size";
+	hashedModuleIds?: OptimizationHashedModuleIdsOptions;
+	deterministicChunkIds?: OptimizationDeterministicChunkIdsOptions;
 	minimize?: boolean;
 	minimizer?: ("..." | RspackPluginInstance)[];
 	splitChunks?: OptimizationSplitChunksOptions | false;
 	runtimeChunk?: OptimizationRuntimeChunk;
 	removeAvailableModules?: boolean;
 	sideEffects?: "flag" | boolean;
+	providedExports?: boolean;
+}
+export interface OptimizationHashedModuleIdsOptions {
+	context?: string;
+	hashFunction?: "md4" | "xxhash64" | "sha256" | "sha384" | "sha512";
+	hashDigest?: "hex" | "base64";
+	hashDigestLength?: number;
+}
+export interface OptimizationDeterministicChunkIdsOptions {
+	context?: string;
+	maxLength?: number;
 }
 export interface OptimizationSplitChunksOptions {
 	cacheGroups?: {
 		[k: string]: OptimizationSplitChunksCacheGroup;
 	};
 	chunks?: "initial" | "async" | "all";
+	filename?: string;
+	hidePathInfo?: boolean;
 	maxAsyncRequests?: number;
 	maxInitialRequests?: number;
 	minChunks?: number;
@@ -474,6 +570,8 @@ export interface OptimizationSplitChunksOptions {
 }
 export interface OptimizationSplitChunksCacheGroup {
 	chunks?: "initial" | "async" | "all";
+	filename?: string;
+	layer?: string;
 	minChunks?: number;
 	name?: string;
 	priority?: number;
@@ -506,6 +604,7 @@ export interface Experiments {
 	lazyCompilation?: boolean;
 	incrementalRebuild?: boolean;
 	asyncWebAssembly?: boolean;
+	layers?: boolean;
 }
 
 ///// Watch /////
@@ -30,6 +30,7 @@ export type StatsCompilation = {
 	warnings?: binding.JsStatsWarning[];
 	warningsCount?: number;
 	filteredModules?: number;
+	pluginHookTimings?: binding.JsStatsPluginHookTiming[];
 	children?: StatsCompilation[];
 };
 
@@ -123,6 +124,14 @@ export class Stats {
 		if (options.warningsCount) {
 			obj.warningsCount = (obj.warnings ?? this.#inner.getWarnings()).length;
 		}
+		if (options.pluginTimings) {
+			// JS plugins are timed by their own names, not as the hooks adapter.
+			obj.pluginHookTimings = this.#inner
+				.getPluginHookTimings()
+				.filter(timing => timing.plugin !== "rspack_plugin_js_hooks_adapter")
+				.concat(this.compilation.__internal_pluginHookTimings.toArray())
+				.sort((a, b) => b.duration - a.duration);
+		}
 		if (obj.modules && forToString) {
 			obj.filteredModules = obj.modules.length - 15;
 			obj.modules = obj.modules.slice(0, 15);
@@ -130,6 +139,27 @@ export class Stats {
 		return obj;
 	}
 
+	/**
+	 * The whole module graph, with the entry modules and the groups of modules importing each other.
+	 */
+	toModuleGraphJson(): binding.JsStatsModuleGraph {
+		return this.#inner.getModuleGraph();
+	}
+
+	/**
+	 * The module graph in the GraphViz DOT format.
+	 */
+	toModuleGraphDot(): string {
+		return this.#inner.getModuleGraphDot();
+	}
+
+	/**
+	 * The shortest chain of imports from an entry module to the module, answering why the module is in the bundle.
+	 */
+	getModuleImportPath(identifier: string): binding.JsStatsModuleIssuer[] | null {
+		return this.#inner.getModuleImportPath(identifier);
+	}
+
 	toString(opts?: StatsValue) {
 		const options = this.compilation.createStatsOptions(opts, {
 			forToString: true
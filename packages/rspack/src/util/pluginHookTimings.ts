@@ -0,0 +1,84 @@
+import { performance } from "perf_hooks";
+import type { JsStatsPluginHookTiming } from "@rspack/binding";
+import { isPromiseLike } from ".";
+
+type RecordTiming = (plugin: string, duration: number) => void;
+
+/**
+ * Time each tap of a hook called by the native side. The native side only sees
+ * the hooks adapter, so the taps are timed here to attribute them to the plugin
+ * that tapped them.
+ */
+export function interceptPluginHookTimings(
+	hook: { intercept: (interceptor: { register: any }) => void },
+	record: RecordTiming
+) {
+	hook.intercept({
+		register: (tap: { type: string; name: string; fn: Function }) => {
+			const fn = tap.fn;
+			const done = (start: number) =>
+				record(tap.name, performance.now() - start);
+			switch (tap.type) {
+				case "sync":
+					return {
+						...tap,
+						fn: (...args: any[]) => {
+							const start = performance.now();
+							try {
+								return fn(...args);
+							} finally {
+								done(start);
+							}
+						}
+					};
+				case "async":
+					return {
+						...tap,
+						fn: (...args: any[]) => {
+							const callback = args.pop();
+							const start = performance.now();
+							return fn(...args, (...results: any[]) => {
+								done(start);
+								return callback(...results);
+							});
+						}
+					};
+				case "promise":
+					return {
+						...tap,
+						fn: (...args: any[]) => {
+							const start = performance.now();
+							const result = fn(...args);
+							return isPromiseLike(result)
+								? result.finally(() => done(start))
+								: result;
+						}
+					};
+				default:
+					return tap;
+			}
+		}
+	});
+}
+
+/**
+ * Sum up the time taken by each plugin in each hook.
+ */
+export class PluginHookTimings {
+	#timings = new Map<string, JsStatsPluginHookTiming>();
+
+	record(plugin: string, hook: string, duration: number) {
+		const key = `${plugin}\0${hook}`;
+		let timing = this.#timings.get(key);
+		if (!timing) {
+			timing = { plugin, hook, calls: 0, duration: 0 };
+			this.#timings.set(key, timing);
+		}
+		timing.calls += 1;
+		timing.duration += duration;
+	}
+
+	toArray(): JsStatsPluginHookTiming[] {
+		return Array.from(this.#timings.values());
+	}
+}
@@ -118,6 +118,7 @@ describe("snapshots", () => {
 		  "experiments": {
 		    "asyncWebAssembly": false,
 		    "incrementalRebuild": true,
+		    "layers": false,
 		    "lazyCompilation": false,
 		  },
 		  "externals": undefined,
@@ -185,9 +186,11 @@ describe("snapshots", () => {
 		    "global": "warn",
 		  },
 		  "optimization": {
+		    "chunkIds": "named",
 		    "minimize": false,
 		    "minimizer": [],
 		    "moduleIds": "named",
+		    "providedExports": false,
 		    "removeAvailableModules": true,
 		    "runtimeChunk": false,
 		    "sideEffects": "flag",
@@ -208,6 +211,7 @@ describe("snapshots", () => {
 		      },
 		      "chunks": "async",
 		      "enforceSizeThreshold": 30000,
+		      "hidePathInfo": false,
 		      "maxAsyncRequests": Infinity,
 		      "maxInitialRequests": Infinity,
 		      "minChunks": 1,
@@ -218,8 +222,15 @@ describe("snapshots", () => {
 		  "output": {
 		    "assetModuleFilename": "[hash][ext][query]",
 		    "chunkFilename": "[name].js",
+		    "chunkLoading": "jsonp",
 		    "cssChunkFilename": "[name].css",
 		    "cssFilename": "[name].css",
+		    "devtoolFallbackModuleFilenameTemplate": "webpack://[namespace]/[resource-path]?[hash]",
+		    "devtoolModuleFilenameTemplate": "webpack://[namespace]/[resource-path]",
+		    "devtoolNamespace": "@rspack/core",
+		    "enabledChunkLoadingTypes": [
+		      "jsonp",
+		    ],
 		    "enabledLibraryTypes": [],
 		    "filename": "[name].js",
 		    "globalObject": "self",
@@ -234,6 +245,9 @@ describe("snapshots", () => {
 		    "webassemblyModuleFilename": "[hash].module.wasm",
 		  },
 		  "plugins": [],
+		  "profile": false,
+		  "recordsInputPath": false,
+		  "recordsOutputPath": false,
 		  "resolve": {
 		    "browserField": true,
 		    "byDependency": {
@@ -346,6 +360,10 @@ describe("snapshots", () => {
 		    ],
 		  },
 		  "snapshot": {
+		    "immutablePaths": [],
+		    "managedPaths": [
+		      /\\^\\(\\.\\+\\?\\[\\\\\\\\/\\]node_modules\\[\\\\\\\\/\\]\\)/,
+		    ],
 		    "module": {
 		      "hash": false,
 		      "timestamp": true,
@@ -409,7 +427,9 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": undefined,
 		@@ ... @@
+		-     "chunkIds": "named",
 		-     "minimize": false,
+		+     "chunkIds": "deterministic",
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
@@ -419,9 +439,11 @@ describe("snapshots", () => {
 		+     "sideEffects": true,
 		@@ ... @@
 		-       "enforceSizeThreshold": 30000,
+		-       "hidePathInfo": false,
 		-       "maxAsyncRequests": Infinity,
 		-       "maxInitialRequests": Infinity,
 		+       "enforceSizeThreshold": 50000,
+		+       "hidePathInfo": true,
 		+       "maxAsyncRequests": 30,
 		+       "maxInitialRequests": 30,
 		@@ ... @@
@@ -457,7 +479,9 @@ describe("snapshots", () => {
 		-   "mode": "none",
 		+   "mode": "production",
 		@@ ... @@
+		-     "chunkIds": "named",
 		-     "minimize": false,
+		+     "chunkIds": "deterministic",
 		+     "minimize": true,
 		@@ ... @@
 		-     "moduleIds": "named",
@@ -467,9 +491,11 @@ describe("snapshots", () => {
 		+     "sideEffects": true,
 		@@ ... @@
 		-       "enforceSizeThreshold": 30000,
+		-       "hidePathInfo": false,
 		-       "maxAsyncRequests": Infinity,
 		-       "maxInitialRequests": Infinity,
 		+       "enforceSizeThreshold": 50000,
+		+       "hidePathInfo": true,
 		+       "maxAsyncRequests": 30,
 		+       "maxInitialRequests": 30,
 		@@ ... @@
@@ -800,6 +826,12 @@ describe("snapshots", () => {
 		+     "__filename": "eval-only",
 		+     "global": false,
 		@@ ... @@
+		-     "chunkLoading": "jsonp",
+		+     "chunkLoading": "require",
+		@@ ... @@
+		-       "jsonp",
+		+       "require",
+		@@ ... @@
 		-     "globalObject": "self",
 		+     "globalObject": "global",
 		@@ ... @@
@@ -841,6 +873,14 @@ describe("snapshots", () => {
 		- Expected
 		+ Received
 
+		@@ ... @@
+		-     "chunkLoading": "jsonp",
+		+     "chunkLoading": false,
+		@@ ... @@
+		-     "enabledChunkLoadingTypes": Array [
+		-       "jsonp",
+		-     ],
+		+     "enabledChunkLoadingTypes": Array [],
 		@@ ... @@
 		+       "worker",
 		@@ ... @@
@@ -864,6 +904,12 @@ describe("snapshots", () => {
 		+     "__filename": "eval-only",
 		+     "global": false,
 		@@ ... @@
+		-     "chunkLoading": "jsonp",
+		+     "chunkLoading": "require",
+		@@ ... @@
+		-       "jsonp",
+		+       "require",
+		@@ ... @@
 		-     "globalObject": "self",
 		+     "globalObject": "global",
 		@@ ... @@
@@ -917,6 +963,12 @@ describe("snapshots", () => {
 		+     "__filename": "eval-only",
 		+     "global": false,
 		@@ ... @@
+		-     "chunkLoading": "jsonp",
+		+     "chunkLoading": "require",
+		@@ ... @@
+		-       "jsonp",
+		+       "require",
+		@@ ... @@
 		-     "globalObject": "self",
 		+     "globalObject": "global",
 		@@ ... @@
@@ -1087,6 +1139,9 @@ describe("snapshots", () => {
 			- Expected
 			+ Received
 
+			@@ ... @@
+			-     "devtoolNamespace": "@rspack/core",
+			+     "devtoolNamespace": "@@@Hello World!",
 			@@ ... @@
 			-     "uniqueName": "@rspack/core",
 			+     "uniqueName": "@@@Hello World!",
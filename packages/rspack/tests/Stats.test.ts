@@ -177,4 +177,43 @@ describe("Stats", () => {
 		"
 	`);
 	});
+
+	it("should time js plugins by their names", async () => {
+		const stats = await compile({
+			context: __dirname,
+			entry: "./fixtures/a",
+			plugins: [
+				{
+					apply(compiler) {
+						compiler.hooks.emit.tap("TimedPlugin", () => {});
+						compiler.hooks.thisCompilation.tap("TimedPlugin", compilation => {
+							compilation.hooks.processAssets.tapPromise(
+								"TimedPlugin",
+								async () => {}
+							);
+						});
+					}
+				}
+			]
+		});
+		const { pluginHookTimings: timings = [] } = stats!.toJson({
+			all: false,
+			pluginTimings: true
+		});
+		expect(
+			timings
+				.filter(timing => timing.plugin === "TimedPlugin")
+				.map(timing => [timing.hook, timing.calls])
+				.sort()
+		).toEqual([
+			["emit", 1],
+			["process_assets_stage_none", 1],
+			["this_compilation", 1]
+		]);
+		expect(
+			timings.some(
+				timing => timing.plugin === "rspack_plugin_js_hooks_adapter"
+			)
+		).toBe(false);
+	});
 });
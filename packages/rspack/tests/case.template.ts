@@ -11,6 +11,31 @@ const define = function (...args) {
 	factory();
 };
 
+// `errors.js` and `warnings.js` of a case export the regexps each message should match
+function checkArrayExpectation(
+	testDirectory: string,
+	kind: "errors" | "warnings",
+	messages: string[]
+) {
+	const expectationPath = path.join(testDirectory, `${kind}.js`);
+	if (!fs.existsSync(expectationPath)) {
+		return;
+	}
+	const expected: RegExp[][] = require(expectationPath);
+	assert(
+		messages.length === expected.length,
+		`Expected ${expected.length} ${kind}, got:\n${messages.join("\n")}`
+	);
+	expected.forEach((regexps, index) => {
+		regexps.forEach(regexp => {
+			assert(
+				regexp.test(messages[index]),
+				`Expected ${kind}[${index}] to match ${regexp}, got:\n${messages[index]}`
+			);
+		});
+	});
+}
+
 // most of these could be removed when we support external builtins by default
 export function describeCases(config: { name: string; casePath: string }) {
 	const casesPath = path.resolve(__dirname, config.casePath);
@@ -88,8 +113,18 @@ export function describeCases(config: { name: string; casePath: string }) {
 									const statsJson = stats!.toJson();
 									if (category.name === "errors") {
 										assert(statsJson.errors!.length > 0);
+										checkArrayExpectation(
+											testRoot,
+											"errors",
+											statsJson.errors!.map(x => x.message)
+										);
 									} else if (category.name === "warnings") {
 										assert(statsJson.warnings!.length > 0);
+										checkArrayExpectation(
+											testRoot,
+											"warnings",
+											statsJson.warnings!.map(x => x.message)
+										);
 									} else {
 										if (statsJson.errors!.length > 0) {
 											console.log(
@@ -0,0 +1,5 @@
+import { value } from "./shared";
+
+it("should load with the entry it depends on", () => {
+	expect(value).toBe(42);
+});
@@ -0,0 +1,3 @@
+module.exports = [
+	[/Circular dependency detected:\n {2}\.\/a\.js -> \.\/b\.js -> \.\/a\.js/]
+];
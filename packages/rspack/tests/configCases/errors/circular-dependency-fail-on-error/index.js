@@ -0,0 +1,7 @@
+import { a } from "./a";
+import { getA } from "./b";
+
+it("should still run modules importing each other", () => {
+	expect(a()).toBe("b");
+	expect(getA()).toBe(a);
+});
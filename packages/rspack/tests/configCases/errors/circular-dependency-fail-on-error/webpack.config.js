@@ -0,0 +1,8 @@
+module.exports = {
+	entry: "./index.js",
+	builtins: {
+		circularDependencies: {
+			failOnError: true
+		}
+	}
+};
@@ -0,0 +1,11 @@
+import path from "path";
+import join from "path-join";
+import sep from "path-sep";
+
+it("should access the property path of array externals", () => {
+	expect(join("a", "b")).toBe(path.join("a", "b"));
+});
+
+it("should use the request of the external type for object externals", () => {
+	expect(sep).toBe(path.sep);
+});
@@ -0,0 +1,11 @@
+module.exports = {
+	target: "node",
+	externalsType: "commonjs",
+	externals: {
+		"path-join": ["path", "join"],
+		"path-sep": {
+			commonjs: ["path", "sep"],
+			root: "pathSep"
+		}
+	}
+};
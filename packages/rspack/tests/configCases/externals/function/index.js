@@ -0,0 +1,12 @@
+import fs from "fs";
+import { join } from "path";
+import local from "./local";
+
+it("should externalize bare requests returned by the function", () => {
+	expect(fs).toBe(require("fs"));
+	expect(join("a", "b")).toBe(require("path").join("a", "b"));
+});
+
+it("should bundle requests the function skips", () => {
+	expect(local).toBe("local");
+});
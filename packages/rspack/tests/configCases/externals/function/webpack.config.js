@@ -0,0 +1,18 @@
+const path = require("path");
+
+module.exports = {
+	target: "node",
+	externals: [
+		async ({ context, request, dependencyType, contextInfo }) => {
+			if (request.startsWith(".")) return;
+			expect(context).toBe(__dirname);
+			expect(dependencyType).toBe("esm");
+			expect(contextInfo.issuer).toBe(path.join(__dirname, "index.js"));
+			if (request === "fs") return "commonjs fs";
+		},
+		({ request }, callback) => {
+			if (request === "path") return callback(undefined, "commonjs path");
+			callback();
+		}
+	]
+};
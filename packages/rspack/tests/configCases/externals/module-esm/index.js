@@ -0,0 +1,32 @@
+const fs = require("fs");
+const path = require("path");
+const url = require("url");
+const { execFileSync } = require("child_process");
+
+it("should import the module externals statically with ESM output", () => {
+	const source = fs.readFileSync(path.join(__dirname, "lib.js"), "utf-8");
+	expect(source).toContain(
+		"import * as __WEBPACK_EXTERNAL_MODULE_esm_dep__ from 'esm-dep';"
+	);
+
+	const libPath = path.join(__dirname, "lib.mjs");
+	fs.writeFileSync(
+		libPath,
+		source.replace(
+			"from 'esm-dep'",
+			'from "data:text/javascript,export const value = 42;"'
+		)
+	);
+	const output = execFileSync(
+		process.execPath,
+		[
+			"--input-type=module",
+			"-e",
+			`import { answer } from ${JSON.stringify(
+				url.pathToFileURL(libPath).href
+			)}; console.log(answer);`
+		],
+		{ encoding: "utf-8" }
+	);
+	expect(output.trim()).toBe("42");
+});
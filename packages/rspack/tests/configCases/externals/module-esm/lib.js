@@ -0,0 +1,3 @@
+import { value } from "esm-dep";
+
+export const answer = value;
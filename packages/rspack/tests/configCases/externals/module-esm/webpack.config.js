@@ -0,0 +1,19 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		lib: {
+			import: "./lib.js",
+			library: {
+				type: "module"
+			}
+		}
+	},
+	output: {
+		module: true
+	},
+	externalsType: "module",
+	externals: {
+		"esm-dep": "esm-dep"
+	}
+};
@@ -0,0 +1,8 @@
+import "./inject";
+
+it("should import the module externals dynamically without ESM output", function () {
+	return import("./user").then(function ({ request, answer }) {
+		expect(request).toBe("esm-dep");
+		expect(answer).toBe(42);
+	});
+});
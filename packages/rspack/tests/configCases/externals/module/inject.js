@@ -0,0 +1,3 @@
+global.__importExternal__ = function (request) {
+	return Promise.resolve({ request: request, nested: { answer: 42 } });
+};
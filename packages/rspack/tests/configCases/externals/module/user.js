@@ -0,0 +1,4 @@
+import { request } from "esm-dep";
+import { answer } from "esm-dep-nested";
+
+export { request, answer };
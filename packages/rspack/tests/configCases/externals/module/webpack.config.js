@@ -0,0 +1,11 @@
+module.exports = {
+	output: {
+		// `import()` can't run in the test context, a stub imports the external
+		importFunctionName: "__importExternal__"
+	},
+	externalsType: "module",
+	externals: {
+		"esm-dep": "esm-dep",
+		"esm-dep-nested": ["esm-dep", "nested"]
+	}
+};
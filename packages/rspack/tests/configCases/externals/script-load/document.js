@@ -0,0 +1,23 @@
+// Scripts "load" asynchronously, only my-sdk.js assigns its global
+global.loadedScripts = [];
+global.document = {
+	head: {
+		appendChild(script) {
+			global.loadedScripts.push(script.src);
+			setTimeout(() => {
+				if (script.src === "https://cdn.example.com/my-sdk.js") {
+					global.LoadedSdk = {
+						greet: name => "hello " + name
+					};
+				}
+				script.onload({ type: "load", target: script });
+			});
+		}
+	},
+	createElement() {
+		return {};
+	},
+	getElementsByTagName() {
+		return [];
+	}
+};
@@ -0,0 +1,22 @@
+import "./document";
+
+it("should load the script of the external before resolving its global", function () {
+	expect(typeof LoadedSdk).toBe("undefined");
+	return import("./sdk-user").then(({ greeting }) => {
+		expect(greeting).toBe("hello rspack");
+		expect(loadedScripts).toEqual(["https://cdn.example.com/my-sdk.js"]);
+	});
+});
+
+it("should reject when the script doesn't assign the global", function () {
+	return import("./missing-user").then(
+		() => {
+			throw new Error("The script external should be rejected");
+		},
+		error => {
+			expect(error.name).toBe("ScriptExternalLoadError");
+			expect(error.type).toBe("missing");
+			expect(error.request).toBe("https://cdn.example.com/missing-sdk.js");
+		}
+	);
+});
@@ -0,0 +1,3 @@
+import { version } from "missing-sdk";
+
+export { version };
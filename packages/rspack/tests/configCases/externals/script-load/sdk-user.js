@@ -0,0 +1,3 @@
+import { greet } from "my-sdk";
+
+export const greeting = greet("rspack");
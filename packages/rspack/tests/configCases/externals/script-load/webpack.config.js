@@ -0,0 +1,6 @@
+module.exports = {
+	externals: {
+		"my-sdk": "script LoadedSdk@https://cdn.example.com/my-sdk.js",
+		"missing-sdk": "script MissingSdk@https://cdn.example.com/missing-sdk.js"
+	}
+};
@@ -0,0 +1,7 @@
+import "./inject";
+
+it("should resolve script externals to the global assigned by the script", function () {
+	return import("./sdk-user").then(({ greeting }) => {
+		expect(greeting).toBe("hello rspack");
+	});
+});
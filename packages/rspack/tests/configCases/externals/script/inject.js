@@ -0,0 +1,3 @@
+global.MySdk = {
+	greet: name => "hello " + name
+};
@@ -0,0 +1,5 @@
+module.exports = {
+	externals: {
+		"my-sdk": "script MySdk@https://cdn.example.com/my-sdk.js"
+	}
+};
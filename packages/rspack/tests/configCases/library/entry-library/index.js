@@ -0,0 +1,12 @@
+const fs = require("fs");
+
+it("should only expose the entry with a library", () => {
+	const main = fs.readFileSync(__filename, "utf-8");
+	expect(main).not.toMatch(/module\["exports"\] = __webpack_exports__/);
+
+	const source = fs.readFileSync(__dirname + "/lib.js", "utf-8");
+	expect(source).toMatch(/module\["exports"\] = __webpack_exports__/);
+	const m = { exports: {} };
+	new Function("module", "exports", source)(m, m.exports);
+	expect(m.exports.answer).toBe(42);
+});
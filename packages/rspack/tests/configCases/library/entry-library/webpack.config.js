@@ -0,0 +1,12 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		lib: {
+			import: "./lib.js",
+			library: {
+				type: "commonjs2"
+			}
+		}
+	}
+};
@@ -0,0 +1,8 @@
+const fs = require("fs");
+
+it("should expose the exports of the entry as ESM exports", () => {
+	const source = fs.readFileSync(__dirname + "/lib.js", "utf-8");
+	expect(source).toContain(
+		"export { __webpack_exports__answer as answer, __webpack_exports__default as default };"
+	);
+});
@@ -0,0 +1,15 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		lib: {
+			import: "./lib.js",
+			library: {
+				type: "module"
+			}
+		}
+	},
+	output: {
+		module: true
+	}
+};
@@ -0,0 +1,26 @@
+const fs = require("fs");
+
+it("should register the entry as a named System module", () => {
+	const source = fs.readFileSync(__dirname + "/lib.js", "utf-8");
+	let registered;
+	const System = {
+		register(name, deps, declare) {
+			registered = { name, deps, declare };
+		}
+	};
+	new Function("System", source)(System);
+	expect(registered.name).toBe("lib");
+	expect(registered.deps).toEqual(["external-dep"]);
+
+	const exports = {};
+	const result = registered.declare(value => Object.assign(exports, value), {});
+	result.setters[0]({ value: "external" });
+	result.execute();
+	expect(exports.answer).toBe(42);
+	expect(exports.getValue()).toBe("external");
+});
+
+it("should not register the entries without the system library", () => {
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).not.toMatch(/^System\.register\(/m);
+});
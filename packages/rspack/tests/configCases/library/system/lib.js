@@ -0,0 +1,6 @@
+import { value } from "external-dep";
+
+export const answer = 42;
+export function getValue() {
+	return value;
+}
@@ -0,0 +1,17 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index.js",
+		lib: {
+			import: "./lib.js",
+			library: {
+				type: "system",
+				name: "lib"
+			}
+		}
+	},
+	externalsType: "system",
+	externals: {
+		"external-dep": "external-dep"
+	}
+};
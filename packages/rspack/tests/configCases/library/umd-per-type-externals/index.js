@@ -0,0 +1,29 @@
+const fs = require("fs");
+
+const load = (requireExternal, root) => {
+	const source = fs.readFileSync(__dirname + "/lib.js", "utf-8");
+	const module = requireExternal && { exports: {} };
+	new Function("exports", "module", "define", "require", "global", source)(
+		module && module.exports,
+		module,
+		undefined,
+		requireExternal,
+		root
+	);
+	return module ? module.exports : root.lib;
+};
+
+it("should require the commonjs2 request of the external", () => {
+	const requested = [];
+	const lib = load(request => {
+		requested.push(request);
+		return { nested: "commonjs2" };
+	});
+	expect(requested).toEqual(["dep-commonjs2"]);
+	expect(lib.value).toBe("commonjs2");
+});
+
+it("should read the root request of the external from the global", () => {
+	const lib = load(undefined, { DepGlobal: { nested: "root" } });
+	expect(lib.value).toBe("root");
+});
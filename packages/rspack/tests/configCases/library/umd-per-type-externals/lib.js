@@ -0,0 +1,3 @@
+import dep from "dep";
+
+export const value = dep;
@@ -0,0 +1,22 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	target: "node",
+	entry: {
+		main: "./index.js",
+		lib: {
+			import: "./lib.js",
+			library: {
+				type: "umd",
+				name: "lib"
+			}
+		}
+	},
+	externals: {
+		dep: {
+			root: ["DepGlobal", "nested"],
+			commonjs: "dep-commonjs",
+			commonjs2: ["dep-commonjs2", "nested"],
+			amd: "dep-amd"
+		}
+	}
+};
@@ -6,7 +6,7 @@ it("basic", () => {
 	const sourceMap = fs.readFileSync(__dirname + "/main.css.map", "utf-8");
 	const css = fs.readFileSync(path.resolve(__dirname, "../index.css"), "utf-8");
 	const map = JSON.parse(sourceMap);
-	expect(map.sources).toContain("index.css");
+	expect(map.sources).toContain("webpack:///./index.css");
 	expect(map.file).toEqual("main.css");
 	expect(map.sourcesContent).toEqual([css]);
 });
@@ -0,0 +1,21 @@
+const fs = require("fs");
+const sourceMap = require("source-map");
+require("./index.css");
+
+it("should only map lines of css modules if cheap options is used", async () => {
+	const source = fs.readFileSync(__dirname + "/main.css.map", "utf-8");
+	const map = JSON.parse(source);
+	expect(map.sources).toContain("webpack:///./index.css");
+	expect(map.file).toEqual("main.css");
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	// The consumer normalizes the names of the sources, look it up by the index in the map.
+	const index = consumer.sources[map.sources.indexOf("webpack:///./index.css")];
+	let mappings = 0;
+	consumer.eachMapping(m => {
+		if (m.source !== index) return;
+		mappings++;
+		expect(m.generatedColumn).toBe(0);
+		expect(m.originalColumn).toBe(0);
+	});
+	expect(mappings).toBeGreaterThan(0);
+});
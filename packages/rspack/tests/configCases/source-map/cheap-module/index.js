@@ -5,7 +5,7 @@ require("./index.scss");
 it("should only map original lines if cheap module options is used", async () => {
 	const source = fs.readFileSync(__dirname + "/main.css.map", "utf-8");
 	const map = JSON.parse(source);
-	expect(map.sources).toContain("index.scss");
+	expect(map.sources).toContain("webpack:///./index.scss");
 	expect(map.file).toEqual("main.css");
 	expect(map.sourcesContent[0]).toContain("$backgroundColor");
 	const consumer = await new sourceMap.SourceMapConsumer(map);
@@ -0,0 +1,20 @@
+const sourceMap = require("source-map");
+
+it("should only map lines in eval source maps if cheap options is used", async () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const base64 =
+		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,(.*)\\n\/\/# sourceURL=webpack-internal:\/\/\/[^"]*"\);/.exec(
+			source
+		)[1];
+	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
+	expect(map.sources).toContain("webpack:///./index.js");
+	const consumer = await new sourceMap.SourceMapConsumer(map);
+	let mappings = 0;
+	consumer.eachMapping(m => {
+		mappings++;
+		expect(m.generatedColumn).toBe(0);
+		expect(m.originalColumn).toBe(0);
+	});
+	expect(mappings).toBeGreaterThan(0);
+});
@@ -0,0 +1,5 @@
+module.exports = {
+	devtool: "eval-cheap-source-map",
+	externals: ["source-map"],
+	externalsType: "commonjs"
+};
@@ -0,0 +1,11 @@
+it("should not include sourcesContent in eval source maps if noSources option is used", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	const base64 =
+		/sourceMappingURL\s*=\s*data:application\/json;charset=utf-8;base64,(.*)\\n\/\/# sourceURL=webpack-internal:\/\/\/[^"]*"\);/.exec(
+			source
+		)[1];
+	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
+	expect(map.sources).toContain("webpack:///./index.js");
+	expect((map.sourcesContent || []).filter(Boolean)).toEqual([]);
+});
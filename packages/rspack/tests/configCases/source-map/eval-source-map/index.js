@@ -6,5 +6,5 @@ it("basic", () => {
 			source
 		)[1];
 	const map = JSON.parse(Buffer.from(base64, "base64").toString("utf-8"));
-	expect(map.sources).toContain("index.js");
+	expect(map.sources).toContain("webpack:///./index.js");
 });
@@ -0,0 +1,10 @@
+it("should wrap modules in eval with a sourceURL comment", () => {
+	const fs = require("fs");
+	const source = fs.readFileSync(__filename, "utf-8");
+	expect(source).toMatch(
+		/eval\(".*\\n\/\/# sourceURL=webpack:\/\/[^"]*\/\.\/index\.js"\);/
+	);
+	expect(source).not.toMatch(
+		/sourceMappingURL=data:application\/json;charset=utf-8;base64,/
+	);
+});
@@ -4,6 +4,6 @@ it("should not have map from url comments if hidden options is used", function (
 	expect(/sourceMappingURL\s*=\s*(.*)/.test(source)).toBe(false);
 	const mapSource = fs.readFileSync(__filename + ".map", "utf-8");
 	const map = JSON.parse(mapSource);
-	expect(map.sources).toContain("index.js");
+	expect(map.sources).toContain("webpack:///./index.js");
 	expect(map.file).toEqual("main.js");
 });
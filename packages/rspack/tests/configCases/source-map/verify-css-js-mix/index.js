@@ -11,14 +11,18 @@ try {
 it("verify importing css js source map", async () => {
 	const source = fs.readFileSync(__filename + ".map", "utf-8");
 	const map = JSON.parse(source);
-	expect(map.sources).toEqual(["../checkSourceMap.js", "a.js", "index.js"]);
+	expect(map.sources).toEqual([
+		"webpack:///../checkSourceMap.js",
+		"webpack:///./a.js",
+		"webpack:///./index.js"
+	]);
 	expect(map.file).toEqual("main.js");
 	const out = fs.readFileSync(__filename, "utf-8");
 	expect(
 		await checkMap(out, source, {
 			// *${id}* as the search key to aviod conflict with `Object.defineProperty(exports, ${id}, ...)`
-			["*a0*"]: "a.js",
-			["*a1*"]: "a.js"
+			["*a0*"]: "webpack:///a.js",
+			["*a1*"]: "webpack:///a.js"
 		})
 	).toBe(true);
 });
@@ -29,14 +33,14 @@ it("verify css source map", async () => {
 		"utf-8"
 	);
 	const cssMap = JSON.parse(cssSource);
-	expect(cssMap.sources).toEqual(["a.css"]);
+	expect(cssMap.sources).toEqual(["webpack:///./a.css"]);
 	expect(cssMap.file).toEqual("main.css");
 	const cssOut = fs.readFileSync(path.resolve(__dirname, "main.css"), "utf-8");
 	expect(
 		await checkMap(cssOut, cssSource, {
-			a0: "a.css",
-			a1: "a.css",
-			a2: "a.css"
+			a0: "webpack:///a.css",
+			a1: "webpack:///a.css",
+			a2: "webpack:///a.css"
 		})
 	).toBe(true);
 });
@@ -11,26 +11,26 @@ it("verify es6 (esmodule) bundle source map", async () => {
 	const source = fs.readFileSync(__filename + ".map", "utf-8");
 	const map = JSON.parse(source);
 	expect(map.sources).toEqual([
-		"../checkSourceMap.js",
-		"a.js",
-		"b-dir/b.js",
-		"b-dir/c-dir/c.js",
-		"index.js"
+		"webpack:///../checkSourceMap.js",
+		"webpack:///./a.js",
+		"webpack:///./b-dir/b.js",
+		"webpack:///./b-dir/c-dir/c.js",
+		"webpack:///./index.js"
 	]);
 	expect(map.file).toEqual("main.js");
 	const out = fs.readFileSync(__filename, "utf-8");
 	expect(
 		await checkMap(out, source, {
 			// *${id}* as the search key to aviod conflict with `Object.defineProperty(exports, ${id}, ...)`
-			["*a0*"]: "a.js",
-			["*a1*"]: "a.js",
-			["*a2*"]: "a.js",
-			["*b0*"]: "b-dir/b.js",
-			["*b1*"]: "b-dir/b.js",
-			["*b2*"]: "b-dir/b.js",
-			["*c0*"]: "b-dir/c-dir/c.js",
-			["*c1*"]: "b-dir/c-dir/c.js",
-			["*c2*"]: "b-dir/c-dir/c.js"
+			["*a0*"]: "webpack:///a.js",
+			["*a1*"]: "webpack:///a.js",
+			["*a2*"]: "webpack:///a.js",
+			["*b0*"]: "webpack:///b-dir/b.js",
+			["*b1*"]: "webpack:///b-dir/b.js",
+			["*b2*"]: "webpack:///b-dir/b.js",
+			["*c0*"]: "webpack:///b-dir/c-dir/c.js",
+			["*c1*"]: "webpack:///b-dir/c-dir/c.js",
+			["*c2*"]: "webpack:///b-dir/c-dir/c.js"
 		})
 	).toBe(true);
 });
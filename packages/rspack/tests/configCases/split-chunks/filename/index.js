@@ -0,0 +1,9 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should emit the split chunk with the cache group filename", function () {
+	return import("./a").then(function (a) {
+		expect(a.default).toBe("a");
+		expect(fs.existsSync(path.join(__dirname, "vendors/vendor.js"))).toBe(true);
+	});
+});
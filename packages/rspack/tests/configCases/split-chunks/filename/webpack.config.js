@@ -0,0 +1,19 @@
+/** @type {import("../../../../").Configuration} */
+module.exports = {
+	entry: {
+		main: "./index"
+	},
+	target: "node",
+	optimization: {
+		splitChunks: {
+			cacheGroups: {
+				vendor: {
+					chunks: "all",
+					name: "vendor",
+					filename: "vendors/[name].js",
+					test: "a.js"
+				}
+			}
+		}
+	}
+};
@@ -0,0 +1,4 @@
+import { a } from "./a";
+
+export const c = "c";
+export const getA = () => a;
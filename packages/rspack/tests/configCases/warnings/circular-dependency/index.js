@@ -0,0 +1,9 @@
+import { a } from "./a";
+import { getA } from "./b";
+import { getA as getAFromC } from "./c";
+
+it("should still run modules importing each other", () => {
+	expect(a()).toBe("bc");
+	expect(getA()).toBe(a);
+	expect(getAFromC()).toBe(a);
+});
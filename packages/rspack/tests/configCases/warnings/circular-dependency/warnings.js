@@ -0,0 +1,4 @@
+module.exports = [
+	[/Circular dependency detected:\n {2}\.\/a\.js -> \.\/b\.js -> \.\/a\.js/],
+	[/Circular dependency detected:\n {2}\.\/a\.js -> \.\/c\.js -> \.\/a\.js/]
+];
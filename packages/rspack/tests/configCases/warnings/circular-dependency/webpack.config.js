@@ -0,0 +1,6 @@
+module.exports = {
+	entry: "./index.js",
+	builtins: {
+		circularDependencies: true
+	}
+};
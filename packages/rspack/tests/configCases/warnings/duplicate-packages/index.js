@@ -0,0 +1,7 @@
+import { version } from "pkg";
+import { legacyVersion } from "legacy";
+
+it("should bundle both copies of pkg", () => {
+	expect(version).toBe("2.0.0");
+	expect(legacyVersion).toBe("1.0.0");
+});
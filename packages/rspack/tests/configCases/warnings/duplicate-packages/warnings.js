@@ -0,0 +1,7 @@
+module.exports = [
+	[
+		/Multiple copies of package "pkg" are bundled:/,
+		/pkg@1\.0\.0 \.\/node_modules\/legacy\/node_modules\/pkg /,
+		/pkg@2\.0\.0 \.\/node_modules\/pkg /
+	]
+];